@@ -0,0 +1,68 @@
+//! Benchmarks of the simulation step across flock sizes and interaction radii.
+//!
+//! Throughput is reported in bird updates per second, so runs with different `N` are
+//! directly comparable and the O(N²) neighbor search shows up as falling throughput.
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use flocking_lib::ensemble::{self, EntryGenerationParams, InitialDistribution, VelocityInit};
+use flocking_lib::simulation::{Engine, SimulationParams, SimulationRequest};
+use std::sync::mpsc;
+
+fn engine(num_birds: usize, interaction_radius: f64) -> Engine {
+    let entry_params = EntryGenerationParams {
+        num_birds,
+        radius: 1.0,
+        speed: 1.0,
+        min_distance: 0.0,
+        distribution: InitialDistribution::Uniform,
+        velocity_init: VelocityInit::Random,
+    };
+    let birds = ensemble::generate_parallel(0, 1, entry_params, Some(42), None)
+        .unwrap()
+        .remove(0)
+        .birds;
+
+    let params = SimulationParams {
+        num_birds,
+        radius: 1.0,
+        speed: 1.0,
+        dt: 0.01,
+        interaction_radius,
+        eta: 0.1,
+        total_iterations: 0,
+        // No frames: only the step itself is measured
+        frame_interval: 0,
+        ..Default::default()
+    };
+    let request = SimulationRequest {
+        id: 0,
+        tag: 0,
+        ensemble_entry_id: 0,
+        initial_values: birds,
+        params,
+        predators: Vec::new(),
+        leaders: Vec::new(),
+        forbidden_bands: Vec::new(),
+    };
+
+    Engine::new(request, mpsc::channel().0)
+}
+
+fn bench_step(c: &mut Criterion) {
+    let mut group = c.benchmark_group("step");
+    for n in [250, 1_000, 4_000] {
+        group.throughput(Throughput::Elements(n as u64));
+        for radius in [0.05, 0.2] {
+            let mut engine = engine(n, radius);
+            group.bench_with_input(
+                BenchmarkId::new(format!("r={}", radius), n),
+                &n,
+                |bench, _| bench.iter(|| engine.step_many(1)),
+            );
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_step);
+criterion_main!(benches);
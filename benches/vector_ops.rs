@@ -0,0 +1,80 @@
+//! Benchmarks comparing slice batch operations against per-element `Vec3` operators.
+
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use flocking_lib::vector::Vec3;
+use flocking_lib::vector::ops::{add_slices, scale_slice, sum_slice};
+use std::hint::black_box;
+
+fn test_vectors(n: usize, offset: f64) -> Vec<Vec3> {
+    (0..n)
+        .map(|i| {
+            let t = i as f64 + offset;
+            Vec3::new(t.sin(), t.cos(), 0.5 * t)
+        })
+        .collect()
+}
+
+fn bench_add(c: &mut Criterion) {
+    let mut group = c.benchmark_group("add");
+    for n in [1_000, 10_000, 100_000] {
+        let a = test_vectors(n, 0.0);
+        let b = test_vectors(n, 1.0);
+        let mut out = vec![Vec3::zero(); n];
+        group.throughput(Throughput::Elements(n as u64));
+
+        group.bench_with_input(BenchmarkId::new("operator", n), &n, |bench, _| {
+            bench.iter(|| {
+                for i in 0..n {
+                    out[i] = black_box(a[i]) + black_box(b[i]);
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("add_slices", n), &n, |bench, _| {
+            bench.iter(|| add_slices(black_box(&a), black_box(&b), &mut out))
+        });
+    }
+    group.finish();
+}
+
+fn bench_scale(c: &mut Criterion) {
+    let mut group = c.benchmark_group("scale");
+    for n in [1_000, 10_000, 100_000] {
+        let mut v = test_vectors(n, 0.0);
+        group.throughput(Throughput::Elements(n as u64));
+
+        group.bench_with_input(BenchmarkId::new("operator", n), &n, |bench, _| {
+            bench.iter(|| {
+                for item in v.iter_mut() {
+                    *item *= black_box(1.000001);
+                }
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("scale_slice", n), &n, |bench, _| {
+            bench.iter(|| scale_slice(&mut v, black_box(1.000001)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_sum(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sum");
+    for n in [1_000, 10_000, 100_000] {
+        let v = test_vectors(n, 0.0);
+        group.throughput(Throughput::Elements(n as u64));
+
+        group.bench_with_input(BenchmarkId::new("fold", n), &n, |bench, _| {
+            bench.iter(|| {
+                black_box(&v)
+                    .iter()
+                    .fold(Vec3::zero(), |acc, item| acc + *item)
+            })
+        });
+        group.bench_with_input(BenchmarkId::new("sum_slice", n), &n, |bench, _| {
+            bench.iter(|| sum_slice(black_box(&v)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_add, bench_scale, bench_sum);
+criterion_main!(benches);
@@ -1 +1,27 @@
+//! # Analysis Module - Observables and Statistics for Flocking Studies
+//!
+//! This module collects the post-processing tools used to characterize flocking
+//! states and locate the order-disorder transition. All functions are free functions
+//! operating on plain slices of birds or scalar samples, so they can be applied to
+//! freshly generated ensembles, live engine state, or stored simulation results alike.
+//!
+//! ## Organization
+//!
+//! - [`dynamics`]: Comparisons between configurations at different times
+//! - [`order`]: Order parameters of a single flock configuration
+//! - [`statistics`]: Scalar statistics over samples from independent runs
+//! - [`structure`]: Spatial structure such as clusters, neighborhoods, and spread
+//!
+//! Every public item of the submodules is re-exported at the module root, so
+//! functions are called as `analysis::binder_cumulant(...)`.
 
+pub mod dynamics;
+pub mod order;
+pub mod statistics;
+pub mod structure;
+pub mod tests;
+
+pub use dynamics::*;
+pub use order::*;
+pub use statistics::*;
+pub use structure::*;
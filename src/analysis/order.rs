@@ -0,0 +1,376 @@
+//! Order parameters characterizing the collective state of a flock.
+//!
+//! All functions take the current bird states as a slice and return dimensionless
+//! observables, so they apply equally to ensembles, live engine state, and stored
+//! snapshots.
+
+use crate::bird::Bird;
+use crate::vector::Vec3;
+use rayon::prelude::*;
+use std::f64::consts::{PI, TAU};
+
+/// Number of birds summed sequentially per task in [`polarization_parallel`].
+const REDUCTION_CHUNK: usize = 1024;
+
+/// Computes the polarization (Vicsek order parameter) of a flock.
+///
+/// ```text
+/// φ = |Σ vᵢ| / Σ |vᵢ|
+/// ```
+///
+/// `φ ≈ 1` when all birds move in the same direction and `φ ≈ 0` for random headings.
+/// Velocities are summed as 3D Cartesian vectors; on the sphere this measures global
+/// alignment of the tangent velocities as seen from the embedding space.
+///
+/// Returns `0.0` for an empty flock or when every bird is at rest.
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::analysis::polarization;
+/// # use flocking_lib::bird::Bird;
+/// # use flocking_lib::vector::Vec3;
+/// let aligned = vec![
+///     Bird { position: Vec3::x_hat(), velocity: Vec3::z_hat(), species: 0 },
+///     Bird { position: Vec3::y_hat(), velocity: Vec3::z_hat(), species: 0 },
+/// ];
+/// assert!((polarization(&aligned) - 1.0).abs() < 1e-12);
+/// ```
+pub fn polarization(birds: &[Bird]) -> f64 {
+    let (sum, total_speed) = birds
+        .iter()
+        .fold((Vec3::zero(), 0.0), |(sum, total_speed), bird| {
+            (sum + bird.velocity, total_speed + bird.velocity.norm())
+        });
+
+    if total_speed > f64::EPSILON {
+        sum.norm() / total_speed
+    } else {
+        0.0
+    }
+}
+
+/// Computes the polarization in parallel with a bit-reproducible result.
+///
+/// A rayon `reduce` combines partial sums in an order that depends on work stealing,
+/// so the last bits of the result can vary between runs and thread counts. Here the
+/// flock is split into fixed chunks of `REDUCTION_CHUNK` birds; each chunk is summed
+/// sequentially in parallel, and the per-chunk partials are combined sequentially in
+/// chunk order. Chunk boundaries do not depend on the thread pool, so the output is
+/// identical for any number of threads.
+///
+/// The price is an extra pass over `N / REDUCTION_CHUNK` partials and no benefit for
+/// small flocks; prefer [`polarization`] unless reproducibility across machines or
+/// thread counts matters. Both agree up to floating-point rounding.
+pub fn polarization_parallel(birds: &[Bird]) -> f64 {
+    let partials: Vec<(Vec3, f64)> = birds
+        .par_chunks(REDUCTION_CHUNK)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .fold((Vec3::zero(), 0.0), |(sum, total_speed), bird| {
+                    (sum + bird.velocity, total_speed + bird.velocity.norm())
+                })
+        })
+        .collect();
+
+    let (sum, total_speed) = partials.into_iter().fold(
+        (Vec3::zero(), 0.0),
+        |(sum, total_speed), (chunk_sum, chunk_speed)| (sum + chunk_sum, total_speed + chunk_speed),
+    );
+
+    if total_speed > f64::EPSILON {
+        sum.norm() / total_speed
+    } else {
+        0.0
+    }
+}
+
+/// Computes the total angular momentum `L = Σ rᵢ × vᵢ` of a flock (unit mass).
+///
+/// A band of birds circulating around a common axis has a large `L` along that axis
+/// even though its linear polarization vanishes.
+pub fn angular_momentum(birds: &[Bird]) -> Vec3 {
+    birds
+        .iter()
+        .map(|bird| bird.position.cross(&bird.velocity))
+        .sum()
+}
+
+/// Computes the rotational order parameter of a flock on the sphere.
+///
+/// ```text
+/// ψ = |Σ rᵢ × vᵢ| / Σ |rᵢ| |vᵢ|
+/// ```
+///
+/// `ψ ≈ 1` when all birds rotate rigidly around a common axis (a milling band) and
+/// `ψ ≈ 0` for disordered motion. Together with [`polarization`] this separates
+/// migrating states (high `φ`) from rotating states (high `ψ`).
+///
+/// Returns `0.0` for an empty flock or when every bird is at rest.
+pub fn rotational_order(birds: &[Bird]) -> f64 {
+    let normalization: f64 = birds
+        .iter()
+        .map(|bird| bird.position.norm() * bird.velocity.norm())
+        .sum();
+
+    if normalization > f64::EPSILON {
+        angular_momentum(birds).norm() / normalization
+    } else {
+        0.0
+    }
+}
+
+/// Computes the order of a circulating band around its dominant rotation axis.
+///
+/// The axis `n̂` is the normalized total [`angular_momentum`]. Each bird's velocity is
+/// projected onto the local circulation direction `êᵢ = (n̂ × r̂ᵢ) / |n̂ × r̂ᵢ|`, the
+/// direction of a rigid rotation about `n̂`, and
+///
+/// ```text
+/// β = Σ vᵢ · êᵢ / Σ |vᵢ|
+/// ```
+///
+/// `β ≈ 1` for a band circulating coherently around `n̂` regardless of its width,
+/// whereas [`rotational_order`] is additionally reduced by birds far from the band's
+/// great circle. Birds on the axis itself have no circulation direction and count as
+/// unaligned.
+///
+/// # Returns
+///
+/// `(n̂, β)`, or `(Vec3::zero(), 0.0)` when the angular momentum vanishes or every
+/// bird is at rest.
+pub fn band_order(birds: &[Bird]) -> (Vec3, f64) {
+    let axis = angular_momentum(birds).normalize();
+    let total_speed: f64 = birds.iter().map(|bird| bird.velocity.norm()).sum();
+    if axis == Vec3::zero() || total_speed <= f64::EPSILON {
+        return (Vec3::zero(), 0.0);
+    }
+
+    let circulating: f64 = birds
+        .iter()
+        .map(|bird| {
+            let direction = axis.cross(&bird.position).normalize();
+            bird.velocity.dot(&direction)
+        })
+        .sum();
+
+    (axis, circulating / total_speed)
+}
+
+/// Computes the normalized Shannon entropy of the flock's heading distribution.
+///
+/// Each bird's [`Bird::heading`] is sorted into `bins` equal-width bins starting at `-π`
+/// (bin `k` covers `[-π + 2πk/bins, -π + 2π(k+1)/bins)`), with occupation fractions `pₖ`,
+/// and
+///
+/// ```text
+/// S = -Σ pₖ ln pₖ / ln(bins)
+/// ```
+///
+/// `S ≈ 0` when all birds share a heading and `S ≈ 1` when headings are spread evenly,
+/// so high entropy indicates disorder. Headings are local tangent-plane angles, so the
+/// caveats of [`Bird::heading`] near the poles apply.
+///
+/// Returns `0.0` for an empty flock.
+///
+/// # Panics
+///
+/// Panics if `bins` is less than 2.
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::analysis::heading_entropy;
+/// # use flocking_lib::bird::Bird;
+/// let aligned: Vec<Bird> = (0..10)
+///     .map(|i| Bird::from_spherical(1.0, 1.0, 0.1 * i as f64, 1.0, 0.4))
+///     .collect();
+/// assert!(heading_entropy(&aligned, 12).abs() < 1e-12);
+/// ```
+pub fn heading_entropy(birds: &[Bird], bins: usize) -> f64 {
+    assert!(bins >= 2, "heading entropy needs at least two bins");
+    if birds.is_empty() {
+        return 0.0;
+    }
+
+    let total = birds.len() as f64;
+    let entropy: f64 = heading_counts(birds, bins, -PI)
+        .iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let p = count as f64 / total;
+            -p * p.ln()
+        })
+        .sum();
+
+    entropy / (bins as f64).ln()
+}
+
+/// Counts the flock's headings in `bins` equal angular bins over `[0, 2π)`.
+///
+/// Each bird's [`Bird::heading`] is wrapped into `[0, 2π)` (`0` points along φ̂, `π/2`
+/// toward south, i.e. along θ̂) and bin `k` covers `[2πk/bins, 2π(k+1)/bins)`, centered
+/// at `2π(k + ½)/bins`. The counts plot directly as a polar bar chart; see
+/// [`export_heading_histogram`](crate::simulation::io::export_heading_histogram) for a
+/// CSV of a stored snapshot. The caveats of [`Bird::heading`] near the poles apply.
+///
+/// # Panics
+///
+/// Panics if `bins` is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::analysis::heading_histogram;
+/// # use flocking_lib::bird::Bird;
+/// let birds: Vec<Bird> = (0..4)
+///     .map(|i| Bird::from_spherical(1.0, 1.0, i as f64, 1.0, 0.1 + 1.6 * i as f64))
+///     .collect();
+/// assert_eq!(heading_histogram(&birds, 4), vec![1, 1, 1, 1]);
+/// ```
+pub fn heading_histogram(birds: &[Bird], bins: usize) -> Vec<usize> {
+    assert!(bins >= 1, "heading histogram needs at least one bin");
+    heading_counts(birds, bins, 0.0)
+}
+
+/// Counts headings in `bins` equal angular bins, the first starting at `origin`.
+fn heading_counts(birds: &[Bird], bins: usize, origin: f64) -> Vec<usize> {
+    let mut counts = vec![0usize; bins];
+    for bird in birds {
+        let fraction = (bird.heading() - origin).rem_euclid(TAU) / TAU;
+        counts[((fraction * bins as f64) as usize).min(bins - 1)] += 1;
+    }
+    counts
+}
+
+/// Returns the minimum, mean, and maximum speed over the flock.
+///
+/// Under [`SpeedControl::Fixed`](crate::simulation::SpeedControl::Fixed) all three agree
+/// with `speed` up to rounding; under a speed cap the spread shows how far birds slow
+/// down, and the maximum must not exceed the cap.
+///
+/// Returns `(NaN, NaN, NaN)` for an empty flock.
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::analysis::speed_stats;
+/// # use flocking_lib::bird::Bird;
+/// let birds = vec![
+///     Bird::from_spherical(1.0, 1.0, 0.0, 0.5, 0.0),
+///     Bird::from_spherical(1.0, 1.5, 0.0, 1.5, 0.0),
+/// ];
+/// let (min, mean, max) = speed_stats(&birds);
+/// assert!((min - 0.5).abs() < 1e-12 && (mean - 1.0).abs() < 1e-12 && (max - 1.5).abs() < 1e-12);
+/// ```
+pub fn speed_stats(birds: &[Bird]) -> (f64, f64, f64) {
+    if birds.is_empty() {
+        return (f64::NAN, f64::NAN, f64::NAN);
+    }
+
+    let (min, max, sum) = birds.iter().map(|bird| bird.velocity.norm()).fold(
+        (f64::INFINITY, f64::NEG_INFINITY, 0.0),
+        |(min, max, sum), speed| (min.min(speed), max.max(speed), sum + speed),
+    );
+    (min, sum / birds.len() as f64, max)
+}
+
+/// Computes the polarization of each bird's neighborhood.
+///
+/// For bird `i`, the velocities of all birds within geodesic distance
+/// `neighborhood_radius` (bird `i` included) are parallel-transported to bird `i`'s
+/// position and combined as in [`polarization`]:
+///
+/// ```text
+/// φᵢ = |Σⱼ Tᵢ(vⱼ)| / Σⱼ |vⱼ|
+/// ```
+///
+/// Transport removes the apparent misalignment that curvature gives parallel flows at
+/// different positions, so a coherently moving domain scores near `1` even when it
+/// spans a noticeable part of the sphere. Ordered domains inside a globally disordered
+/// flock show up as regions of high `φᵢ`. The search is `O(N²)`.
+///
+/// # Returns
+///
+/// One value per bird, in bird order. A bird whose neighborhood is entirely at rest
+/// gets `0.0`; an isolated moving bird gets `1.0`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::analysis::local_order;
+/// # use flocking_lib::bird::Bird;
+/// let birds = vec![
+///     Bird::from_spherical(1.0, 1.00, 0.0, 1.0, 0.5),
+///     Bird::from_spherical(1.0, 1.05, 0.0, 1.0, 0.5),
+///     Bird::from_spherical(1.0, 2.50, 0.0, 1.0, 2.0),
+/// ];
+/// let order = local_order(&birds, 1.0, 0.2);
+/// assert!((order[0] - 1.0).abs() < 1e-9 && (order[2] - 1.0).abs() < 1e-12);
+/// ```
+pub fn local_order(birds: &[Bird], radius: f64, neighborhood_radius: f64) -> Vec<f64> {
+    birds
+        .par_iter()
+        .map(|center| {
+            let (sum, total_speed) = birds
+                .iter()
+                .filter(|other| center.distance_from(other, radius) <= neighborhood_radius)
+                .fold((Vec3::zero(), 0.0), |(sum, speed), other| {
+                    (
+                        sum + other.parallel_transport_velocity(center),
+                        speed + other.velocity.norm(),
+                    )
+                });
+
+            if total_speed > f64::EPSILON {
+                sum.norm() / total_speed
+            } else {
+                0.0
+            }
+        })
+        .collect()
+}
+
+/// Rotates a flock so that its net angular momentum points along `+z`.
+///
+/// The minimal rotation taking [`angular_momentum`] onto [`Vec3::z_hat`] (see
+/// [`Vec3::rotation_to`]) is applied to every position and velocity. This removes the
+/// global rotational freedom of the sphere up to a rotation about `z`, so snapshots or
+/// ensembles can be compared and averaged frame by frame.
+///
+/// A flock without net angular momentum (`|L|` within floating-point epsilon) has no
+/// dominant axis and is returned unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::analysis::{angular_momentum, canonicalize};
+/// # use flocking_lib::bird::Bird;
+/// # use flocking_lib::vector::Vec3;
+/// let birds = vec![Bird::from_spherical(1.0, 1.0, 0.3, 1.0, 0.7)];
+/// let canonical = canonicalize(&birds);
+/// let axis = angular_momentum(&canonical).normalize();
+/// assert!(axis.approx_eq(&Vec3::z_hat(), 1e-12));
+/// ```
+pub fn canonicalize(birds: &[Bird]) -> Vec<Bird> {
+    let momentum = angular_momentum(birds);
+    if momentum.norm() <= f64::EPSILON {
+        return birds.to_vec();
+    }
+
+    let (axis, angle) = momentum.rotation_to(&Vec3::z_hat());
+    let rotate = |v: &Vec3| {
+        v.rotate_around(&axis, angle)
+            .expect("rotation_to returns a unit axis")
+    };
+
+    birds
+        .iter()
+        .map(|bird| Bird {
+            position: rotate(&bird.position),
+            velocity: rotate(&bird.velocity),
+            species: bird.species,
+        })
+        .collect()
+}
@@ -0,0 +1,159 @@
+//! Scalar statistics over order-parameter samples.
+//!
+//! Functions in this module treat their input as a set of scalar measurements,
+//! typically one order-parameter value per independent run or per snapshot.
+
+/// Computes the fourth-order Binder cumulant of a set of order-parameter samples.
+///
+/// The cumulant is defined as
+///
+/// ```text
+/// U = 1 - ⟨φ⁴⟩ / (3 ⟨φ²⟩²)
+/// ```
+///
+/// where the averages run over `order_samples`, interpreted as scalar order
+/// parameters from independent runs. Curves of `U` versus noise for different
+/// system sizes cross at the critical point, which makes the cumulant the standard
+/// tool for locating the transition precisely. A sharply peaked (ordered)
+/// distribution gives `U → 2/3`.
+///
+/// # Edge Cases
+///
+/// Returns `f64::NAN` when `order_samples` is empty or when every sample is zero,
+/// since `⟨φ²⟩ = 0` leaves the ratio undefined.
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::analysis::binder_cumulant;
+/// // A constant order parameter gives exactly 2/3
+/// let u = binder_cumulant(&[0.8, 0.8, 0.8]);
+/// assert!((u - 2.0 / 3.0).abs() < 1e-12);
+///
+/// assert!(binder_cumulant(&[]).is_nan());
+/// assert!(binder_cumulant(&[0.0, 0.0]).is_nan());
+/// ```
+pub fn binder_cumulant(order_samples: &[f64]) -> f64 {
+    if order_samples.is_empty() {
+        return f64::NAN;
+    }
+
+    let n = order_samples.len() as f64;
+    let second_moment = order_samples.iter().map(|phi| phi.powi(2)).sum::<f64>() / n;
+    let fourth_moment = order_samples.iter().map(|phi| phi.powi(4)).sum::<f64>() / n;
+
+    if second_moment == 0.0 {
+        return f64::NAN;
+    }
+
+    1.0 - fourth_moment / (3.0 * second_moment * second_moment)
+}
+
+/// Computes the sample mean and its standard error.
+///
+/// The standard error is `s / √n` with `s` the unbiased (n - 1) sample standard
+/// deviation. Samples are treated as independent; for correlated time series the
+/// error is underestimated.
+///
+/// Returns `(NaN, NaN)` for an empty slice and a `NaN` error for a single sample.
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::analysis::mean_with_error;
+/// let (mean, error) = mean_with_error(&[1.0, 2.0, 3.0]);
+/// assert!((mean - 2.0).abs() < 1e-12);
+/// assert!((error - 1.0 / 3.0_f64.sqrt()).abs() < 1e-12);
+/// ```
+pub fn mean_with_error(samples: &[f64]) -> (f64, f64) {
+    if samples.is_empty() {
+        return (f64::NAN, f64::NAN);
+    }
+
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    if samples.len() < 2 {
+        return (mean, f64::NAN);
+    }
+
+    let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    (mean, (variance / n).sqrt())
+}
+
+/// Computes the susceptibility `χ = ⟨φ²⟩ - ⟨φ⟩²` of a set of order-parameter samples.
+///
+/// This is the variance of the order parameter over independent runs. The physical
+/// susceptibility carries an extra factor of the number of birds `N`, which is omitted
+/// here since it does not move the peak for a fixed system size. For the time-averaged
+/// estimate from a single run, including `N`, see
+/// [`SimulationResult::susceptibility`](crate::simulation::SimulationResult::susceptibility).
+///
+/// Returns `f64::NAN` for fewer than two samples.
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::analysis::susceptibility;
+/// assert!((susceptibility(&[0.2, 0.4]) - 0.01).abs() < 1e-12);
+/// assert!(susceptibility(&[0.5]).is_nan());
+/// ```
+pub fn susceptibility(order_samples: &[f64]) -> f64 {
+    if order_samples.len() < 2 {
+        return f64::NAN;
+    }
+
+    let n = order_samples.len() as f64;
+    let mean = order_samples.iter().sum::<f64>() / n;
+    let second_moment = order_samples.iter().map(|phi| phi.powi(2)).sum::<f64>() / n;
+
+    second_moment - mean * mean
+}
+
+/// Locates the order-disorder transition as the peak of the susceptibility versus noise.
+///
+/// For every noise value `eta_values[k]` the [`susceptibility`] of
+/// `order_samples_per_eta[k]` (order parameters of independent runs) is computed, and
+/// the noise at the largest susceptibility is returned together with the peak value.
+/// The location is only as fine as the noise grid; refine the grid around the result
+/// for a better estimate.
+///
+/// # Edge Cases
+///
+/// - Noise values with fewer than two samples have no susceptibility and are skipped
+/// - On ties the first (lowest-index) noise value wins
+/// - Returns `(NaN, NaN)` when no noise value has enough samples
+///
+/// # Panics
+///
+/// Panics if the two slices have different lengths.
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::analysis::transition_point;
+/// let etas = [0.5, 1.0, 1.5];
+/// let samples = vec![vec![0.9, 0.9], vec![0.2, 0.8], vec![0.1, 0.2]];
+/// let (eta, peak) = transition_point(&etas, &samples);
+/// assert_eq!(eta, 1.0);
+/// assert!((peak - 0.09).abs() < 1e-12);
+/// ```
+pub fn transition_point(eta_values: &[f64], order_samples_per_eta: &[Vec<f64>]) -> (f64, f64) {
+    assert_eq!(
+        eta_values.len(),
+        order_samples_per_eta.len(),
+        "transition_point: one sample set per noise value is required"
+    );
+
+    eta_values
+        .iter()
+        .zip(order_samples_per_eta)
+        .map(|(&eta, samples)| (eta, susceptibility(samples)))
+        .filter(|(_, chi)| !chi.is_nan())
+        .fold((f64::NAN, f64::NAN), |best, (eta, chi)| {
+            if best.1.is_nan() || chi > best.1 {
+                (eta, chi)
+            } else {
+                best
+            }
+        })
+}
@@ -0,0 +1,454 @@
+//! Spatial structure of a flock: neighborhoods, clusters, and spread.
+//!
+//! Distances are geodesic on the sphere of the given `radius`, consistent with
+//! [`Bird::distance_from`] used by the simulation's neighbor search.
+
+use crate::bird::Bird;
+use crate::vector::Vec3;
+use std::f64::consts::{PI, TAU};
+
+/// Partitions a flock into clusters of mutually reachable birds.
+///
+/// Two birds are linked when their geodesic distance is below `cutoff`; clusters are the
+/// connected components of that graph. Components are found with a union-find over all
+/// `N(N-1)/2` pairs.
+///
+/// # Returns
+///
+/// One `Vec` of bird indices per cluster, sorted by decreasing size (ties by smallest
+/// index). Indices within a cluster are ascending. Isolated birds form singleton clusters,
+/// so the sizes always sum to `birds.len()`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::analysis::find_clusters;
+/// # use flocking_lib::bird::Bird;
+/// let birds = vec![
+///     Bird::from_spherical(1.0, 0.50, 0.0, 1.0, 0.0),
+///     Bird::from_spherical(1.0, 0.55, 0.0, 1.0, 0.0),
+///     Bird::from_spherical(1.0, 2.50, 0.0, 1.0, 0.0),
+/// ];
+/// let clusters = find_clusters(&birds, 1.0, 0.1);
+/// assert_eq!(clusters, vec![vec![0, 1], vec![2]]);
+/// ```
+pub fn find_clusters(birds: &[Bird], radius: f64, cutoff: f64) -> Vec<Vec<usize>> {
+    let n = birds.len();
+    let mut parent: Vec<usize> = (0..n).collect();
+
+    fn find(parent: &mut [usize], mut i: usize) -> usize {
+        while parent[i] != i {
+            parent[i] = parent[parent[i]];
+            i = parent[i];
+        }
+        i
+    }
+
+    for i in 0..n {
+        for j in (i + 1)..n {
+            if birds[i].distance_from(&birds[j], radius) < cutoff {
+                let (ri, rj) = (find(&mut parent, i), find(&mut parent, j));
+                if ri != rj {
+                    parent[ri.max(rj)] = ri.min(rj);
+                }
+            }
+        }
+    }
+
+    let mut clusters: Vec<Vec<usize>> = Vec::new();
+    let mut cluster_of_root = vec![usize::MAX; n];
+    for i in 0..n {
+        let root = find(&mut parent, i);
+        if cluster_of_root[root] == usize::MAX {
+            cluster_of_root[root] = clusters.len();
+            clusters.push(Vec::new());
+        }
+        clusters[cluster_of_root[root]].push(i);
+    }
+
+    clusters.sort_by(|a, b| b.len().cmp(&a.len()).then(a[0].cmp(&b[0])));
+    clusters
+}
+
+/// Computes the mean geodesic distance from each bird to its nearest neighbor.
+///
+/// Useful to check that an ensemble respects its `min_distance` and to compare the
+/// packing of different initial distributions. The search is `O(N²)`.
+///
+/// Returns `NaN` for fewer than two birds, where no neighbor exists.
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::analysis::mean_nearest_neighbor_distance;
+/// # use flocking_lib::bird::Bird;
+/// let birds = vec![
+///     Bird::from_spherical(1.0, 0.5, 0.0, 1.0, 0.0),
+///     Bird::from_spherical(1.0, 0.8, 0.0, 1.0, 0.0),
+/// ];
+/// assert!((mean_nearest_neighbor_distance(&birds, 1.0) - 0.3).abs() < 1e-12);
+/// ```
+pub fn mean_nearest_neighbor_distance(birds: &[Bird], radius: f64) -> f64 {
+    if birds.len() < 2 {
+        return f64::NAN;
+    }
+
+    let total: f64 = birds
+        .iter()
+        .enumerate()
+        .map(|(i, bird)| {
+            birds
+                .iter()
+                .enumerate()
+                .filter(|&(j, _)| j != i)
+                .map(|(_, other)| bird.distance_from(other, radius))
+                .fold(f64::INFINITY, f64::min)
+        })
+        .sum();
+
+    total / birds.len() as f64
+}
+
+/// Computes the distribution of neighbor counts within `interaction_radius`.
+///
+/// Neighbors of a bird are all *other* birds at geodesic distance strictly below
+/// `interaction_radius`, matching the alignment neighborhood of the simulation.
+///
+/// # Returns
+///
+/// A histogram where index `k` holds the number of birds with exactly `k` neighbors.
+/// Its length is the maximum degree plus one, so the entries sum to `birds.len()`;
+/// an empty flock yields an empty histogram. A large count at `k = 0` signals a
+/// fragmented interaction graph.
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::analysis::neighbor_count_histogram;
+/// # use flocking_lib::bird::Bird;
+/// let birds = vec![
+///     Bird::from_spherical(1.0, 0.50, 0.0, 1.0, 0.0),
+///     Bird::from_spherical(1.0, 0.55, 0.0, 1.0, 0.0),
+///     Bird::from_spherical(1.0, 2.50, 0.0, 1.0, 0.0),
+/// ];
+/// assert_eq!(neighbor_count_histogram(&birds, 1.0, 0.1), vec![1, 2]);
+/// ```
+pub fn neighbor_count_histogram(
+    birds: &[Bird],
+    radius: f64,
+    interaction_radius: f64,
+) -> Vec<usize> {
+    let mut degrees = vec![0usize; birds.len()];
+    for i in 0..birds.len() {
+        for j in (i + 1)..birds.len() {
+            if birds[i].distance_from(&birds[j], radius) < interaction_radius {
+                degrees[i] += 1;
+                degrees[j] += 1;
+            }
+        }
+    }
+
+    let mut histogram = vec![0usize; degrees.iter().max().map_or(0, |&max| max + 1)];
+    for degree in degrees {
+        histogram[degree] += 1;
+    }
+    histogram
+}
+
+/// Builds the interaction graph of a flock as an undirected edge list.
+///
+/// Birds `i` and `j` are linked when their geodesic distance is strictly below
+/// `interaction_radius`, the same rule as [`neighbor_count_histogram`]. The search is
+/// `O(N²)`.
+///
+/// # Returns
+///
+/// One `(i, j)` pair with `i < j` per edge, sorted lexicographically. Isolated birds
+/// do not appear, so keep `birds.len()` alongside the edges when counting components.
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::analysis::interaction_graph;
+/// # use flocking_lib::bird::Bird;
+/// let birds = vec![
+///     Bird::from_spherical(1.0, 0.50, 0.0, 1.0, 0.0),
+///     Bird::from_spherical(1.0, 0.55, 0.0, 1.0, 0.0),
+///     Bird::from_spherical(1.0, 2.50, 0.0, 1.0, 0.0),
+/// ];
+/// assert_eq!(interaction_graph(&birds, 1.0, 0.1), vec![(0, 1)]);
+/// ```
+pub fn interaction_graph(
+    birds: &[Bird],
+    radius: f64,
+    interaction_radius: f64,
+) -> Vec<(usize, usize)> {
+    let mut edges = Vec::new();
+    for i in 0..birds.len() {
+        for j in (i + 1)..birds.len() {
+            if birds[i].distance_from(&birds[j], radius) < interaction_radius {
+                edges.push((i, j));
+            }
+        }
+    }
+    edges
+}
+
+/// Measures how widely a flock is spread over the sphere.
+///
+/// ```text
+/// D = 1 - |(1/N) Σ r̂ᵢ|
+/// ```
+///
+/// where `r̂ᵢ` are the unit position vectors. `D = 0` when all birds sit at one point
+/// and `D ≈ 1` when they cover the sphere uniformly (or in any configuration whose
+/// positions balance out, such as two antipodal clusters). It costs a single pass over
+/// the flock, making it a cheap cohesion metric alongside the velocity order parameters.
+///
+/// Returns `NaN` for an empty flock.
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::analysis::angular_dispersion;
+/// # use flocking_lib::bird::Bird;
+/// let clustered = vec![Bird::from_spherical(1.0, 0.5, 0.0, 1.0, 0.0); 3];
+/// assert!(angular_dispersion(&clustered).abs() < 1e-12);
+/// ```
+pub fn angular_dispersion(birds: &[Bird]) -> f64 {
+    if birds.is_empty() {
+        return f64::NAN;
+    }
+
+    let sum: Vec3 = birds.iter().map(|bird| bird.position.normalize()).sum();
+
+    1.0 - sum.norm() / birds.len() as f64
+}
+
+/// Computes the spherical centroid direction of a flock, `Σ r̂ᵢ / |Σ r̂ᵢ|`.
+///
+/// This is the mean direction of the unit position vectors, tracking where on the
+/// sphere the flock is located (e.g. during migration). Returns the zero vector for an
+/// empty flock or when the positions balance out (e.g. a uniform cover of the sphere),
+/// as the direction is then undefined.
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::analysis::center_of_mass_direction;
+/// # use flocking_lib::bird::Bird;
+/// # use flocking_lib::vector::Vec3;
+/// let birds = [
+///     Bird::from_spherical(2.0, 0.1, 0.0, 1.0, 0.0),
+///     Bird::from_spherical(2.0, 0.1, std::f64::consts::PI, 1.0, 0.0),
+/// ];
+/// assert!(center_of_mass_direction(&birds).approx_eq(&Vec3::z_hat(), 1e-12));
+/// ```
+pub fn center_of_mass_direction(birds: &[Bird]) -> Vec3 {
+    birds
+        .iter()
+        .map(|bird| bird.position.normalize())
+        .sum::<Vec3>()
+        .try_normalize()
+        .unwrap_or_else(Vec3::zero)
+}
+
+/// Computes the mean angular distance (radians) of the birds from the flock's centroid.
+///
+/// Measured from [`center_of_mass_direction`]; together they summarize the location
+/// and extent of the flock. Multiply by the sphere radius for a geodesic length. A
+/// uniformly filled hemisphere gives exactly 1 rad.
+///
+/// Returns `NaN` for an empty flock or when the centroid direction is undefined.
+pub fn angular_radius(birds: &[Bird]) -> f64 {
+    let centroid = center_of_mass_direction(birds);
+    if birds.is_empty() || centroid == Vec3::zero() {
+        return f64::NAN;
+    }
+
+    birds
+        .iter()
+        .map(|bird| centroid.angle_between(&bird.position))
+        .sum::<f64>()
+        / birds.len() as f64
+}
+
+/// Suggests an `interaction_radius` giving about `target_neighbors` neighbors per bird.
+///
+/// Assumes the flock is spread uniformly over the sphere of `radius`. A geodesic disk of
+/// radius `r` covers the fraction `(1 - cos(r / radius)) / 2` of the sphere, so each bird
+/// expects `(N - 1)` times that many neighbors. Solving for `r` gives
+///
+/// ```text
+/// r = radius · acos(1 - 2k / (N - 1))
+/// ```
+///
+/// Clustered flocks have more neighbors at this radius than requested; check the
+/// outcome with [`neighbor_count_histogram`].
+///
+/// # Returns
+///
+/// The suggested geodesic radius; `π · radius` (the whole sphere) when
+/// `target_neighbors >= N - 1`, and `NaN` for fewer than two birds.
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::analysis::suggest_interaction_radius;
+/// # use flocking_lib::bird::Bird;
+/// let birds = vec![Bird::default(); 101];
+/// // Half the other birds lie within a quarter great circle
+/// let r = suggest_interaction_radius(&birds, 1.0, 50);
+/// assert!((r - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+/// ```
+pub fn suggest_interaction_radius(birds: &[Bird], radius: f64, target_neighbors: usize) -> f64 {
+    if birds.len() < 2 {
+        return f64::NAN;
+    }
+
+    let others = (birds.len() - 1) as f64;
+    let fraction = (target_neighbors as f64 / others).min(1.0);
+    radius * (1.0 - 2.0 * fraction).acos()
+}
+
+/// Samples the flock's velocity onto a regular `(θ, φ)` grid for heatmap plotting.
+///
+/// The polar angle `θ ∈ [0, π]` (from +Z) is split into `n_theta` equal bins and the
+/// azimuth `φ ∈ [0, 2π)` into `n_phi`; each bird falls into one cell by the direction of
+/// its position on the sphere of `radius`. Each cell holds the plain mean of the
+/// velocities of its birds, or the zero vector if it is empty. Cells are small enough
+/// that averaging Cartesian vectors without parallel transport is adequate for plotting.
+///
+/// # Returns
+///
+/// `field[i][j]` is the mean velocity of the cell with `θ` in bin `i` and `φ` in bin `j`;
+/// empty if either bin count is zero.
+pub fn velocity_field(birds: &[Bird], n_theta: usize, n_phi: usize, radius: f64) -> Vec<Vec<Vec3>> {
+    if n_theta == 0 || n_phi == 0 {
+        return Vec::new();
+    }
+
+    let mut sums = vec![vec![Vec3::zero(); n_phi]; n_theta];
+    let mut counts = vec![vec![0usize; n_phi]; n_theta];
+    for bird in birds {
+        let theta = (bird.position.z / radius).clamp(-1.0, 1.0).acos();
+        let phi = bird.position.y.atan2(bird.position.x).rem_euclid(TAU);
+        let i = ((theta / PI * n_theta as f64) as usize).min(n_theta - 1);
+        let j = ((phi / TAU * n_phi as f64) as usize).min(n_phi - 1);
+        sums[i][j] += bird.velocity;
+        counts[i][j] += 1;
+    }
+
+    sums.into_iter()
+        .zip(counts)
+        .map(|(row, row_counts)| {
+            row.into_iter()
+                .zip(row_counts)
+                .map(|(sum, count)| if count > 0 { sum / count as f64 } else { sum })
+                .collect()
+        })
+        .collect()
+}
+
+/// Computes the pair-correlation function `g(r)` of a flock on the sphere.
+///
+/// Geodesic pair distances in `[0, max_geodesic]` are histogrammed into `bins` equal
+/// bins. Each count is divided by the number of pairs a uniform distribution would put
+/// in that annulus: for `N` birds on a sphere of radius `R`, a pair is found at a distance
+/// between `r₁` and `r₂` with probability `(cos(r₁/R) - cos(r₂/R)) / 2`, so
+///
+/// ```text
+/// g(r) = count(r₁, r₂) / (N(N-1)/2 · (cos(r₁/R) - cos(r₂/R)) / 2)
+/// ```
+///
+/// `g ≈ 1` for a uniform flock, peaks mark preferred spacings, and a dip at small `r`
+/// reveals excluded volume. `max_geodesic` is clamped to `π · radius`.
+///
+/// # Returns
+///
+/// `(bin center, g)` per bin; `g` is `NaN` for fewer than two birds.
+pub fn pair_correlation(
+    birds: &[Bird],
+    radius: f64,
+    bins: usize,
+    max_geodesic: f64,
+) -> Vec<(f64, f64)> {
+    if bins == 0 {
+        return Vec::new();
+    }
+
+    let max_geodesic = max_geodesic.min(PI * radius);
+    let width = max_geodesic / bins as f64;
+    let mut counts = vec![0usize; bins];
+    for (i, a) in birds.iter().enumerate() {
+        for b in &birds[i + 1..] {
+            let distance = a.distance_from(b, radius);
+            if distance < max_geodesic {
+                counts[((distance / width) as usize).min(bins - 1)] += 1;
+            }
+        }
+    }
+
+    let n = birds.len() as f64;
+    let pairs = n * (n - 1.0) / 2.0;
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(k, count)| {
+            let (r1, r2) = (k as f64 * width, (k + 1) as f64 * width);
+            let expected = pairs * ((r1 / radius).cos() - (r2 / radius).cos()) / 2.0;
+            let g = if birds.len() < 2 {
+                f64::NAN
+            } else {
+                count as f64 / expected
+            };
+            ((r1 + r2) / 2.0, g)
+        })
+        .collect()
+}
+
+/// Draws a deterministic random subset of about `fraction` of the birds.
+///
+/// Exactly `round(fraction · N)` birds are picked uniformly without replacement and
+/// returned in their original order; the same `seed` always selects the same indices
+/// for flocks of equal size. Running an O(N²) diagnostic such as [`pair_correlation`]
+/// on the subset cuts its cost by `fraction²`.
+///
+/// Uniform sampling keeps single-bird averages (polarization, speed and heading
+/// statistics) unbiased, with a statistical error growing as the sample shrinks.
+/// Observables built from neighborhoods are biased instead: the subset is sparser, so
+/// neighbor counts, cluster sizes, and local order computed with the same cutoff shrink,
+/// and nearest-neighbor distances grow by about `1 / √fraction`. Pair counts are
+/// normalized by the sample size, so `g(r)` stays unbiased but noisier.
+///
+/// # Panics
+///
+/// Panics if `fraction` is not within `[0, 1]`.
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::analysis::subsample;
+/// # use flocking_lib::bird::Bird;
+/// let birds: Vec<Bird> =
+///     (0..100).map(|i| Bird::from_spherical(1.0, 1.0, 0.05 * i as f64, 1.0, 0.0)).collect();
+/// let sample = subsample(&birds, 0.25, 3);
+/// assert_eq!(sample.len(), 25);
+/// assert_eq!(sample, subsample(&birds, 0.25, 3));
+/// ```
+pub fn subsample(birds: &[Bird], fraction: f64, seed: u64) -> Vec<Bird> {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    assert!(
+        (0.0..=1.0).contains(&fraction),
+        "subsample fraction must be within [0, 1], got {}",
+        fraction
+    );
+
+    let amount = (fraction * birds.len() as f64).round() as usize;
+    let mut indices =
+        rand::seq::index::sample(&mut StdRng::seed_from_u64(seed), birds.len(), amount).into_vec();
+    indices.sort_unstable();
+    indices.into_iter().map(|i| birds[i]).collect()
+}
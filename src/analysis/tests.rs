@@ -0,0 +1,783 @@
+//! # Unit tests for the Analysis module
+
+#[cfg(test)]
+mod units {
+    use crate::analysis;
+    use crate::bird::Bird;
+    use crate::vector::Vec3;
+
+    #[test]
+    fn binder_cumulant_constant_samples() {
+        let u = analysis::binder_cumulant(&[0.5; 10]);
+        assert!((u - 2.0 / 3.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn binder_cumulant_two_point_distribution() {
+        // φ ∈ {1, 3}: ⟨φ²⟩ = 5, ⟨φ⁴⟩ = 41, U = 1 - 41/75
+        let u = analysis::binder_cumulant(&[1.0, 3.0, 1.0, 3.0]);
+        assert!((u - 34.0 / 75.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn binder_cumulant_gaussian_limit() {
+        // For a symmetric Gaussian ⟨φ⁴⟩ = 3⟨φ²⟩², so U → 0. Gauss-Hermite style
+        // three-point rule reproduces the moments exactly: values ±√3 with weight 1/6,
+        // 0 with weight 2/3.
+        let s = 3.0_f64.sqrt();
+        let samples = [-s, 0.0, 0.0, 0.0, 0.0, s];
+        let u = analysis::binder_cumulant(&samples);
+        assert!(u.abs() < 1e-12);
+    }
+
+    #[test]
+    fn binder_cumulant_degenerate_input() {
+        assert!(analysis::binder_cumulant(&[]).is_nan());
+        assert!(analysis::binder_cumulant(&[0.0, 0.0, 0.0]).is_nan());
+    }
+
+    #[test]
+    fn transition_point_synthetic_peak() {
+        // Order samples spread most widely at eta = 1.2
+        let etas = [0.4, 0.8, 1.2, 1.6, 2.0];
+        let spreads = [0.02, 0.1, 0.3, 0.15, 0.05];
+        let samples: Vec<Vec<f64>> = spreads
+            .iter()
+            .map(|&d| vec![0.5 - d, 0.5 + d, 0.5 - d, 0.5 + d])
+            .collect();
+
+        let (eta, peak) = analysis::transition_point(&etas, &samples);
+        assert_eq!(eta, 1.2);
+        assert!((peak - 0.09).abs() < 1e-12);
+
+        // Ties resolve to the first noise value; sparse entries are skipped
+        let tied = vec![vec![0.0, 1.0], vec![1.0], vec![1.0, 0.0]];
+        assert_eq!(
+            analysis::transition_point(&[0.1, 0.2, 0.3], &tied),
+            (0.1, 0.25)
+        );
+
+        let (eta, peak) = analysis::transition_point(&[0.1], &[vec![0.5]]);
+        assert!(eta.is_nan() && peak.is_nan());
+    }
+
+    #[test]
+    fn polarization_aligned_and_opposed() {
+        let aligned: Vec<Bird> = (0..4)
+            .map(|i| Bird {
+                position: Vec3::new(1.0, i as f64, 0.0).normalize(),
+                velocity: Vec3::z_hat() * 2.0,
+                species: 0,
+            })
+            .collect();
+        assert!((analysis::polarization(&aligned) - 1.0).abs() < 1e-12);
+
+        let opposed = vec![
+            Bird {
+                position: Vec3::x_hat(),
+                velocity: Vec3::z_hat(),
+                species: 0,
+            },
+            Bird {
+                position: Vec3::y_hat(),
+                velocity: -Vec3::z_hat(),
+                species: 0,
+            },
+        ];
+        assert!(analysis::polarization(&opposed).abs() < 1e-12);
+
+        let perpendicular = vec![
+            Bird {
+                position: Vec3::z_hat(),
+                velocity: Vec3::x_hat(),
+                species: 0,
+            },
+            Bird {
+                position: Vec3::z_hat(),
+                velocity: Vec3::y_hat(),
+                species: 0,
+            },
+        ];
+        let expected = 2.0_f64.sqrt() / 2.0;
+        assert!((analysis::polarization(&perpendicular) - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn polarization_degenerate_input() {
+        assert_eq!(analysis::polarization(&[]), 0.0);
+        assert_eq!(analysis::polarization(&[Bird::default(); 3]), 0.0);
+    }
+
+    #[test]
+    fn mean_with_error_values() {
+        let (mean, error) = analysis::mean_with_error(&[2.0, 4.0, 6.0, 8.0]);
+        assert!((mean - 5.0).abs() < 1e-12);
+        // s² = 20/3, error = sqrt(s²/4)
+        assert!((error - (20.0_f64 / 3.0 / 4.0).sqrt()).abs() < 1e-12);
+
+        let (mean, error) = analysis::mean_with_error(&[0.7; 5]);
+        assert!((mean - 0.7).abs() < 1e-12);
+        assert!(error.abs() < 1e-12);
+
+        let (mean, error) = analysis::mean_with_error(&[1.5]);
+        assert_eq!(mean, 1.5);
+        assert!(error.is_nan());
+
+        let (mean, error) = analysis::mean_with_error(&[]);
+        assert!(mean.is_nan() && error.is_nan());
+    }
+
+    #[test]
+    fn rotational_order_uniform_rotation() {
+        // Birds on the equator all circulating around the z axis
+        let n = 12;
+        let birds: Vec<Bird> = (0..n)
+            .map(|i| {
+                let phi = std::f64::consts::TAU * i as f64 / n as f64;
+                let position = Vec3::new(phi.cos(), phi.sin(), 0.0) * 2.0;
+                let velocity = Vec3::z_hat().cross(&position).normalize() * 0.5;
+                Bird {
+                    position,
+                    velocity,
+                    species: 0,
+                }
+            })
+            .collect();
+
+        let l = analysis::angular_momentum(&birds);
+        assert!((l.normalize().z - 1.0).abs() < 1e-12);
+        assert!((l.norm() - n as f64).abs() < 1e-12);
+        assert!((analysis::rotational_order(&birds) - 1.0).abs() < 1e-12);
+        // A rotating band has no net linear momentum
+        assert!(analysis::polarization(&birds) < 1e-12);
+    }
+
+    #[test]
+    fn rotational_order_counter_rotation() {
+        let birds = vec![
+            Bird {
+                position: Vec3::x_hat(),
+                velocity: Vec3::y_hat(),
+                species: 0,
+            },
+            Bird {
+                position: -Vec3::x_hat(),
+                velocity: Vec3::y_hat(),
+                species: 0,
+            },
+        ];
+        assert!(analysis::angular_momentum(&birds).norm() < 1e-12);
+        assert!(analysis::rotational_order(&birds).abs() < 1e-12);
+        assert_eq!(analysis::rotational_order(&[]), 0.0);
+    }
+
+    #[test]
+    fn find_clusters_groups_by_cutoff() {
+        // Chain of three birds 0.1 apart near the north pole, a pair at the south pole
+        let birds = vec![
+            Bird::from_spherical(1.0, 2.9, 0.0, 1.0, 0.0),
+            Bird::from_spherical(1.0, 0.2, 0.0, 1.0, 0.0),
+            Bird::from_spherical(1.0, 0.3, 0.0, 1.0, 0.0),
+            Bird::from_spherical(1.0, 0.4, 0.0, 1.0, 0.0),
+            Bird::from_spherical(1.0, 3.0, 0.0, 1.0, 0.0),
+            Bird::from_spherical(1.0, 1.5, 0.0, 1.0, 0.0),
+        ];
+
+        let clusters = analysis::find_clusters(&birds, 1.0, 0.15);
+        assert_eq!(clusters, vec![vec![1, 2, 3], vec![0, 4], vec![5]]);
+
+        // Chains are linked transitively even when the end points are far apart
+        assert_eq!(analysis::find_clusters(&birds, 1.0, 0.05).len(), 6);
+        assert_eq!(
+            analysis::find_clusters(&birds, 1.0, 4.0),
+            vec![vec![0, 1, 2, 3, 4, 5]]
+        );
+        assert!(analysis::find_clusters(&[], 1.0, 1.0).is_empty());
+    }
+
+    #[test]
+    fn mean_nearest_neighbor_distance_known_configurations() {
+        // Two birds a quarter circle apart on a sphere of radius 2
+        let pair = vec![
+            Bird {
+                position: Vec3::x_hat() * 2.0,
+                velocity: Vec3::z_hat(),
+                species: 0,
+            },
+            Bird {
+                position: Vec3::y_hat() * 2.0,
+                velocity: Vec3::z_hat(),
+                species: 0,
+            },
+        ];
+        let expected = std::f64::consts::PI;
+        assert!((analysis::mean_nearest_neighbor_distance(&pair, 2.0) - expected).abs() < 1e-12);
+
+        // Third bird close to the first: nearest distances are 0.1, 0.1 and 1.4
+        let triple = vec![
+            Bird::from_spherical(1.0, 0.1, 0.0, 1.0, 0.0),
+            Bird::from_spherical(1.0, 0.2, 0.0, 1.0, 0.0),
+            Bird::from_spherical(1.0, 1.6, 0.0, 1.0, 0.0),
+        ];
+        let mean = analysis::mean_nearest_neighbor_distance(&triple, 1.0);
+        assert!((mean - 1.6 / 3.0).abs() < 1e-12);
+
+        assert!(analysis::mean_nearest_neighbor_distance(&pair[..1], 2.0).is_nan());
+        assert!(analysis::mean_nearest_neighbor_distance(&[], 2.0).is_nan());
+    }
+
+    #[test]
+    fn neighbor_count_histogram_clustered_configuration() {
+        // A tight triangle (each bird has 2 neighbors), a pair (1 each) and a loner
+        let birds = vec![
+            Bird::from_spherical(1.0, 0.30, 0.00, 1.0, 0.0),
+            Bird::from_spherical(1.0, 0.35, 0.00, 1.0, 0.0),
+            Bird::from_spherical(1.0, 0.32, 0.10, 1.0, 0.0),
+            Bird::from_spherical(1.0, 2.00, 1.00, 1.0, 0.0),
+            Bird::from_spherical(1.0, 2.05, 1.00, 1.0, 0.0),
+            Bird::from_spherical(1.0, 1.50, 4.00, 1.0, 0.0),
+        ];
+
+        let histogram = analysis::neighbor_count_histogram(&birds, 1.0, 0.1);
+        assert_eq!(histogram, vec![1, 2, 3]);
+        assert_eq!(histogram.iter().sum::<usize>(), birds.len());
+
+        // Nobody interacts with a vanishing radius
+        assert_eq!(
+            analysis::neighbor_count_histogram(&birds, 1.0, 0.0),
+            vec![6]
+        );
+        // Everybody interacts with everybody at a radius beyond π
+        assert_eq!(
+            analysis::neighbor_count_histogram(&birds, 1.0, 4.0),
+            vec![0, 0, 0, 0, 0, 6]
+        );
+        assert!(analysis::neighbor_count_histogram(&[], 1.0, 0.1).is_empty());
+    }
+
+    #[test]
+    fn band_order_rotating_band() {
+        // A wide band around the x axis circulating rigidly about it
+        let axis = Vec3::x_hat();
+        let birds: Vec<Bird> = (0..24)
+            .map(|i| {
+                let angle = std::f64::consts::TAU * i as f64 / 24.0;
+                let tilt = 0.4 * ((i % 3) as f64 - 1.0);
+                let position = Vec3::new(
+                    tilt.sin(),
+                    tilt.cos() * angle.cos(),
+                    tilt.cos() * angle.sin(),
+                );
+                let velocity = axis.cross(&position).normalize() * 1.5;
+                Bird {
+                    position,
+                    velocity,
+                    species: 0,
+                }
+            })
+            .collect();
+
+        let (found_axis, beta) = analysis::band_order(&birds);
+        assert!(found_axis.approx_eq(&axis, 1e-12));
+        assert!((beta - 1.0).abs() < 1e-12);
+        // Off-equator birds lower the rotational order but not the band order
+        assert!(analysis::rotational_order(&birds) < 0.99);
+
+        // Reversing a third of the birds reduces the band order to 1/3
+        let mixed: Vec<Bird> = birds
+            .iter()
+            .enumerate()
+            .map(|(i, bird)| Bird {
+                position: bird.position,
+                velocity: if i % 3 == 0 {
+                    -bird.velocity
+                } else {
+                    bird.velocity
+                },
+                species: 0,
+            })
+            .collect();
+        let (_, beta) = analysis::band_order(&mixed);
+        assert!((beta - 1.0 / 3.0).abs() < 1e-12);
+
+        assert_eq!(analysis::band_order(&[]), (Vec3::zero(), 0.0));
+    }
+
+    #[test]
+    fn polarization_parallel_is_thread_count_independent() {
+        // Irregular, partially aligned flock large enough to span several chunks
+        let birds: Vec<Bird> = (0..5000)
+            .map(|i| {
+                let t = i as f64 * 0.7548776662466927;
+                let theta = 0.1 + 2.9 * t.fract();
+                let phi = std::f64::consts::TAU * (i as f64 * 0.5698402909980532).fract();
+                Bird::from_spherical(1.0, theta, phi, 1.0 + 0.1 * t.sin(), 0.3 + 0.5 * t.cos())
+            })
+            .collect();
+
+        let with_threads = |threads| {
+            rayon::ThreadPoolBuilder::new()
+                .num_threads(threads)
+                .build()
+                .unwrap()
+                .install(|| analysis::polarization_parallel(&birds))
+        };
+        let single = with_threads(1);
+        for threads in [2, 3, 8] {
+            assert_eq!(with_threads(threads).to_bits(), single.to_bits());
+        }
+
+        assert!((single - analysis::polarization(&birds)).abs() < 1e-12);
+        assert_eq!(analysis::polarization_parallel(&[]), 0.0);
+    }
+
+    #[test]
+    fn snapshot_displacement_known_motion() {
+        use crate::simulation::SimulationSnapshot;
+
+        let snapshot = |birds: Vec<Bird>| SimulationSnapshot {
+            step: 0,
+            timestamp: 0.0,
+            birds,
+            cluster_count: None,
+        };
+        // Sphere of radius 2: one bird moves by 0.1 rad, the other by 0.3 rad
+        let before = snapshot(vec![
+            Bird::from_spherical(2.0, 1.0, 0.0, 1.0, 0.0),
+            Bird::from_spherical(2.0, 1.0, 1.0, 1.0, 0.0),
+        ]);
+        let after = snapshot(vec![
+            Bird::from_spherical(2.0, 1.1, 0.0, 1.0, 0.0),
+            Bird::from_spherical(2.0, 1.3, 1.0, 1.0, 0.0),
+        ]);
+
+        let mean = analysis::snapshot_displacement(&before, &after);
+        assert!((mean - (0.2 + 0.6) / 2.0).abs() < 1e-12);
+        assert_eq!(analysis::snapshot_displacement(&before, &before), 0.0);
+        assert_eq!(
+            analysis::snapshot_displacement(&snapshot(vec![]), &snapshot(vec![])),
+            0.0
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "different numbers of birds")]
+    fn snapshot_displacement_count_mismatch() {
+        use crate::simulation::SimulationSnapshot;
+
+        let one = SimulationSnapshot {
+            step: 0,
+            timestamp: 0.0,
+            birds: vec![Bird::default()],
+            cluster_count: None,
+        };
+        let two = SimulationSnapshot {
+            birds: vec![Bird::default(); 2],
+            ..one.clone()
+        };
+        analysis::snapshot_displacement(&one, &two);
+    }
+
+    #[test]
+    fn angular_dispersion_cluster_and_uniform() {
+        // Tight cluster around theta = 1.0 on a sphere of radius 3
+        let cluster: Vec<Bird> = (0..20)
+            .map(|i| Bird::from_spherical(3.0, 1.0 + 1e-3 * i as f64, 1e-3 * i as f64, 1.0, 0.0))
+            .collect();
+        assert!(analysis::angular_dispersion(&cluster) < 1e-3);
+
+        // Fibonacci lattice covers the sphere nearly uniformly
+        let n = 500;
+        let golden_angle = std::f64::consts::PI * (3.0 - 5.0_f64.sqrt());
+        let lattice: Vec<Bird> = (0..n)
+            .map(|i| {
+                let z = 1.0 - (2.0 * i as f64 + 1.0) / n as f64;
+                Bird::from_spherical(2.0, z.acos(), golden_angle * i as f64, 1.0, 0.0)
+            })
+            .collect();
+        assert!(analysis::angular_dispersion(&lattice) > 0.99);
+
+        assert!(analysis::angular_dispersion(&[]).is_nan());
+    }
+
+    #[test]
+    fn msd_ballistic_trajectory() {
+        use crate::simulation::{
+            SIMULATION_SCHEMA_VERSION, SimulationParams, SimulationResult, SimulationSnapshot,
+        };
+
+        // Single bird moving 0.1 rad per snapshot along a meridian of a radius-2 sphere
+        let snapshots: Vec<SimulationSnapshot> = (0..10)
+            .map(|k| SimulationSnapshot {
+                step: k,
+                timestamp: k as f64,
+                birds: vec![Bird::from_spherical(
+                    2.0,
+                    0.5 + 0.1 * k as f64,
+                    0.0,
+                    1.0,
+                    0.0,
+                )],
+                cluster_count: None,
+            })
+            .collect();
+        let result = SimulationResult {
+            schema_version: SIMULATION_SCHEMA_VERSION,
+            id: 0,
+            tag: 0,
+            ensemble_entry_id: 0,
+            params: SimulationParams {
+                num_birds: 1,
+                radius: 2.0,
+                speed: 0.2,
+                dt: 1.0,
+                interaction_radius: 0.1,
+                eta: 0.0,
+                total_iterations: 9,
+                frame_interval: 1,
+                ..Default::default()
+            },
+            snapshots,
+            final_state: Vec::new(),
+        };
+
+        assert_eq!(analysis::msd(&result, 0), 0.0);
+        for lag in 1..10 {
+            let expected = (0.2 * lag as f64).powi(2);
+            assert!((analysis::msd(&result, lag) - expected).abs() < 1e-12);
+        }
+        // Quadratic growth: doubling the lag quadruples the MSD
+        let ratio = analysis::msd(&result, 4) / analysis::msd(&result, 2);
+        assert!((ratio - 4.0).abs() < 1e-9);
+
+        assert!(analysis::msd(&result, 10).is_nan());
+    }
+
+    #[test]
+    fn canonicalize_removes_global_rotation() {
+        let birds: Vec<Bird> = (0..8)
+            .map(|i| {
+                let i = i as f64;
+                Bird::from_spherical(1.5, 0.3 + 0.3 * i, 0.7 * i, 1.0, 0.2 + 0.1 * i)
+            })
+            .collect();
+
+        // Arbitrary global rotation of the whole flock
+        let axis = Vec3::new(1.0, -2.0, 0.5).normalize();
+        let rotated: Vec<Bird> = birds
+            .iter()
+            .map(|bird| Bird {
+                position: bird.position.rotate_around(&axis, 2.1).unwrap(),
+                velocity: bird.velocity.rotate_around(&axis, 2.1).unwrap(),
+                species: 0,
+            })
+            .collect();
+
+        let canonical = analysis::canonicalize(&birds);
+        let canonical_rotated = analysis::canonicalize(&rotated);
+        let momentum = analysis::angular_momentum(&canonical).normalize();
+        assert!(momentum.approx_eq(&Vec3::z_hat(), 1e-12));
+
+        // The copies agree up to the remaining rotation about z
+        let azimuth = |v: Vec3| v.y.atan2(v.x);
+        let twist = azimuth(canonical[0].position) - azimuth(canonical_rotated[0].position);
+        for (a, b) in canonical.iter().zip(&canonical_rotated) {
+            let position = b.position.rotate_around(&Vec3::z_hat(), twist).unwrap();
+            let velocity = b.velocity.rotate_around(&Vec3::z_hat(), twist).unwrap();
+            assert!(a.position.approx_eq(&position, 1e-10));
+            assert!(a.velocity.approx_eq(&velocity, 1e-10));
+        }
+
+        // No dominant axis: returned unchanged
+        let still = vec![Bird {
+            position: Vec3::x_hat(),
+            velocity: Vec3::zero(),
+            species: 0,
+        }];
+        assert_eq!(analysis::canonicalize(&still)[0].position, Vec3::x_hat());
+    }
+
+    #[test]
+    fn suggest_interaction_radius_uniform_ensemble() {
+        use crate::ensemble::{self, EntryGenerationParams, InitialDistribution, VelocityInit};
+
+        let params = EntryGenerationParams {
+            num_birds: 600,
+            radius: 2.0,
+            speed: 1.0,
+            min_distance: 0.0,
+            distribution: InitialDistribution::Uniform,
+            velocity_init: VelocityInit::Random,
+        };
+        let birds = &ensemble::generate_parallel(0, 1, params, Some(5), None).unwrap()[0].birds;
+
+        let target = 12;
+        let r = analysis::suggest_interaction_radius(birds, 2.0, target);
+        let histogram = analysis::neighbor_count_histogram(birds, 2.0, r);
+        let mean_neighbors = histogram
+            .iter()
+            .enumerate()
+            .map(|(k, count)| k * count)
+            .sum::<usize>() as f64
+            / birds.len() as f64;
+        assert!((mean_neighbors - target as f64).abs() < 0.15 * target as f64);
+
+        assert_eq!(
+            analysis::suggest_interaction_radius(birds, 2.0, 10_000),
+            2.0 * std::f64::consts::PI
+        );
+        assert!(analysis::suggest_interaction_radius(&birds[..1], 2.0, 3).is_nan());
+    }
+
+    #[test]
+    fn centroid_and_angular_radius_of_cap_and_hemisphere() {
+        use crate::ensemble::{self, EntryGenerationParams, InitialDistribution, VelocityInit};
+
+        let entry = |distribution, num_birds| {
+            let params = EntryGenerationParams {
+                num_birds,
+                radius: 1.0,
+                speed: 1.0,
+                min_distance: 0.0,
+                distribution,
+                velocity_init: VelocityInit::Random,
+            };
+            ensemble::generate_parallel(0, 1, params, Some(11), None).unwrap()[0]
+                .birds
+                .clone()
+        };
+
+        // Tight cap around the north pole: every bird within 0.05 rad of the centroid
+        let cap = entry(InitialDistribution::PolarCap { max_theta: 0.05 }, 200);
+        assert!(analysis::center_of_mass_direction(&cap).approx_eq(&Vec3::z_hat(), 1e-2));
+        let r = analysis::angular_radius(&cap);
+        assert!(r > 0.0 && r < 0.05);
+
+        // Uniform northern hemisphere: mean polar angle is exactly 1 rad
+        let hemisphere = entry(
+            InitialDistribution::Band {
+                theta_min: 0.0,
+                theta_max: std::f64::consts::FRAC_PI_2,
+            },
+            4000,
+        );
+        assert!(analysis::center_of_mass_direction(&hemisphere).approx_eq(&Vec3::z_hat(), 0.05));
+        assert!((analysis::angular_radius(&hemisphere) - 1.0).abs() < 0.03);
+
+        assert_eq!(analysis::center_of_mass_direction(&[]), Vec3::zero());
+        assert!(analysis::angular_radius(&[]).is_nan());
+    }
+
+    #[test]
+    fn velocity_field_of_uniform_flock() {
+        use crate::ensemble::{self, EntryGenerationParams, InitialDistribution, VelocityInit};
+
+        // Uniform positions, every bird flying along its local φ̂ (a rigid rotation)
+        let params = EntryGenerationParams {
+            num_birds: 3_000,
+            radius: 2.0,
+            speed: 0.5,
+            min_distance: 0.0,
+            distribution: InitialDistribution::Uniform,
+            velocity_init: VelocityInit::Aligned { alpha: 0.0 },
+        };
+        let birds = &ensemble::generate_parallel(0, 1, params, Some(3), None).unwrap()[0].birds;
+
+        let (n_theta, n_phi) = (6, 12);
+        let field = analysis::velocity_field(birds, n_theta, n_phi, 2.0);
+        assert_eq!(field.len(), n_theta);
+        for (i, row) in field.iter().enumerate() {
+            assert_eq!(row.len(), n_phi);
+            for (j, velocity) in row.iter().enumerate() {
+                // Mean velocity follows φ̂ at the cell center with nearly full speed
+                let phi = (j as f64 + 0.5) * std::f64::consts::TAU / n_phi as f64;
+                let e_phi = Vec3::new(-phi.sin(), phi.cos(), 0.0);
+                let speed = velocity.norm();
+                assert!(
+                    speed > 0.45 && speed <= 0.5 + 1e-12,
+                    "cell ({i}, {j}): {speed}"
+                );
+                assert!(velocity.normalize().dot(&e_phi) > 0.97, "cell ({i}, {j})");
+            }
+        }
+
+        let empty = analysis::velocity_field(&[], 2, 3, 1.0);
+        assert!(empty.iter().flatten().all(|v| *v == Vec3::zero()));
+        assert!(analysis::velocity_field(birds, 0, 3, 2.0).is_empty());
+    }
+
+    #[test]
+    fn pair_correlation_of_uniform_ensemble() {
+        use crate::ensemble::{self, EntryGenerationParams, InitialDistribution, VelocityInit};
+
+        let params = EntryGenerationParams {
+            num_birds: 1500,
+            radius: 2.0,
+            speed: 1.0,
+            min_distance: 0.0,
+            distribution: InitialDistribution::Uniform,
+            velocity_init: VelocityInit::Random,
+        };
+        let birds = &ensemble::generate_parallel(0, 1, params, Some(17), None).unwrap()[0].birds;
+
+        let g = analysis::pair_correlation(birds, 2.0, 10, 2.0);
+        assert_eq!(g.len(), 10);
+        for (k, &(r, value)) in g.iter().enumerate() {
+            assert!((r - (k as f64 + 0.5) * 0.2).abs() < 1e-12);
+            assert!((value - 1.0).abs() < 0.1, "g({r}) = {value}");
+        }
+
+        // Over the whole sphere every pair is counted exactly once
+        let full = analysis::pair_correlation(birds, 2.0, 1, 100.0);
+        assert!((full[0].0 - std::f64::consts::PI).abs() < 1e-12);
+        assert!((full[0].1 - 1.0).abs() < 1e-12);
+
+        assert!(
+            analysis::pair_correlation(&birds[..1], 2.0, 3, 1.0)[0]
+                .1
+                .is_nan()
+        );
+    }
+
+    #[test]
+    fn heading_entropy_aligned_and_uniform() {
+        use std::f64::consts::TAU;
+
+        let bins = 8;
+        let aligned: Vec<Bird> = (0..64)
+            .map(|i| Bird::from_spherical(1.0, 0.5 + 0.03 * i as f64, 0.1 * i as f64, 1.0, -1.0))
+            .collect();
+        assert!(analysis::heading_entropy(&aligned, bins).abs() < 1e-12);
+
+        // Eight birds per bin, each heading at the center of its bin
+        let uniform: Vec<Bird> = (0..64)
+            .map(|i| {
+                let alpha = -std::f64::consts::PI + ((i % bins) as f64 + 0.5) * TAU / bins as f64;
+                Bird::from_spherical(1.0, 0.5 + 0.03 * i as f64, 0.1 * i as f64, 1.0, alpha)
+            })
+            .collect();
+        assert!((analysis::heading_entropy(&uniform, bins) - 1.0).abs() < 1e-12);
+
+        // Two equally occupied bins out of four give ln 2 / ln 4 = 1/2
+        let split: Vec<Bird> = (0..10)
+            .map(|i| {
+                let alpha = if i % 2 == 0 { -1.0 } else { 2.0 };
+                Bird::from_spherical(1.0, 1.0, 0.2 * i as f64, 1.0, alpha)
+            })
+            .collect();
+        assert!((analysis::heading_entropy(&split, 4) - 0.5).abs() < 1e-12);
+        assert_eq!(analysis::heading_entropy(&[], bins), 0.0);
+
+        // With three bins the first edge above -π lies at -π/3, where bins from 0 would
+        // have none, so headings just either side of it fall into different bins
+        let straddling: Vec<Bird> = [-1e-6, 1e-6]
+            .iter()
+            .map(|offset| {
+                let alpha = -std::f64::consts::PI / 3.0 + offset;
+                Bird::from_spherical(1.0, 1.0, 0.0, 1.0, alpha)
+            })
+            .collect();
+        let two_of_three = 2f64.ln() / 3f64.ln();
+        assert!((analysis::heading_entropy(&straddling, 3) - two_of_three).abs() < 1e-12);
+        assert_eq!(analysis::heading_histogram(&straddling, 3), vec![0, 0, 2]);
+    }
+
+    #[test]
+    fn interaction_graph_of_known_configuration() {
+        use std::f64::consts::FRAC_PI_2;
+
+        // Four birds on the equator at 0, 0.3, 0.6 and π/2 plus one at the north pole
+        let birds = vec![
+            Bird::from_spherical(2.0, FRAC_PI_2, 0.0, 1.0, 0.0),
+            Bird::from_spherical(2.0, FRAC_PI_2, 0.3, 1.0, 0.0),
+            Bird::from_spherical(2.0, FRAC_PI_2, 0.6, 1.0, 0.0),
+            Bird::from_spherical(2.0, FRAC_PI_2, FRAC_PI_2, 1.0, 0.0),
+            Bird::from_spherical(2.0, 0.0, 0.0, 1.0, 0.0),
+        ];
+
+        // Consecutive equatorial birds are 0.6 apart; bird 3 is 1.94 from bird 2
+        assert_eq!(
+            analysis::interaction_graph(&birds, 2.0, 0.7),
+            vec![(0, 1), (1, 2)]
+        );
+        assert_eq!(
+            analysis::interaction_graph(&birds, 2.0, 1.3),
+            vec![(0, 1), (0, 2), (1, 2)]
+        );
+        // No pair is farther apart than the pole and the equator, 2 · π/2
+        assert_eq!(analysis::interaction_graph(&birds, 2.0, 3.2).len(), 10);
+        assert!(analysis::interaction_graph(&birds[..1], 2.0, 10.0).is_empty());
+    }
+
+    #[test]
+    fn local_order_finds_aligned_cluster() {
+        // A tight aligned cluster in the northern hemisphere ...
+        let mut birds: Vec<Bird> = (0..20)
+            .map(|i| {
+                let theta = 0.8 + 0.02 * (i / 5) as f64;
+                let phi = 0.02 * (i % 5) as f64;
+                Bird::from_spherical(1.0, theta, phi, 1.0, 0.4)
+            })
+            .collect();
+        // ... and scattered birds with unrelated headings on the other hemisphere
+        birds.extend((0..20).map(|i| {
+            let theta = 1.8 + 1.0 * (i as f64 / 20.0);
+            let phi = 2.0 + 0.61 * i as f64;
+            Bird::from_spherical(1.0, theta, phi, 1.0, 2.399 * i as f64)
+        }));
+
+        let order = analysis::local_order(&birds, 1.0, 0.3);
+        assert_eq!(order.len(), birds.len());
+        assert!(order[..20].iter().all(|&phi| phi > 0.999), "{order:?}");
+        // Scattered birds are mostly alone (order 1) or in small disordered groups
+        assert!(order[20..].iter().any(|&phi| phi < 0.9), "{order:?}");
+
+        // A neighborhood covering the whole sphere mixes the cluster with the scattered birds
+        let global = analysis::local_order(&birds, 1.0, std::f64::consts::PI);
+        assert!(global[..20].iter().all(|&phi| phi < 0.95), "{global:?}");
+
+        let resting = vec![Bird::from_spherical(1.0, 1.0, 0.0, 0.0, 0.0)];
+        assert_eq!(analysis::local_order(&resting, 1.0, 0.3), vec![0.0]);
+    }
+
+    #[test]
+    fn speed_stats_of_mixed_speeds() {
+        let birds: Vec<Bird> = [0.25, 1.0, 2.0, 0.75]
+            .iter()
+            .enumerate()
+            .map(|(i, &speed)| Bird::from_spherical(2.0, 0.4 * (i + 1) as f64, 0.3, speed, 1.0))
+            .collect();
+        let (min, mean, max) = analysis::speed_stats(&birds);
+        assert!((min - 0.25).abs() < 1e-12);
+        assert!((mean - 1.0).abs() < 1e-12);
+        assert!((max - 2.0).abs() < 1e-12);
+
+        let (min, mean, max) = analysis::speed_stats(&birds[2..3]);
+        assert!(min == max && (mean - 2.0).abs() < 1e-12);
+
+        let (min, mean, max) = analysis::speed_stats(&[]);
+        assert!(min.is_nan() && mean.is_nan() && max.is_nan());
+    }
+
+    #[test]
+    fn subsample_size_and_reproducibility() {
+        let birds: Vec<Bird> = (0..200)
+            .map(|i| Bird::from_spherical(1.0, 0.5 + 0.01 * i as f64, 0.37 * i as f64, 1.0, 0.0))
+            .collect();
+
+        let sample = analysis::subsample(&birds, 0.3, 5);
+        assert_eq!(sample.len(), 60);
+        assert_eq!(sample, analysis::subsample(&birds, 0.3, 5));
+        assert_ne!(sample, analysis::subsample(&birds, 0.3, 6));
+        // Picked birds are distinct and keep their original order
+        let indices: Vec<usize> = sample
+            .iter()
+            .map(|bird| birds.iter().position(|b| b == bird).unwrap())
+            .collect();
+        assert!(indices.windows(2).all(|pair| pair[0] < pair[1]));
+
+        assert_eq!(analysis::subsample(&birds, 0.004, 5).len(), 1);
+        assert!(analysis::subsample(&birds, 0.0, 5).is_empty());
+        assert_eq!(analysis::subsample(&birds, 1.0, 5), birds);
+    }
+}
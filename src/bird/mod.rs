@@ -42,13 +42,33 @@ pub mod physics;
 /// # Fields
 /// - `position`: 3D Cartesian position vector from the sphere center to the bird's location.
 /// - `velocity`: 3D velocity vector that is tangent to the sphere surface at the bird's position.
-#[derive(Default, Debug, Clone, Copy, serde::Serialize, serde::Deserialize)]
+/// - `species`: Population the bird belongs to; `0` unless set with [`Bird::with_species`].
+#[derive(Default, Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct Bird {
     /// Position vector from sphere center to particle location
     pub position: Vec3,
     /// Velocity vector tangent to sphere surface at particle position
     pub velocity: Vec3,
+    /// Species index selecting the row and column of
+    /// [`SimulationParams::species_alignment`](crate::simulation::SimulationParams::species_alignment)
+    #[serde(default)]
+    pub species: u8,
 }
+
+/// On-disk layout of [`Bird`] before species existed (simulation schema 5 and earlier,
+/// ensemble schema 1), read when migrating old files.
+#[derive(Debug, Clone, Copy, serde::Deserialize)]
+pub(crate) struct LegacyBird {
+    position: Vec3,
+    velocity: Vec3,
+}
+
+impl From<LegacyBird> for Bird {
+    fn from(legacy: LegacyBird) -> Self {
+        Bird::new(legacy.position, legacy.velocity)
+    }
+}
+
 impl Bird {
     /// Creates a new bird from Cartesian position and velocity vectors.
     ///
@@ -63,7 +83,24 @@ impl Bird {
     /// * `velocity` - 3D velocity vector (**should be tangent to sphere surface**)
     ///
     fn new(position: Vec3, velocity: Vec3) -> Self {
-        Bird { position, velocity }
+        Bird {
+            position,
+            velocity,
+            species: 0,
+        }
+    }
+
+    /// Returns this bird assigned to `species`, keeping position and velocity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use flocking_lib::bird::Bird;
+    /// let bird = Bird::from_spherical(1.0, 1.0, 0.0, 1.0, 0.0).with_species(1);
+    /// assert_eq!(bird.species, 1);
+    /// ```
+    pub fn with_species(self, species: u8) -> Self {
+        Bird { species, ..self }
     }
 
     /// Creates a new bird from spherical coordinates with velocity parameterization.
@@ -97,6 +134,10 @@ impl Bird {
     /// velocity = speed * (cos(α) * e_φ + sin(α) * e_θ)
     /// ```
     ///
+    /// # Panics
+    ///
+    /// Panics if any input is rejected by [`Bird::try_from_spherical`].
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -113,15 +154,68 @@ impl Bird {
     /// let bird_random = Bird::from_spherical(2.0, PI/3.0, PI/4.0, 1.0, PI/6.0);
     /// ```
     pub fn from_spherical(radius: f64, theta: f64, phi: f64, speed: f64, alpha: f64) -> Self {
+        Self::try_from_spherical(radius, theta, phi, speed, alpha)
+            .unwrap_or_else(|e| panic!("Invalid input: {}", e))
+    }
+
+    /// Creates a new bird from spherical coordinates, validating every input.
+    ///
+    /// This is the non-panicking counterpart of [`Bird::from_spherical`], intended for
+    /// user-supplied data. The same conversion is applied once all inputs pass validation.
+    ///
+    /// # Arguments
+    ///
+    /// * `radius` - Distance from sphere center, must be finite and strictly positive
+    /// * `theta` - Polar angle from positive z-axis in radians, must lie in [0, π]
+    /// * `phi` - Azimuthal angle from positive x-axis in radians, must be finite
+    /// * `speed` - Magnitude of velocity vector, must be finite and non-negative
+    /// * `alpha` - Direction angle of velocity in local tangent plane, must be finite
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Bird)` - The constructed bird
+    /// * `Err(String)` - Descriptive message naming the first rejected input
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use flocking_lib::bird::Bird;
+    /// use std::f64::consts::PI;
+    ///
+    /// assert!(Bird::try_from_spherical(1.0, PI / 2.0, 0.0, 1.0, 0.0).is_ok());
+    /// assert!(Bird::try_from_spherical(-1.0, PI / 2.0, 0.0, 1.0, 0.0).is_err());
+    /// assert!(Bird::try_from_spherical(1.0, 4.0, 0.0, 1.0, 0.0).is_err());
+    /// ```
+    pub fn try_from_spherical(
+        radius: f64,
+        theta: f64,
+        phi: f64,
+        speed: f64,
+        alpha: f64,
+    ) -> Result<Self, String> {
+        use std::f64::consts::PI;
+
         // Validate input parameters
-        if radius.is_nan()
-            || theta.is_nan()
-            || phi.is_nan()
-            || speed.is_nan()
-            || alpha.is_nan()
-            || radius < f64::EPSILON
-        {
-            panic!("Invalid input");
+        if !radius.is_finite() || radius < f64::EPSILON {
+            return Err(format!(
+                "radius must be finite and positive, got {}",
+                radius
+            ));
+        }
+        if !(0.0..=PI).contains(&theta) {
+            return Err(format!("theta must lie in [0, π], got {}", theta));
+        }
+        if !phi.is_finite() {
+            return Err(format!("phi must be finite, got {}", phi));
+        }
+        if !speed.is_finite() || speed < 0.0 {
+            return Err(format!(
+                "speed must be finite and non-negative, got {}",
+                speed
+            ));
+        }
+        if !alpha.is_finite() {
+            return Err(format!("alpha must be finite, got {}", alpha));
         }
 
         // Convert spherical coordinates to Cartesian position
@@ -141,7 +235,111 @@ impl Bird {
         // Construct velocity vector in local tangent plane
         let velocity = speed * (alpha.cos() * phi_hat + alpha.sin() * theta_hat);
 
-        Self::new(position, velocity)
+        Ok(Self::new(position, velocity))
+    }
+
+    /// Returns the local orthonormal tangent basis `(e_θ, e_φ)` at this bird's position.
+    ///
+    /// These are the unit vectors of increasing polar angle `θ` and azimuth `φ` used by
+    /// [`Bird::from_spherical`], so a velocity built there with direction angle `α` equals
+    /// `speed · (cos α · e_φ + sin α · e_θ)`. Only the direction of the position matters.
+    ///
+    /// # Poles
+    ///
+    /// At the poles `φ` is undefined. The basis of `φ = 0` is used instead, matching
+    /// `from_spherical` with `phi = 0`: `e_φ = (0, 1, 0)` and `e_θ = e_φ × r̂`, i.e.
+    /// `(1, 0, 0)` at the north pole and `(-1, 0, 0)` at the south pole.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use flocking_lib::bird::Bird;
+    /// # use flocking_lib::vector::Vec3;
+    /// let bird = Bird{position: Vec3::new(2.0, 0.0, 0.0), velocity: Vec3::zero(), species: 0};
+    /// let (e_theta, e_phi) = bird.tangent_basis();
+    /// assert!(e_theta.approx_eq(&Vec3::new(0.0, 0.0, -1.0), 1e-15));
+    /// assert!(e_phi.approx_eq(&Vec3::new(0.0, 1.0, 0.0), 1e-15));
+    /// ```
+    pub fn tangent_basis(&self) -> (Vec3, Vec3) {
+        let normal = self.position.normalize();
+        let rho = (normal.x * normal.x + normal.y * normal.y).sqrt();
+
+        let e_phi = if rho > f64::EPSILON {
+            Vec3::new(-normal.y / rho, normal.x / rho, 0.0)
+        } else {
+            Vec3::y_hat()
+        };
+        let e_theta = e_phi.cross(&normal);
+
+        (e_theta, e_phi)
+    }
+
+    /// Returns the direction angle `α` of the velocity in the local tangent plane.
+    ///
+    /// Inverts the parameterization of [`Bird::from_spherical`]: with `(e_θ, e_φ)` from
+    /// [`Bird::tangent_basis`], `velocity = speed · (cos α · e_φ + sin α · e_θ)`, so
+    /// `α = atan2(v · e_θ, v · e_φ)` in `(-π, π]`. Any radial velocity component is
+    /// ignored, and a bird at rest has heading `0`.
+    ///
+    /// # Poles
+    ///
+    /// At the poles the heading is measured in the fallback basis of `φ = 0` described
+    /// in [`Bird::tangent_basis`], which is the frame `from_spherical` uses there, so
+    /// round trips still hold. Near (but not at) a pole the basis rotates quickly with
+    /// `φ`, so headings of nearby birds there are not directly comparable.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use flocking_lib::bird::Bird;
+    /// let bird = Bird::from_spherical(1.0, 1.0, 2.0, 1.5, 0.7);
+    /// assert!((bird.heading() - 0.7).abs() < 1e-12);
+    /// ```
+    pub fn heading(&self) -> f64 {
+        let (e_theta, e_phi) = self.tangent_basis();
+        self.velocity.dot(&e_theta).atan2(self.velocity.dot(&e_phi))
+    }
+
+    /// Returns `true` if both position and velocity are finite.
+    ///
+    /// A non-finite bird signals that a simulation has broken down numerically.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use flocking_lib::bird::Bird;
+    /// # use flocking_lib::vector::Vec3;
+    /// let bird = Bird{position: Vec3::x_hat(), velocity: Vec3::y_hat(), species: 0};
+    /// assert!(bird.is_finite());
+    ///
+    /// let broken = Bird {
+    ///     position: Vec3::x_hat(),
+    ///     velocity: Vec3::new(f64::NAN, 0.0, 0.0),
+    ///     species: 0,
+    /// };
+    /// assert!(!broken.is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self.position.is_finite() && self.velocity.is_finite()
+    }
+
+    /// Checks if two birds are approximately equal within a given tolerance.
+    ///
+    /// Both position and velocity are compared with [`Vec3::approx_eq`], i.e. per
+    /// component. Use `==` for exact comparison.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use flocking_lib::bird::Bird;
+    /// let bird = Bird::from_spherical(1.0, 0.5, 0.0, 1.0, 0.0);
+    /// let moved = bird.move_on_sphere(1e-9, 1.0, 1.0);
+    /// assert!(bird.approx_eq(&moved, 1e-6));
+    /// assert!(bird != moved);
+    /// ```
+    pub fn approx_eq(&self, other: &Bird, epsilon: f64) -> bool {
+        self.position.approx_eq(&other.position, epsilon)
+            && self.velocity.approx_eq(&other.velocity, epsilon)
     }
 }
 impl Display for Bird {
@@ -163,7 +361,9 @@ impl Display for Bird {
         );
 
         // Calculate spherical coordinates for additional context
-        let theta = pos_norm.atan2((self.position.x.powi(2) + self.position.y.powi(2)).sqrt());
+        let theta = (self.position.x.powi(2) + self.position.y.powi(2))
+            .sqrt()
+            .atan2(self.position.z);
         let phi = self.position.y.atan2(self.position.x);
 
         write!(
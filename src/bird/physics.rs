@@ -6,6 +6,7 @@
 //! including geodesic distances, parallel transport of vectors, and stochastic dynamics.
 
 use crate::bird::Bird;
+use crate::constants::CONSTRAINT_TOLERANCE;
 use crate::vector::Vec3;
 
 impl Bird {
@@ -36,8 +37,8 @@ impl Bird {
     /// ```rust
     /// # use flocking_lib::bird::Bird;
     /// # use flocking_lib::vector::Vec3;
-    /// let bird1 = Bird{position:Vec3::new(1.0, 0.0, 0.0), velocity:Vec3::zero()};
-    /// let bird2 = Bird{position: Vec3::new(0.0, 1.0, 0.0), velocity: Vec3::zero()};
+    /// let bird1 = Bird{position:Vec3::new(1.0, 0.0, 0.0), velocity:Vec3::zero(), species: 0};
+    /// let bird2 = Bird{position: Vec3::new(0.0, 1.0, 0.0), velocity: Vec3::zero(), species: 0};
     /// let distance = bird1.distance_from(&bird2, 1.0); // π/2 ≈ 1.57
     /// ```
     pub fn distance_from(&self, other: &Bird, radius: f64) -> f64 {
@@ -68,27 +69,47 @@ impl Bird {
     /// - **angle** = arccos(**r₁** · **r₂** / (|**r₁**| × |**r₂**|))
     /// - **v'** = Rotate(**v**, **axis**, **angle**)
     ///
+    /// Only the directions of the two positions enter, so the result is the same on a
+    /// sphere of any radius; the radii of `self` and `base` need not even agree.
+    ///
     /// # Special Cases
     ///
-    /// When the two positions are identical or antipodal (axis ≈ 0), the original
-    /// velocity is returned unchanged as no transport is needed.
+    /// When the two positions are identical (axis ≈ 0), the original velocity is
+    /// returned unchanged as no transport is needed.
+    ///
+    /// When the positions are antipodal, every great circle through them is a shortest
+    /// path, so parallel transport is inherently ambiguous. The velocity is then
+    /// transported along the great circle the bird is travelling on (axis **r** × **v**),
+    /// which maps **v** to **-v**. For a zero velocity a fixed axis orthogonal to
+    /// **r** is used instead. Either way the result keeps the original magnitude and is
+    /// tangent to the sphere at the target position.
     ///
     /// # Examples
     ///
     /// ```rust
     /// # use flocking_lib::bird::Bird;
     /// # use flocking_lib::vector::Vec3;
-    /// let bird1 = Bird {position: Vec3::new(1.0, 0.0, 0.0), velocity: Vec3::new(0.0, 1.0, 0.0) };
-    /// let bird2 = Bird{position: Vec3::new(0.0, 1.0, 0.0), velocity: Vec3::zero()};
+    /// let bird1 = Bird {
+    ///     position: Vec3::new(1.0, 0.0, 0.0),
+    ///     velocity: Vec3::new(0.0, 1.0, 0.0),
+    ///     species: 0,
+    /// };
+    /// let bird2 = Bird{position: Vec3::new(0.0, 1.0, 0.0), velocity: Vec3::zero(), species: 0};
     /// let transported_vel = bird1.parallel_transport_velocity(&bird2);
     /// ```
     pub fn parallel_transport_velocity(&self, base: &Bird) -> Vec3 {
         let angle = self.position.angle_between(&base.position);
-        let axis = self.position.cross(&base.position).normalize();
-        if axis.approx_eq(&Vec3::zero(), 1e-10) {
-            // If the axis is zero, return the original velocity
-            return self.velocity;
-        }
+        let axis = match self.position.cross(&base.position).try_normalize() {
+            Some(axis) => axis,
+            // Identical positions, no transport needed
+            None if self.position.dot(&base.position) >= 0.0 => return self.velocity,
+            // Antipodal positions, follow the bird's own great circle
+            None => self
+                .position
+                .cross(&self.velocity)
+                .try_normalize()
+                .unwrap_or_else(|| self.position.orthogonal_unit()),
+        };
         match self.velocity.rotate_around(&axis, angle) {
             Some(velocity) => velocity,
             None => {
@@ -161,12 +182,15 @@ impl Bird {
     /// 2. Rotation axis: **n** = **r_base** / |**r_base**|
     /// 3. Apply rotation: **v'** = Rotate(**v_avg**, **n**, θ)
     ///
+    /// The axis is the unit normal, so the rotation does not depend on the sphere radius
+    /// and keeps a tangent `averaged` tangent and its magnitude unchanged.
+    ///
     /// # Examples
     ///
     /// ```rust
     /// # use flocking_lib::bird::Bird;
     /// # use flocking_lib::vector::Vec3;
-    /// let base_bird = Bird{position:Vec3::new(0.0, 0.0, 1.0), velocity:Vec3::zero()};
+    /// let base_bird = Bird{position:Vec3::new(0.0, 0.0, 1.0), velocity:Vec3::zero(), species: 0};
     /// let avg_velocity = Vec3::new(1.0, 0.0, 0.0);
     /// let noisy_vel = Bird::add_noise(avg_velocity, &base_bird, 0.2);
     /// ```
@@ -200,28 +224,58 @@ impl Bird {
     /// ```rust
     /// # use flocking_lib::bird::Bird;
     /// # use flocking_lib::vector::Vec3;
-    /// let bird = Bird{position:Vec3::new(1.0, 0.0, 0.0), velocity:Vec3::new(0.0, 2.0, 0.0)};
+    /// let bird = Bird {
+    ///     position: Vec3::new(1.0, 0.0, 0.0),
+    ///     velocity: Vec3::new(0.0, 2.0, 0.0),
+    ///     species: 0,
+    /// };
     /// let new_bird = bird.move_on_sphere(0.1, 1.0, 2.0);
     /// ```
     pub fn move_on_sphere(&self, dt: f64, radius: f64, speed: f64) -> Self {
+        self.try_move_on_sphere(dt, radius, speed)
+            .unwrap_or_else(|message| panic!("{}", message))
+    }
+
+    /// Moves this bird along the sphere surface, reporting constraint violations as errors.
+    ///
+    /// Performs the same geodesic step as [`Bird::move_on_sphere`], but returns `Err`
+    /// with a description of the mismatch instead of panicking when the bird's speed
+    /// differs from `speed`, or its distance from the origin from `radius`, by more than
+    /// [`CONSTRAINT_TOLERANCE`] relative to the expected value.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use flocking_lib::bird::Bird;
+    /// # use flocking_lib::vector::Vec3;
+    /// let bird = Bird {
+    ///     position: Vec3::new(1.0, 0.0, 0.0),
+    ///     velocity: Vec3::new(0.0, 2.0, 0.0),
+    ///     species: 0,
+    /// };
+    /// assert!(bird.try_move_on_sphere(0.1, 1.0, 2.0).is_ok());
+    /// assert!(bird.try_move_on_sphere(0.1, 1.0, 2.1).is_err());
+    /// ```
+    pub fn try_move_on_sphere(&self, dt: f64, radius: f64, speed: f64) -> Result<Self, String> {
         // Validate that the speed parameter matches the actual velocity magnitude
         let actual_speed = self.velocity.norm();
         let actual_radius = self.position.norm();
-        if (actual_speed - speed).abs() > 1e-10 {
-            panic!(
+        // Rounding error grows with both magnitudes, so compare them relatively
+        if (actual_speed - speed).abs() > CONSTRAINT_TOLERANCE * speed {
+            return Err(format!(
                 "Speed parameter ({}) doesn't match bird velocity magnitude ({}). Difference: {}",
                 speed,
                 actual_speed,
                 (actual_speed - speed).abs()
-            );
+            ));
         }
-        if (actual_radius - radius).abs() > 1e-10 {
-            panic!(
+        if (actual_radius - radius).abs() > CONSTRAINT_TOLERANCE * radius {
+            return Err(format!(
                 "Position radius ({}) doesn't match expected sphere radius ({}). Difference: {}",
                 actual_radius,
                 radius,
                 (actual_radius - radius).abs()
-            );
+            ));
         }
 
         // Calculate new position using geodesic motion
@@ -233,6 +287,138 @@ impl Bird {
         let velocity =
             self.velocity * angle.cos() - (speed * angle.sin()) * self.position.normalize();
 
-        Bird { position, velocity }
+        Ok(Bird {
+            position,
+            velocity,
+            species: self.species,
+        })
+    }
+
+    /// Projects this bird back onto the sphere of `radius` with tangent velocity of `speed`.
+    ///
+    /// The position is rescaled to exactly `radius`; the velocity has its radial
+    /// component removed and is rescaled to exactly `speed`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use flocking_lib::bird::Bird;
+    /// # use flocking_lib::vector::Vec3;
+    /// let bird = Bird {
+    ///     position: Vec3::new(1.1, 0.0, 0.0),
+    ///     velocity: Vec3::new(0.1, 2.0, 0.0),
+    ///     species: 0,
+    /// };
+    /// let projected = bird.project_onto_sphere(1.0, 2.0);
+    /// assert_eq!(projected.position, Vec3::new(1.0, 0.0, 0.0));
+    /// assert_eq!(projected.velocity, Vec3::new(0.0, 2.0, 0.0));
+    /// ```
+    pub fn project_onto_sphere(&self, radius: f64, speed: f64) -> Self {
+        let normal = self.position.normalize();
+        let tangent = self.velocity - normal * normal.dot(&self.velocity);
+
+        Bird {
+            position: normal * radius,
+            velocity: tangent.normalize() * speed,
+            species: self.species,
+        }
+    }
+
+    /// Moves this bird like [`Bird::move_on_sphere`] and then removes accumulated drift.
+    ///
+    /// After the geodesic step the position is rescaled to exactly `radius`, the
+    /// velocity is projected onto the tangent plane at the new position and rescaled
+    /// to exactly `speed`. Each step of the plain integrator loses a few ulps of the
+    /// constraints; over long runs this drift can exceed the [`CONSTRAINT_TOLERANCE`]
+    /// that `move_on_sphere` enforces on its input, which the correction prevents.
+    ///
+    /// # Panics
+    ///
+    /// Panics under the same conditions as [`Bird::move_on_sphere`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use flocking_lib::bird::Bird;
+    /// # use flocking_lib::vector::Vec3;
+    /// let mut bird = Bird {
+    ///     position: Vec3::new(1.0, 0.0, 0.0),
+    ///     velocity: Vec3::new(0.0, 2.0, 0.0),
+    ///     species: 0,
+    /// };
+    /// for _ in 0..1000 {
+    ///     bird = bird.move_on_sphere_corrected(0.01, 1.0, 2.0);
+    /// }
+    /// assert!((bird.position.norm() - 1.0).abs() < 1e-14);
+    /// ```
+    pub fn move_on_sphere_corrected(&self, dt: f64, radius: f64, speed: f64) -> Self {
+        self.move_on_sphere(dt, radius, speed)
+            .project_onto_sphere(radius, speed)
+    }
+
+    /// Interpolates between this bird and `other` along the shortest great circle.
+    ///
+    /// The position is spherically interpolated (slerp): it is rotated from this bird's
+    /// position towards `other`'s by the fraction `t` of the angle between them and
+    /// placed on the sphere of `radius`. For the velocity, both endpoint velocities are
+    /// parallel-transported along the same great circle to the interpolated position,
+    /// blended linearly, projected onto the tangent plane, and rescaled to the linearly
+    /// interpolated speed. `t = 0` and `t = 1` reproduce the endpoints up to rounding.
+    /// The result keeps this bird's species.
+    ///
+    /// # Special Cases
+    ///
+    /// For antipodal positions every great circle is a shortest path; as in
+    /// [`Bird::parallel_transport_velocity`], the circle this bird travels on is used
+    /// (or a fixed one if it is at rest). If the blended velocity vanishes (opposite
+    /// transported velocities), the interpolated bird is at rest.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use flocking_lib::bird::Bird;
+    /// # use flocking_lib::vector::Vec3;
+    /// let a = Bird {
+    ///     position: Vec3::new(1.0, 0.0, 0.0),
+    ///     velocity: Vec3::new(0.0, 1.0, 0.0),
+    ///     species: 0,
+    /// };
+    /// let b = Bird {
+    ///     position: Vec3::new(0.0, 1.0, 0.0),
+    ///     velocity: Vec3::new(-1.0, 0.0, 0.0),
+    ///     species: 0,
+    /// };
+    /// let halfway = a.interpolate(&b, 0.5, 1.0);
+    /// let expected = Vec3::new(1.0, 1.0, 0.0).normalize();
+    /// assert!(halfway.position.approx_eq(&expected, 1e-12));
+    /// ```
+    pub fn interpolate(&self, other: &Bird, t: f64, radius: f64) -> Bird {
+        let angle = self.position.angle_between(&other.position);
+        let axis = match self.position.cross(&other.position).try_normalize() {
+            Some(axis) => axis,
+            // Identical directions: no rotation, any axis works
+            None if self.position.dot(&other.position) >= 0.0 => self.position.orthogonal_unit(),
+            // Antipodal positions, follow this bird's own great circle
+            None => self
+                .position
+                .cross(&self.velocity)
+                .try_normalize()
+                .unwrap_or_else(|| self.position.orthogonal_unit()),
+        };
+        let rotate = |v: &Vec3, by: f64| v.rotate_around(&axis, by).unwrap_or(*v);
+
+        let normal = rotate(&self.position, t * angle).normalize();
+        let from_self = rotate(&self.velocity, t * angle);
+        let from_other = rotate(&other.velocity, -(1.0 - t) * angle);
+
+        let blended = from_self * (1.0 - t) + from_other * t;
+        let tangent = blended - normal * normal.dot(&blended);
+        let speed = self.velocity.norm() * (1.0 - t) + other.velocity.norm() * t;
+
+        Bird {
+            position: normal * radius,
+            velocity: tangent.normalize() * speed,
+            species: self.species,
+        }
     }
 }
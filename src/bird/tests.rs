@@ -223,4 +223,288 @@ mod units {
         // Movements should be in different directions
         assert!((moved_x.position - moved_y.position).norm() > 0.1);
     }
+
+    #[test]
+    fn try_from_spherical_valid() {
+        let bird = Bird::try_from_spherical(2.0, PI / 3.0, PI / 4.0, 1.5, PI / 6.0).unwrap();
+        let reference = Bird::from_spherical(2.0, PI / 3.0, PI / 4.0, 1.5, PI / 6.0);
+
+        assert_eq!(bird, reference);
+
+        // Boundary values are accepted
+        assert!(Bird::try_from_spherical(1.0, 0.0, 0.0, 0.0, 0.0).is_ok());
+        assert!(Bird::try_from_spherical(1.0, PI, 0.0, 1.0, 0.0).is_ok());
+    }
+
+    #[test]
+    fn try_from_spherical_rejects_radius() {
+        assert!(Bird::try_from_spherical(0.0, PI / 2.0, 0.0, 1.0, 0.0).is_err());
+        assert!(Bird::try_from_spherical(-1.0, PI / 2.0, 0.0, 1.0, 0.0).is_err());
+        assert!(Bird::try_from_spherical(f64::NAN, PI / 2.0, 0.0, 1.0, 0.0).is_err());
+        assert!(Bird::try_from_spherical(f64::INFINITY, PI / 2.0, 0.0, 1.0, 0.0).is_err());
+
+        let err = Bird::try_from_spherical(-1.0, PI / 2.0, 0.0, 1.0, 0.0).unwrap_err();
+        assert!(err.contains("radius"));
+    }
+
+    #[test]
+    fn try_from_spherical_rejects_theta() {
+        assert!(Bird::try_from_spherical(1.0, -0.1, 0.0, 1.0, 0.0).is_err());
+        assert!(Bird::try_from_spherical(1.0, PI + 0.1, 0.0, 1.0, 0.0).is_err());
+        assert!(Bird::try_from_spherical(1.0, f64::NAN, 0.0, 1.0, 0.0).is_err());
+
+        let err = Bird::try_from_spherical(1.0, 4.0, 0.0, 1.0, 0.0).unwrap_err();
+        assert!(err.contains("theta"));
+    }
+
+    #[test]
+    fn try_from_spherical_rejects_phi() {
+        assert!(Bird::try_from_spherical(1.0, PI / 2.0, f64::NAN, 1.0, 0.0).is_err());
+        assert!(Bird::try_from_spherical(1.0, PI / 2.0, f64::INFINITY, 1.0, 0.0).is_err());
+
+        let err = Bird::try_from_spherical(1.0, PI / 2.0, f64::NAN, 1.0, 0.0).unwrap_err();
+        assert!(err.contains("phi"));
+    }
+
+    #[test]
+    fn try_from_spherical_rejects_speed() {
+        assert!(Bird::try_from_spherical(1.0, PI / 2.0, 0.0, -1.0, 0.0).is_err());
+        assert!(Bird::try_from_spherical(1.0, PI / 2.0, 0.0, f64::NAN, 0.0).is_err());
+
+        let err = Bird::try_from_spherical(1.0, PI / 2.0, 0.0, -1.0, 0.0).unwrap_err();
+        assert!(err.contains("speed"));
+    }
+
+    #[test]
+    fn try_from_spherical_rejects_alpha() {
+        assert!(Bird::try_from_spherical(1.0, PI / 2.0, 0.0, 1.0, f64::NAN).is_err());
+
+        let err = Bird::try_from_spherical(1.0, PI / 2.0, 0.0, 1.0, f64::NAN).unwrap_err();
+        assert!(err.contains("alpha"));
+    }
+
+    #[test]
+    #[should_panic(expected = "Invalid input")]
+    fn from_spherical_panics_on_invalid_input() {
+        Bird::from_spherical(1.0, PI + 1.0, 0.0, 1.0, 0.0);
+    }
+
+    #[test]
+    fn parallel_transport_antipodal() {
+        let radius = 1.0;
+        let speed = 1.5;
+
+        let positions = [
+            (PI / 2.0, 0.0, 0.0),
+            (PI / 3.0, PI / 4.0, PI / 6.0),
+            (0.0, 0.0, 1.0),
+            (2.0, 5.0, 4.0),
+        ];
+
+        for (theta, phi, alpha) in positions {
+            let bird = Bird::from_spherical(radius, theta, phi, speed, alpha);
+            let antipode = Bird::new(-bird.position, Vec3::zero());
+            let transported = bird.parallel_transport_velocity(&antipode);
+
+            // Magnitude preserved and tangent at the antipode
+            assert!((transported.norm() - speed).abs() < 1e-10);
+            assert!(transported.dot(&antipode.position).abs() < 1e-10);
+
+            // Transport along the bird's own great circle reverses the velocity
+            assert!(transported.approx_eq(&-bird.velocity, 1e-10));
+        }
+
+        // Zero velocity falls back to a fixed axis and stays zero
+        let still = Bird::new(Vec3::new(0.0, 0.0, 1.0), Vec3::zero());
+        let antipode = Bird::new(Vec3::new(0.0, 0.0, -1.0), Vec3::zero());
+        assert!(still.parallel_transport_velocity(&antipode).norm() < 1e-15);
+    }
+
+    #[test]
+    fn move_on_sphere_corrected_long_run() {
+        let radius = 2.0;
+        let speed = 1.3;
+        let dt = 0.01;
+
+        let mut bird = Bird::from_spherical(radius, PI / 3.0, PI / 4.0, speed, PI / 6.0);
+        for _ in 0..100_000 {
+            bird = bird.move_on_sphere_corrected(dt, radius, speed);
+        }
+
+        assert!((bird.position.norm() - radius).abs() < 1e-12);
+        assert!((bird.velocity.norm() - speed).abs() < 1e-12);
+        assert!(bird.velocity.dot(&bird.position).abs() < 1e-12);
+    }
+
+    #[test]
+    fn try_move_on_sphere_reports_mismatch() {
+        let bird = Bird::new(Vec3::new(1.0, 0.0, 0.0), Vec3::new(0.0, 2.0, 0.0));
+
+        let moved = bird.try_move_on_sphere(0.1, 1.0, 2.0).unwrap();
+        let expected = bird.move_on_sphere(0.1, 1.0, 2.0);
+        assert_eq!(moved, expected);
+
+        // Off by more than the 1e-10 tolerance in speed and in radius
+        let error = bird.try_move_on_sphere(0.1, 1.0, 2.0 + 1e-9).unwrap_err();
+        assert!(error.contains("Speed parameter"));
+        let error = bird.try_move_on_sphere(0.1, 1.0 + 1e-9, 2.0).unwrap_err();
+        assert!(error.contains("Position radius"));
+
+        // Projection repairs the drift
+        let drifted = Bird::new(Vec3::new(1.0 + 1e-9, 0.0, 0.0), Vec3::new(1e-9, 2.0, 0.0));
+        assert!(drifted.try_move_on_sphere(0.1, 1.0, 2.0).is_err());
+        assert!(
+            drifted
+                .project_onto_sphere(1.0, 2.0)
+                .try_move_on_sphere(0.1, 1.0, 2.0)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn is_finite_detects_nan_and_inf() {
+        let bird = Bird::from_spherical(1.0, PI / 3.0, 0.5, 1.0, 0.2);
+        assert!(bird.is_finite());
+
+        let bad_position = Bird::new(Vec3::new(f64::NAN, 0.0, 0.0), bird.velocity);
+        let bad_velocity = Bird::new(bird.position, Vec3::new(0.0, 0.0, f64::INFINITY));
+        assert!(!bad_position.is_finite());
+        assert!(!bad_velocity.is_finite());
+    }
+
+    #[test]
+    fn approx_eq_and_exact_equality() {
+        let bird = Bird::from_spherical(1.0, PI / 3.0, 0.5, 1.0, 0.2);
+        let nudged = Bird::new(
+            bird.position + Vec3::new(1e-12, 0.0, 0.0),
+            bird.velocity - Vec3::new(0.0, 0.0, 1e-12),
+        );
+        assert!(bird.approx_eq(&nudged, 1e-10));
+        assert_ne!(bird, nudged);
+        assert_eq!(bird, bird.clone());
+
+        let elsewhere = Bird::from_spherical(1.0, PI / 2.0, 0.5, 1.0, 0.2);
+        let reversed = Bird::new(bird.position, -bird.velocity);
+        assert!(!bird.approx_eq(&elsewhere, 1e-3));
+        assert!(!bird.approx_eq(&reversed, 1e-3));
+    }
+
+    #[test]
+    fn tangent_basis_orthonormal_and_tangent() {
+        let positions = [
+            (PI / 2.0, 0.0),
+            (PI / 3.0, PI / 4.0),
+            (2.5, -1.0),
+            (0.0, 0.0),
+            (PI, 0.0),
+        ];
+
+        for (theta, phi) in positions {
+            let bird = Bird::from_spherical(2.0, theta, phi, 1.0, 0.0);
+            let (e_theta, e_phi) = bird.tangent_basis();
+
+            assert!((e_theta.norm() - 1.0).abs() < 1e-12);
+            assert!((e_phi.norm() - 1.0).abs() < 1e-12);
+            assert!(e_theta.dot(&e_phi).abs() < 1e-12);
+            assert!(e_theta.dot(&bird.position).abs() < 1e-12);
+            assert!(e_phi.dot(&bird.position).abs() < 1e-12);
+
+            // alpha = 0 points along e_phi, matching from_spherical
+            assert!(bird.velocity.approx_eq(&e_phi, 1e-12));
+        }
+
+        // Pole fallback
+        let north = Bird::new(Vec3::z_hat(), Vec3::zero());
+        let (e_theta, e_phi) = north.tangent_basis();
+        assert_eq!(e_phi, Vec3::y_hat());
+        assert!(e_theta.approx_eq(&Vec3::x_hat(), 1e-15));
+    }
+
+    #[test]
+    fn heading_round_trip() {
+        let alphas = [0.0, 0.7, PI / 2.0, 2.5, -1.2, -PI / 2.0, PI];
+        let positions = [(PI / 3.0, PI / 4.0), (2.0, -2.0), (0.0, 0.0), (PI, 0.0)];
+
+        for (theta, phi) in positions {
+            for alpha in alphas {
+                let bird = Bird::from_spherical(1.5, theta, phi, 2.0, alpha);
+                assert!((bird.heading() - alpha).abs() < 1e-12);
+            }
+        }
+
+        // Angles outside (-π, π] wrap around
+        let bird = Bird::from_spherical(1.0, 1.0, 0.5, 1.0, 2.0 * PI + 0.3);
+        assert!((bird.heading() - 0.3).abs() < 1e-12);
+    }
+
+    #[test]
+    fn interpolate_endpoints_and_path() {
+        let radius = 2.0;
+        let a = Bird::from_spherical(radius, PI / 3.0, 0.2, 1.5, 0.4);
+        let b = Bird::from_spherical(radius, PI / 2.0, 1.1, 1.0, -0.8);
+
+        assert!(a.interpolate(&b, 0.0, radius).approx_eq(&a, 1e-12));
+        assert!(a.interpolate(&b, 1.0, radius).approx_eq(&b, 1e-12));
+
+        for t in [0.25, 0.5, 0.75] {
+            let mid = a.interpolate(&b, t, radius);
+            assert!((mid.position.norm() - radius).abs() < 1e-12);
+            assert!(mid.velocity.dot(&mid.position).abs() < 1e-12);
+            assert!((mid.velocity.norm() - (1.5 * (1.0 - t) + t)).abs() < 1e-12);
+            // Geodesic distances split in proportion t : 1 - t
+            let total = a.distance_from(&b, radius);
+            assert!((a.distance_from(&mid, radius) - t * total).abs() < 1e-12);
+        }
+
+        // Antipodal endpoints move along a's own great circle
+        let c = Bird::new(-a.position, -a.velocity);
+        let mid = a.interpolate(&c, 0.5, radius);
+        assert!((mid.position.norm() - radius).abs() < 1e-12);
+        assert!(mid.position.dot(&a.position).abs() < 1e-12);
+        assert!(
+            mid.position
+                .normalize()
+                .approx_eq(&a.velocity.normalize(), 1e-12)
+        );
+        assert!(a.interpolate(&c, 1.0, radius).approx_eq(&c, 1e-12));
+    }
+
+    #[test]
+    fn display_reports_polar_angle_at_any_radius() {
+        for radius in [1.0, 5.0] {
+            let bird = Bird::from_spherical(radius, PI / 3.0, PI / 4.0, 2.0, 0.0);
+            let shown = bird.to_string();
+            assert!(shown.contains("θ: 60.00°"), "{shown}");
+            assert!(shown.contains("φ: 45.00°"), "{shown}");
+            assert!(shown.contains(&format!("|r|: {radius:.3}")), "{shown}");
+        }
+    }
+
+    #[test]
+    fn constraint_tolerance_bounds_move_and_rotation() {
+        use crate::constants::{CONSTRAINT_TOLERANCE, UNIT_AXIS_TOLERANCE};
+
+        let radius = 3.0;
+        let bird = Bird::from_spherical(radius, 1.0, 0.5, 2.0, 0.3);
+
+        // Speed and radius mismatches are both relative to the expected value
+        let slow = 2.0 * (1.0 + 0.5 * CONSTRAINT_TOLERANCE);
+        let fast = 2.0 * (1.0 + 2.0 * CONSTRAINT_TOLERANCE);
+        assert!(bird.try_move_on_sphere(0.01, radius, slow).is_ok());
+        assert!(bird.try_move_on_sphere(0.01, radius, fast).is_err());
+        let racer = Bird::from_spherical(radius, 1.0, 0.5, 1e6, 0.3);
+        let rounded = 1e6 * (1.0 + 0.5 * CONSTRAINT_TOLERANCE);
+        assert!(racer.try_move_on_sphere(1e-9, radius, rounded).is_ok());
+        let within = radius * (1.0 + 0.5 * CONSTRAINT_TOLERANCE);
+        let beyond = radius * (1.0 + 2.0 * CONSTRAINT_TOLERANCE);
+        assert!(bird.try_move_on_sphere(0.01, within, 2.0).is_ok());
+        assert!(bird.try_move_on_sphere(0.01, beyond, 2.0).is_err());
+
+        // Axes whose squared norm is within the tolerance of 1 count as unit axes
+        let v = Vec3::x_hat();
+        let slightly_long = Vec3::z_hat() * (1.0 + 0.25 * UNIT_AXIS_TOLERANCE);
+        let too_long = Vec3::z_hat() * (1.0 + UNIT_AXIS_TOLERANCE);
+        assert!(v.rotate_around(&slightly_long, PI / 2.0).is_some());
+        assert!(v.rotate_around(&too_long, PI / 2.0).is_none());
+    }
 }
@@ -2,41 +2,55 @@
 //!
 //! This module provides a command-line interface for the three-stage flocking simulation pipeline:
 //!
-//! ## Stage 1: Ensemble Generation (`generate-ensembles`)
-//!
-//! Creates multiple ensembles of birds in parallel using configurable threading. Each thread
-//! generates its own ensemble independently and saves it to a separate file in `./data/ensemble/`.
-//! This stage supports:
-//! - Parallel ensemble generation using multiple threads
-//! - Configurable ensemble size (number of ensembles to create)
-//! - Configurable birds per ensemble
-//! - Automatic file naming and organization
+//! ## Stage 1: Ensemble Generation (`ensemble`)
+//!
+//! Generates a batch of ensemble entries in parallel on a configurable number of threads
+//! and saves each one to `{data-dir}/ensemble/t{tag}-i{id}.bin` (or
+//! `{data-dir}/ensemble/{tag}/i{id}.bin` with the global `--tag-dirs` flag). This stage
+//! supports:
+//! - Parallel generation on a dedicated thread pool
+//! - Configurable entry count, birds per entry, radius, speed and minimum distance
+//! - Optional base seed for reproducible batches
+//! - Early rejection of infeasible minimum-distance constraints
 //! - Performance timing and reporting
 //!
 //! **Usage**:
 //! ```bash
-//! flok generate-ensembles --ensemble-count 10 --birds-per-ensemble 500 --threads 4
+//! vicseksim-rs ensemble --tag 1 --count 10 --num-birds 500 --min-distance 0.05 --threads 4
 //! ```
 //!
 //! ## Stage 2: Main Simulation (`simulate`)
 //!
-//! Runs the main flocking simulation using pre-generated ensembles as initial conditions.
-//! Processes multiple ensemble files and generates simulation trajectories for visualization
-//! and analysis.
+//! Runs the main flocking simulation using a pre-generated ensemble entry as initial
+//! conditions. The entry is loaded from `{data-dir}/ensemble/`, simulated with the given
+//! physics parameters, and the trajectory is saved to `{data-dir}/simulation/`.
 //!
 //! **Usage**:
 //! ```bash
-//! flok simulate --input-dir ./data/ensemble/ --steps 2000 --output-dir ./data/simulation/
+//! vicseksim-rs simulate --ensemble-tag 1 --ensemble-id 0 --iterations 2000 --eta 0.3
 //! ```
 //!
 //! ## Stage 3: Post Analysis (`analyze`)
 //!
-//! Performs statistical analysis and visualization of simulation results. Generates
-//! plots, statistical summaries, and other analytical outputs from simulation data.
+//! Loads a saved simulation result and reports, per snapshot, the step, timestamp,
+//! polarization order parameter, and number of clusters. The table is printed to
+//! stdout or written as CSV, optionally followed by the steady-state order after a
+//! burn-in.
+//!
+//! **Usage**:
+//! ```bash
+//! vicseksim-rs analyze --tag 1 --id 0 --burn-in 500 --csv ./data/analysis/t1-i0.csv
+//! ```
+//!
+//! ## Batch Runs (`batch`)
+//!
+//! Executes every simulation described in a TOML or JSON spec file (see
+//! [`simulation::BatchSpec`]), loading stored ensemble entries or generating seeded ones,
+//! and saves each result to `{data-dir}/simulation/`.
 //!
 //! **Usage**:
 //! ```bash
-//! flok analyze --input-dir ./data/simulation/ --output-dir ./data/analysis/
+//! vicseksim-rs batch ./campaign.toml
 //! ```
 //!
 //! ## Design Philosophy
@@ -51,85 +65,378 @@
 //! Each command includes timing information and progress reporting to help users
 //! understand performance characteristics and optimize their workflows.
 
+use crate::analysis;
+use crate::constants::ENTRY_VALIDATION_TOLERANCE;
+use crate::ensemble::{
+    self, EntryGenerationParams, EntryResult, InitialDistribution, VelocityInit,
+};
+use crate::io::{DataPersistence, DataRoot, DataType, bin};
+use crate::simulation::{self, SimulationParams, SimulationRequest, SimulationResult};
 use clap::{Parser, Subcommand};
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufWriter, Write};
 use std::path::PathBuf;
+use std::time::Instant;
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
 #[command(propagate_version = true)]
 pub struct Cli {
+    /// Root directory holding the ensemble, simulation, and analysis data folders
+    #[arg(long, global = true, default_value = "./data")]
+    pub data_dir: PathBuf,
+
+    /// Store files in per-tag sub-folders (`{type}/{tag}/i{id}.bin`) instead of flat
+    #[arg(long, global = true)]
+    pub tag_dirs: bool,
+
     #[command(subcommand)]
     pub command: Commands,
 }
 
 #[derive(Subcommand, Debug)]
 pub enum Commands {
-    /// Stage 1: Generate multiple ensembles in parallel for simulation input
-    GenerateEnsembles {
-        /// Number of ensembles to generate
-        #[arg(short = 'e', long, default_value_t = 10)]
-        ensemble_count: usize,
+    /// Stage 1: Generate ensemble entries in parallel for simulation input
+    #[command(alias = "generate-ensembles")]
+    Ensemble {
+        /// Numerical tag of the generated ensemble
+        #[arg(long)]
+        tag: usize,
 
-        /// Number of birds per ensemble
-        #[arg(short = 'b', long, default_value_t = 500)]
-        birds_per_ensemble: usize,
+        /// Number of entries to generate
+        #[arg(short, long, default_value_t = 10)]
+        count: usize,
 
-        /// Number of threads to use for parallel generation
-        #[arg(short, long, default_value_t = 4)]
+        /// Number of worker threads (0 uses all available cores)
+        #[arg(short, long, default_value_t = 0)]
         threads: usize,
 
+        /// Number of birds per entry
+        #[arg(short = 'b', long, default_value_t = 500)]
+        num_birds: usize,
+
         /// Sphere radius for bird positioning
         #[arg(short, long, default_value_t = 1.0)]
         radius: f64,
 
         /// Speed of all birds in the ensemble
-        #[arg(short, long, default_value_t = 2.0)]
+        #[arg(short, long, default_value_t = 1.0)]
         speed: f64,
 
-        /// Minimum allowed distance between birds
-        #[arg(short = 'd', long, default_value_t = 0.1)]
+        /// Minimum allowed geodesic distance between birds
+        #[arg(short = 'd', long, default_value_t = 0.01)]
         min_distance: f64,
 
-        /// Base tag for ensemble naming (will be appended with thread ID)
-        #[arg(long, default_value = "ensemble")]
-        tag: String,
+        /// Base seed for reproducible generation
+        #[arg(long)]
+        seed: Option<u64>,
     },
 
-    /// Stage 2: Run simulations from generated ensembles
+    /// Stage 2: Run a simulation from a generated ensemble entry
     Simulate {
-        /// Directory containing ensemble files to simulate
-        #[arg(short, long, default_value = "./data/ensemble/")]
-        input_dir: PathBuf,
+        /// Tag of the ensemble providing the initial conditions
+        #[arg(long)]
+        ensemble_tag: usize,
+
+        /// Entry id within the ensemble
+        #[arg(long)]
+        ensemble_id: usize,
+
+        /// Tag of the saved simulation (defaults to the ensemble tag)
+        #[arg(long)]
+        tag: Option<usize>,
 
-        /// Directory to save simulation results
-        #[arg(short, long, default_value = "./data/simulation/")]
-        output_dir: PathBuf,
+        /// Id of the saved simulation (defaults to the ensemble entry id)
+        #[arg(long)]
+        id: Option<usize>,
 
         /// Total number of time steps to run
-        #[arg(short, long, default_value_t = 2000)]
-        steps: u64,
+        #[arg(short = 'n', long, default_value_t = 2000)]
+        iterations: usize,
+
+        /// Number of steps between saved snapshots
+        #[arg(short, long, default_value_t = 10)]
+        frame_interval: usize,
+
+        /// Number of initial steps, counted in the total, that save no snapshots
+        #[arg(long, default_value_t = 0)]
+        warmup_steps: usize,
+
+        /// Integration time step
+        #[arg(long, default_value_t = 0.01)]
+        dt: f64,
 
         /// Interaction radius for alignment
         #[arg(long, default_value_t = 1.0)]
         interaction_radius: f64,
 
-        /// Noise parameter for random motion
-        #[arg(long, default_value_t = 0.1)]
-        noise: f64,
+        /// Noise strength η of the angular perturbation
+        #[arg(long, alias = "noise", default_value_t = 0.1)]
+        eta: f64,
+
+        /// Weight of the neighbor average against the own velocity (1 = Vicsek)
+        #[arg(long, default_value_t = 1.0)]
+        alignment_strength: f64,
     },
 
-    /// Stage 3: Analyze simulation results and generate visualizations
+    /// Stage 3: Report order parameter and cluster time series of a saved simulation
     Analyze {
-        /// Directory containing simulation result files
-        #[arg(short, long, default_value = "./data/simulation/")]
-        input_dir: PathBuf,
+        /// Tag of the saved simulation
+        #[arg(long)]
+        tag: usize,
+
+        /// Id of the saved simulation
+        #[arg(long)]
+        id: usize,
 
-        /// Directory to save analysis results and plots
-        #[arg(short, long, default_value = "./data/analysis/")]
-        output_dir: PathBuf,
+        /// Linking distance for cluster detection (defaults to the interaction radius)
+        #[arg(long)]
+        cluster_radius: Option<f64>,
+
+        /// Report the steady-state order of snapshots from this step on
+        #[arg(long)]
+        burn_in: Option<usize>,
+
+        /// Write the time series to this CSV file instead of stdout
+        #[arg(long)]
+        csv: Option<PathBuf>,
+    },
 
-        /// Types of analysis to perform
-        #[arg(long, value_delimiter = ',', default_values = ["order", "clustering", "trajectory"])]
-        analysis_types: Vec<String>,
+    /// Run every simulation described in a TOML or JSON batch spec
+    Batch {
+        /// Path of the spec file (`.json` for JSON, TOML otherwise)
+        spec: PathBuf,
     },
 }
+
+/// Executes the parsed command line.
+///
+/// Each subcommand prints human-readable progress to stdout; errors are returned to
+/// the caller so the binary can report them and exit with a non-zero status.
+pub fn run(cli: Cli) -> Result<(), Box<dyn Error>> {
+    let mut root = DataRoot::new(cli.data_dir);
+    if cli.tag_dirs {
+        root = root.with_tag_directories();
+    }
+
+    match cli.command {
+        Commands::Ensemble {
+            tag,
+            count,
+            threads,
+            num_birds,
+            radius,
+            speed,
+            min_distance,
+            seed,
+        } => {
+            let params = EntryGenerationParams {
+                num_birds,
+                radius,
+                speed,
+                min_distance,
+                distribution: InitialDistribution::Uniform,
+                velocity_init: VelocityInit::Random,
+            };
+            generate_ensemble(tag, count, threads, params, seed, &root)
+        }
+        Commands::Simulate {
+            ensemble_tag,
+            ensemble_id,
+            tag,
+            id,
+            iterations,
+            frame_interval,
+            warmup_steps,
+            dt,
+            interaction_radius,
+            eta,
+            alignment_strength,
+        } => {
+            let entry = ensemble::io::load_ensemble(&root, ensemble_tag, ensemble_id)?;
+            entry
+                .validate(ENTRY_VALIDATION_TOLERANCE)
+                .map_err(|violations| {
+                    format!(
+                        "ensemble t{}-i{} is invalid: {}",
+                        ensemble_tag,
+                        ensemble_id,
+                        violations.join("; ")
+                    )
+                })?;
+            let params = SimulationParams {
+                num_birds: entry.birds.len(),
+                radius: entry.params.radius,
+                speed: entry.params.speed,
+                dt,
+                interaction_radius,
+                eta,
+                total_iterations: iterations,
+                frame_interval,
+                alignment_strength,
+                warmup_steps,
+                ..Default::default()
+            };
+            let request = SimulationRequest::from_ensemble(
+                entry,
+                params,
+                id.unwrap_or(ensemble_id),
+                tag.unwrap_or(ensemble_tag),
+            )?;
+            simulate(request, &root)
+        }
+        Commands::Analyze {
+            tag,
+            id,
+            cluster_radius,
+            burn_in,
+            csv,
+        } => analyze(tag, id, cluster_radius, burn_in, csv, &root),
+        Commands::Batch { spec } => run_batch(&spec, &root),
+    }
+}
+
+fn run_batch(path: &std::path::Path, root: &DataRoot) -> Result<(), Box<dyn Error>> {
+    let spec = simulation::BatchSpec::load(path)?;
+    println!(
+        "Running {} simulations from {}{}",
+        spec.runs.len(),
+        path.display(),
+        if spec.parallel { " in parallel" } else { "" }
+    );
+
+    let start = Instant::now();
+    simulation::run_batch(&spec, root)?;
+    println!(
+        "Saved {} results to {} in {:.2?}",
+        spec.runs.len(),
+        root.folder(DataType::Simulation).display(),
+        start.elapsed()
+    );
+
+    Ok(())
+}
+
+fn generate_ensemble(
+    tag: usize,
+    count: usize,
+    threads: usize,
+    params: EntryGenerationParams,
+    seed: Option<u64>,
+    root: &DataRoot,
+) -> Result<(), Box<dyn Error>> {
+    params.validate()?;
+
+    let pool = rayon::ThreadPoolBuilder::new()
+        .num_threads(threads)
+        .build()?;
+    println!(
+        "Generating {} entries of {} birds with tag {} on {} threads",
+        count,
+        params.num_birds,
+        tag,
+        pool.current_num_threads()
+    );
+
+    let start = Instant::now();
+    let save = |entry: &EntryResult| bin::save_file(entry, root).map_err(|e| e.to_string());
+    let entries =
+        pool.install(|| ensemble::generate_parallel(tag, count, params, seed, Some(&save)))?;
+    println!(
+        "Saved {} entries to {} in {:.2?}",
+        entries.len(),
+        root.folder(DataType::Ensemble).display(),
+        start.elapsed()
+    );
+
+    Ok(())
+}
+
+fn simulate(request: SimulationRequest, root: &DataRoot) -> Result<(), Box<dyn Error>> {
+    let (tag, id) = (request.tag, request.id);
+    println!(
+        "Simulating {} birds for {} iterations (ensemble t{}-i{})",
+        request.params.num_birds,
+        request.params.total_iterations,
+        request.tag,
+        request.ensemble_entry_id
+    );
+
+    let start = Instant::now();
+    simulation::run(request, root)?;
+    println!("Finished in {:.2?}", start.elapsed());
+
+    let path = SimulationResult::binary_path_for(root, tag, id);
+    let result: SimulationResult = bin::load_file(&path)?;
+    println!(
+        "Saved {} snapshots to {}",
+        result.snapshots.len(),
+        path.display()
+    );
+    match result.snapshots.last() {
+        Some(last) => println!("Final order parameter: {:.6}", last.order_parameter()),
+        None if !result.final_state.is_empty() => println!(
+            "Final order parameter: {:.6}",
+            analysis::polarization(&result.final_state)
+        ),
+        None => println!("Final order parameter: n/a (no snapshots)"),
+    }
+
+    Ok(())
+}
+
+fn analyze(
+    tag: usize,
+    id: usize,
+    cluster_radius: Option<f64>,
+    burn_in: Option<usize>,
+    csv: Option<PathBuf>,
+    root: &DataRoot,
+) -> Result<(), Box<dyn Error>> {
+    let result: SimulationResult =
+        bin::load_file(&SimulationResult::binary_path_for(root, tag, id))?;
+    let cutoff = cluster_radius.unwrap_or(result.params.interaction_radius);
+
+    let mut writer: Box<dyn Write> = match &csv {
+        Some(path) => {
+            if let Some(parent) = path.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            Box::new(BufWriter::new(File::create(path)?))
+        }
+        None => Box::new(std::io::stdout().lock()),
+    };
+
+    writeln!(writer, "step,timestamp,order_parameter,clusters")?;
+    for snapshot in &result.snapshots {
+        let clusters = analysis::find_clusters(&snapshot.birds, result.params.radius, cutoff);
+        writeln!(
+            writer,
+            "{},{},{},{}",
+            snapshot.step,
+            snapshot.timestamp,
+            snapshot.order_parameter(),
+            clusters.len()
+        )?;
+    }
+    writer.flush()?;
+    drop(writer);
+
+    if let Some(path) = &csv {
+        println!(
+            "Wrote {} snapshots to {}",
+            result.snapshots.len(),
+            path.display()
+        );
+    }
+    if let Some(burn_in) = burn_in {
+        let (mean, error) = result.steady_state_order(burn_in);
+        println!(
+            "Steady-state order (step >= {}): {:.6} ± {:.6}",
+            burn_in, mean, error
+        );
+    }
+
+    Ok(())
+}
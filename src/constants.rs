@@ -0,0 +1,40 @@
+//! # Numerical Tolerances
+//!
+//! Named tolerances used by the physics, the step logic, and the consistency checks,
+//! collected here so the numerical behavior of the library can be audited in one place.
+//! Plain `f64::EPSILON` guards against dividing by an (effectively) zero norm are not
+//! listed; they are not tunable and stay at their call sites.
+
+/// Largest relative deviation from the expected speed and radius that
+/// [`Bird::try_move_on_sphere`](crate::bird::Bird::try_move_on_sphere) accepts before
+/// reporting the bird as off the constraint surface.
+///
+/// Each geodesic step loses a few ulps, so long runs without drift correction approach
+/// this bound; the simulation projects offending birds back instead of aborting.
+pub const CONSTRAINT_TOLERANCE: f64 = 1e-10;
+
+/// Norm below which a blended target velocity counts as vanishing in the alignment step.
+///
+/// Opposing neighbors can cancel; normalizing the remainder would amplify rounding noise
+/// into an arbitrary heading, so the configured
+/// [`AlignmentFallback`](crate::simulation::AlignmentFallback) is applied instead.
+pub const DEGENERATE_VELOCITY: f64 = 1e-6;
+
+/// Largest deviation of `|axis|²` from 1 that
+/// [`Vec3::rotate_around`](crate::vector::Vec3::rotate_around) accepts as a unit axis.
+pub const UNIT_AXIS_TOLERANCE: f64 = f64::EPSILON * 10.0;
+
+/// Geodesic distance at or below which two birds are treated as sitting at the same
+/// position, so they are neither neighbors nor repel each other.
+pub const COINCIDENT_DISTANCE: f64 = f64::EPSILON;
+
+/// Largest relative difference at which the radius or speed of simulation parameters
+/// and an ensemble entry (or of two merged entries) count as equal.
+///
+/// Both sides are normally copies of the same configured value, so only rounding from
+/// serialization round trips needs to be absorbed.
+pub const PARAMETER_MATCH_TOLERANCE: f64 = 1e-12;
+
+/// Tolerance passed to [`EntryResult::validate`](crate::ensemble::EntryResult::validate)
+/// when the command line checks a stored ensemble entry before simulating it.
+pub const ENTRY_VALIDATION_TOLERANCE: f64 = 1e-9;
@@ -1,5 +1,6 @@
 use super::*;
-use crate::io::{DataPersistence, DataType, bin};
+use crate::bird::LegacyBird;
+use crate::io::{DataPersistence, DataRoot, DataType, bin, incompatible_schema};
 use std::sync::mpsc::Receiver;
 use std::thread;
 
@@ -9,6 +10,8 @@ use std::thread;
 /// data through the unified I/O system. It defines how ensemble entries are categorized,
 /// identified, and persisted to disk storage.
 impl DataPersistence for EntryResult {
+    const SCHEMA_VERSION: u32 = ENSEMBLE_SCHEMA_VERSION;
+
     /// Returns the data type identifier for ensemble entries.
     fn data_type() -> DataType {
         DataType::Ensemble
@@ -23,6 +26,47 @@ impl DataPersistence for EntryResult {
     fn tag(&self) -> usize {
         self.tag
     }
+
+    /// Upgrades schema 1 entries, whose birds have no species, and reads current entries
+    /// saved before files carried the [`FILE_MAGIC`](crate::io::FILE_MAGIC).
+    ///
+    /// A file without the magic is only accepted if it decodes exactly as the layout named
+    /// by its first four bytes; otherwise it predates schema versions and is rejected.
+    fn migrate(version: Option<u32>, bytes: &[u8]) -> Result<Self, String> {
+        let stored = version.or_else(|| bin::leading_version(bytes));
+        let migrated = match stored {
+            Some(1) => bin::decode_exact::<EntryResultV1>(bytes).map(EntryResult::from),
+            Some(ENSEMBLE_SCHEMA_VERSION) if version.is_none() => bin::decode_exact(bytes),
+            _ => return Err(incompatible_schema::<Self>(version)),
+        };
+
+        migrated.map_err(|e| match version {
+            Some(version) => format!("corrupt ensemble file of schema version {version}: {e}"),
+            None => incompatible_schema::<Self>(None),
+        })
+    }
+}
+
+/// Layout of [`EntryResult`] in ensemble schema 1, before birds had a species.
+#[derive(serde::Deserialize)]
+struct EntryResultV1 {
+    _schema_version: u32,
+    id: usize,
+    tag: usize,
+    birds: Vec<LegacyBird>,
+    params: EntryGenerationParams,
+}
+
+impl From<EntryResultV1> for EntryResult {
+    fn from(legacy: EntryResultV1) -> Self {
+        EntryResult {
+            schema_version: ENSEMBLE_SCHEMA_VERSION,
+            id: legacy.id,
+            tag: legacy.tag,
+            birds: legacy.birds.into_iter().map(Bird::from).collect(),
+            params: legacy.params,
+        }
+    }
 }
 
 /// Starts a dedicated I/O thread for concurrent ensemble data persistence.
@@ -50,7 +94,8 @@ impl DataPersistence for EntryResult {
 /// # Arguments
 ///
 /// * `rx` - MPSC receiver for [`EntryResult`] instances from generation workers.
-///          The receiver is moved into the thread for exclusive ownership.
+///   The receiver is moved into the thread for exclusive ownership.
+/// * `root` - [`DataRoot`] below which the entries are saved
 ///
 /// # Returns
 ///
@@ -66,14 +111,41 @@ impl DataPersistence for EntryResult {
 /// data integrity while maximizing successful saves.
 pub(super) fn start_receiver_thread(
     rx: Receiver<EntryResult>,
+    root: DataRoot,
 ) -> thread::JoinHandle<Result<(), String>> {
     thread::spawn(move || {
         // Continuously process ensemble results until channel closes
         while let Ok(entry_result) = rx.recv() {
             // Save each ensemble entry using binary serialization
             // Convert any I/O error to string for consistent error handling
-            bin::save_file(&entry_result).map_err(|e| e.to_string())?;
+            bin::save_file(&entry_result, &root).map_err(|e| e.to_string())?;
         }
         Ok(())
     })
 }
+
+/// Loads a saved ensemble entry `(tag, id)` from below `root`.
+///
+/// The uncompressed `.bin` file is preferred; if it does not exist the gzip-compressed
+/// `.bin.gz` variant is tried instead.
+pub fn load_ensemble(
+    root: &DataRoot,
+    tag: usize,
+    id: usize,
+) -> Result<EntryResult, Box<dyn std::error::Error>> {
+    let path = EntryResult::binary_path_for(root, tag, id);
+    if path.exists() {
+        return bin::load_file(&path);
+    }
+
+    let mut compressed = path.into_os_string();
+    compressed.push(".gz");
+    bin::load_file(&std::path::PathBuf::from(compressed))
+}
+
+/// Lists the `(tag, id)` pairs of all readable ensemble entries below `root`.
+///
+/// Unreadable files are skipped with a warning; see [`bin::list_tags_and_ids`].
+pub fn list_ensemble_tags_and_ids(root: &DataRoot) -> Result<Vec<(usize, usize)>, std::io::Error> {
+    bin::list_tags_and_ids::<EntryResult>(root)
+}
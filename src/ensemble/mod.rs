@@ -22,6 +22,7 @@
 //! - **[`EntryResult`]**: Complete ensemble data structure with metadata
 //! - **[`EntryGenerationParams`]**: Physics and constraint parameters  
 //! - **[`generate`]**: Main parallel generation interface
+//! - **[`generate_parallel`]**: Rayon-only generation returning entries in memory, optionally seeded
 //! - **[`io`]**: Concurrent persistence and serialization subsystem
 //!
 //! ### Generation Pipeline
@@ -122,8 +123,11 @@
 //! - **Custom Distributions**: Non-uniform initial distributions for specialized studies
 
 use crate::bird::Bird;
+use crate::constants::PARAMETER_MATCH_TOLERANCE;
+use crate::io::{DataRoot, DataType};
 use log::{debug, error, info, trace};
 use rayon::prelude::*;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::sync::mpsc;
 
 /// IO specific Implementations for ensemble data.
@@ -131,6 +135,9 @@ pub mod io;
 /// Unit tests for the ensemble module
 pub mod tests;
 
+/// Current schema version of stored [`EntryResult`] files.
+pub const ENSEMBLE_SCHEMA_VERSION: u32 = 2;
+
 /// Result structure containing a complete generated ensemble with metadata.
 ///
 /// This structure serves as the unified format for ensemble data throughout the system,
@@ -145,6 +152,9 @@ pub mod tests;
 /// * `params` - Original generation parameters preserved for reproducibility and analysis
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct EntryResult {
+    /// On-disk layout version; [`ENSEMBLE_SCHEMA_VERSION`] for entries built here.
+    /// Must stay the first field so loaders can check it before decoding the rest.
+    pub schema_version: u32,
     /// Unique identifier for this entry
     pub id: usize,
     /// Numerical tag for the ensemble used in file naming
@@ -155,6 +165,158 @@ pub struct EntryResult {
     pub params: EntryGenerationParams,
 }
 
+impl EntryResult {
+    /// Concatenates several entries into one larger entry.
+    ///
+    /// Birds keep their order, entry by entry. Every entry must have been generated with
+    /// a `min_distance` of at least the requested one, so pairs within an entry already
+    /// respect it; every pair drawn from two *different* entries is checked against
+    /// `min_distance` (geodesic, on the sphere of `radius`).
+    ///
+    /// The merged entry takes `id` and `tag` from the first entry. Its parameters are
+    /// those of the first entry with `num_birds`, `radius` and `min_distance` updated;
+    /// `distribution` and `velocity_init` fall back to their defaults unless all
+    /// entries agree on them.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message if `entries` is empty, an entry was generated on a different
+    /// radius, with a different speed or with a smaller `min_distance`, or two birds from
+    /// different entries are closer than `min_distance`. Radius and speed are compared
+    /// within [`PARAMETER_MATCH_TOLERANCE`] relative to the expected value.
+    pub fn merge(
+        entries: Vec<EntryResult>,
+        min_distance: f64,
+        radius: f64,
+    ) -> Result<EntryResult, String> {
+        let first = entries
+            .first()
+            .ok_or("cannot merge an empty list of entries")?;
+        let mut params = first.params;
+
+        for entry in &entries {
+            if (entry.params.radius - radius).abs() > PARAMETER_MATCH_TOLERANCE * radius {
+                return Err(format!(
+                    "entry {} was generated on radius {}, expected {}",
+                    entry.id, entry.params.radius, radius
+                ));
+            }
+            if (entry.params.speed - params.speed).abs() > PARAMETER_MATCH_TOLERANCE * params.speed
+            {
+                return Err(format!(
+                    "entry {} has speed {}, expected {}",
+                    entry.id, entry.params.speed, params.speed
+                ));
+            }
+            if entry.params.min_distance < min_distance {
+                return Err(format!(
+                    "entry {} was generated with min_distance {}, below {}",
+                    entry.id, entry.params.min_distance, min_distance
+                ));
+            }
+            if entry.params.distribution != params.distribution {
+                params.distribution = InitialDistribution::default();
+            }
+            if entry.params.velocity_init != params.velocity_init {
+                params.velocity_init = VelocityInit::default();
+            }
+        }
+
+        for (a, entry_a) in entries.iter().enumerate() {
+            for entry_b in &entries[a + 1..] {
+                for bird_a in &entry_a.birds {
+                    for bird_b in &entry_b.birds {
+                        let distance = bird_a.distance_from(bird_b, radius);
+                        if distance < min_distance {
+                            return Err(format!(
+                                "entries {} and {} have birds {} apart, closer than {}",
+                                entry_a.id, entry_b.id, distance, min_distance
+                            ));
+                        }
+                    }
+                }
+            }
+        }
+
+        let (id, tag) = (first.id, first.tag);
+        let birds: Vec<Bird> = entries.into_iter().flat_map(|entry| entry.birds).collect();
+        params.num_birds = birds.len();
+        params.radius = radius;
+        params.min_distance = min_distance;
+
+        Ok(EntryResult {
+            schema_version: ENSEMBLE_SCHEMA_VERSION,
+            id,
+            tag,
+            birds,
+            params,
+        })
+    }
+
+    /// Checks the physical invariants of every bird against the stored parameters.
+    ///
+    /// Intended for entries loaded from disk or produced by other tools, before they are
+    /// used as simulation initial conditions: the engine's geodesic integrator panics on
+    /// birds that are off the sphere or moving at the wrong speed.
+    ///
+    /// Each bird must satisfy, within the absolute tolerance `tol`:
+    /// - `|‖r‖ - params.radius| ≤ tol` (on the sphere)
+    /// - `|r̂ · v| ≤ tol` (velocity tangent to the sphere)
+    /// - `|‖v‖ - params.speed| ≤ tol` (prescribed speed)
+    ///
+    /// The number of birds must also equal `params.num_birds`.
+    ///
+    /// # Errors
+    ///
+    /// Returns every violation found, one message per failed check, rather than
+    /// stopping at the first.
+    pub fn validate(&self, tol: f64) -> Result<(), Vec<String>> {
+        let mut violations = Vec::new();
+        // Written so that NaN deviations count as violations
+        let within = |deviation: f64| deviation.abs() <= tol;
+
+        if self.birds.len() != self.params.num_birds {
+            violations.push(format!(
+                "expected {} birds, found {}",
+                self.params.num_birds,
+                self.birds.len()
+            ));
+        }
+
+        for (i, bird) in self.birds.iter().enumerate() {
+            let radius = bird.position.norm();
+            if !within(radius - self.params.radius) {
+                violations.push(format!(
+                    "bird {}: distance from center {} differs from radius {}",
+                    i, radius, self.params.radius
+                ));
+            }
+
+            let radial_velocity = bird.position.normalize().dot(&bird.velocity);
+            if !within(radial_velocity) {
+                violations.push(format!(
+                    "bird {}: velocity is not tangent (radial component {})",
+                    i, radial_velocity
+                ));
+            }
+
+            let speed = bird.velocity.norm();
+            if !within(speed - self.params.speed) {
+                violations.push(format!(
+                    "bird {}: speed {} differs from {}",
+                    i, speed, self.params.speed
+                ));
+            }
+        }
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
 /// Physical and numerical parameters controlling entry generation.
 ///
 /// These parameters define the physical properties of the generated entry and the
@@ -167,6 +329,8 @@ pub struct EntryResult {
 /// * `radius` - Radius of the spherical surface (typically 1.0 for unit sphere)
 /// * `speed` - Initial speed magnitude for all birds (velocity vector magnitude)
 /// * `min_distance` - Minimum geodesic distance constraint between any two birds
+/// * `distribution` - Region of the sphere in which birds are placed
+/// * `velocity_init` - How initial velocity directions are chosen
 #[derive(Debug, Copy, Clone, serde::Serialize, serde::Deserialize)]
 pub struct EntryGenerationParams {
     /// Number of particles to generate in this entry
@@ -177,8 +341,186 @@ pub struct EntryGenerationParams {
     pub speed: f64,
     /// Minimum allowed geodesic distance between birds
     pub min_distance: f64,
+    /// Spatial distribution of the initial positions
+    #[serde(default)]
+    pub distribution: InitialDistribution,
+    /// Initialization of the velocity directions
+    #[serde(default)]
+    pub velocity_init: VelocityInit,
+}
+
+/// Region of the sphere from which initial positions are drawn.
+///
+/// Polar angles `θ` are measured from the north pole (+Z). In every variant `φ` is
+/// uniform and `cos θ` is uniform over the allowed range, so the area density is
+/// uniform within the region.
+#[derive(Debug, Default, Copy, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum InitialDistribution {
+    /// Uniform over the whole sphere
+    #[default]
+    Uniform,
+    /// Uniform within the cap `0 ≤ θ ≤ max_theta` around the north pole
+    PolarCap { max_theta: f64 },
+    /// Uniform within the band `theta_min ≤ θ ≤ theta_max`
+    Band { theta_min: f64, theta_max: f64 },
+}
+
+impl InitialDistribution {
+    /// Returns the polar angle range `(theta_min, theta_max)` covered by the distribution.
+    pub fn theta_range(&self) -> (f64, f64) {
+        match *self {
+            InitialDistribution::Uniform => (0.0, std::f64::consts::PI),
+            InitialDistribution::PolarCap { max_theta } => (0.0, max_theta),
+            InitialDistribution::Band {
+                theta_min,
+                theta_max,
+            } => (theta_min, theta_max),
+        }
+    }
+
+    /// Returns the fraction of the sphere's area covered by the distribution.
+    pub fn area_fraction(&self) -> f64 {
+        let (theta_min, theta_max) = self.theta_range();
+        (theta_min.cos() - theta_max.cos()) / 2.0
+    }
+
+    /// Checks that the polar angle range is a non-empty subset of [0, π].
+    pub fn validate(&self) -> Result<(), String> {
+        let (theta_min, theta_max) = self.theta_range();
+        if !(0.0..=std::f64::consts::PI).contains(&theta_min)
+            || !(0.0..=std::f64::consts::PI).contains(&theta_max)
+            || theta_min >= theta_max
+        {
+            return Err(format!(
+                "invalid polar angle range [{}, {}] for {:?}",
+                theta_min, theta_max, self
+            ));
+        }
+        Ok(())
+    }
 }
 
+/// Initialization of the bird velocity directions.
+///
+/// Directions are given by the local tangent-plane angle `α` of
+/// [`Bird::from_spherical`], measured from `φ̂` towards `θ̂`.
+///
+/// # Aligned flows on the sphere
+///
+/// A common local `α` is a consistent tangent flow but not a globally parallel one:
+/// `α = 0` is a rigid rotation about the Z-axis (net angular momentum along Z but
+/// polarization ≈ 0 on the full sphere), while `α = π/2` flows along the meridians and is nearly
+/// parallel inside a narrow equatorial [`InitialDistribution::Band`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum VelocityInit {
+    /// Independent uniform random directions (disordered initial state)
+    #[default]
+    Random,
+    /// The same local direction `alpha` for every bird (ordered initial state)
+    Aligned { alpha: f64 },
+    /// Local directions drawn from a von Mises distribution around `mean_alpha`
+    ///
+    /// The concentration `kappa` interpolates between [`VelocityInit::Random`]
+    /// (`kappa = 0`) and [`VelocityInit::Aligned`] (`kappa = ∞`); the circular
+    /// spread is roughly `1 / √kappa` for large `kappa`.
+    VonMises { mean_alpha: f64, kappa: f64 },
+}
+
+/// Highest fraction of the sphere that the exclusion caps may cover in [`EntryGenerationParams::validate`].
+///
+/// Random sequential adsorption of disks jams at a coverage of about 0.547, beyond
+/// which rejection sampling never terminates; the limit keeps a safety margin below it.
+pub const MAX_COVERAGE: f64 = 0.5;
+
+impl EntryGenerationParams {
+    /// Checks that the parameters describe an entry rejection sampling can actually produce.
+    ///
+    /// Every bird excludes a spherical cap of geodesic radius `min_distance / 2`. The
+    /// fraction of the allowed region covered by `num_birds` such caps is
+    ///
+    /// ```text
+    /// coverage = num_birds · (1 - cos(min_distance / (2 · radius))) / (2 · area_fraction)
+    /// ```
+    ///
+    /// where `area_fraction` is [`InitialDistribution::area_fraction`] (1 for the whole
+    /// sphere). It must not exceed [`MAX_COVERAGE`], otherwise generation would stall.
+    ///
+    /// # Errors
+    ///
+    /// Returns a descriptive message if `num_birds` is zero, `radius` is not positive,
+    /// `speed` or `min_distance` is negative, any value (including an aligned `alpha`)
+    /// is non-finite, a von Mises `kappa` is negative or NaN, the distribution's angle
+    /// range is invalid, or the requested packing is infeasible.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.num_birds == 0 {
+            return Err("num_birds must be at least 1".to_string());
+        }
+        if !self.radius.is_finite() || self.radius <= 0.0 {
+            return Err(format!("radius must be positive, got {}", self.radius));
+        }
+        if !self.speed.is_finite() || self.speed < 0.0 {
+            return Err(format!("speed must be non-negative, got {}", self.speed));
+        }
+        if !self.min_distance.is_finite() || self.min_distance < 0.0 {
+            return Err(format!(
+                "min_distance must be non-negative, got {}",
+                self.min_distance
+            ));
+        }
+
+        self.distribution.validate()?;
+        match self.velocity_init {
+            VelocityInit::Aligned { alpha } if !alpha.is_finite() => {
+                return Err(format!("aligned alpha must be finite, got {}", alpha));
+            }
+            VelocityInit::VonMises { mean_alpha, .. } if !mean_alpha.is_finite() => {
+                return Err(format!(
+                    "von Mises mean_alpha must be finite, got {}",
+                    mean_alpha
+                ));
+            }
+            VelocityInit::VonMises { kappa, .. } if kappa.is_nan() || kappa < 0.0 => {
+                return Err(format!(
+                    "von Mises kappa must be non-negative, got {}",
+                    kappa
+                ));
+            }
+            _ => {}
+        }
+
+        if self.num_birds > 1 {
+            let half_angle = (self.min_distance / (2.0 * self.radius)).min(std::f64::consts::PI);
+            let coverage = self.num_birds as f64 * (1.0 - half_angle.cos())
+                / (2.0 * self.distribution.area_fraction());
+            if coverage > MAX_COVERAGE {
+                return Err(format!(
+                    "min_distance {} is infeasible for {} birds on radius {}: exclusion caps cover {:.1}% of the allowed region (limit {:.0}%)",
+                    self.min_distance,
+                    self.num_birds,
+                    self.radius,
+                    coverage * 100.0,
+                    MAX_COVERAGE * 100.0
+                ));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Progress callback invoked as `(completed, total)` during ensemble generation.
+///
+/// The callback may be called concurrently from several worker threads, hence the
+/// `Send + Sync` bounds. `completed` is monotonically increasing across calls and
+/// reaches `total` exactly once.
+pub type ProgressCallback = Box<dyn Fn(usize, usize) + Send + Sync>;
+
+/// Per-entry persistence hook used by [`generate_parallel`].
+///
+/// Called from rayon worker threads as each entry completes; returning `Err` aborts
+/// the batch and the message is propagated to the caller.
+pub type SaveFn<'a> = dyn Fn(&EntryResult) -> Result<(), String> + Sync + 'a;
+
 /// Internal request structure for coordinating ensemble generation across threads.
 ///
 /// This structure combines generation parameters with metadata needed for proper
@@ -210,11 +552,13 @@ struct EntryGenerationRequest {
 /// The function generates three independent random values:
 /// 1. **φ (azimuthal angle)**: Uniform distribution over [0, 2π]
 /// 2. **α (velocity direction)**: Uniform distribution over [0, 2π]
-/// 3. **cos(θ)**: Uniform distribution over [-1, 1], then θ = arccos(cos(θ))
+/// 3. **cos(θ)**: Uniform distribution over [cos θ_max, cos θ_min] of the requested
+///    [`InitialDistribution`] ([-1, 1] for the whole sphere), then θ = arccos(cos(θ))
 ///
 /// The key insight is that sampling cos(θ) uniformly (rather than θ directly) ensures
 /// uniform area distribution on the sphere surface, avoiding the pole clustering that
-/// would occur with naive uniform sampling of θ.
+/// would occur with naive uniform sampling of θ. Restricting the range of cos(θ) keeps
+/// the density uniform within caps and bands.
 ///
 /// # Returns
 ///
@@ -225,24 +569,190 @@ struct EntryGenerationRequest {
 ///
 /// # Thread Safety
 ///
-/// Uses thread-local random number generator (`rand::rng()`) for thread safety
-/// in parallel ensemble generation.
-fn random_bird() -> (f64, f64, f64) {
+/// Draws from the caller-supplied generator, which is either the thread-local
+/// `rand::rng()` or a per-entry seeded generator, so no state is shared between
+/// parallel workers.
+fn random_bird<R: rand::Rng + ?Sized>(
+    rng: &mut R,
+    distribution: InitialDistribution,
+) -> (f64, f64, f64) {
     use rand::prelude::*;
     use rand_distr::Uniform;
     use std::f64::consts::PI;
 
-    let mut rng = rand::rng();
     let angle_distribution = Uniform::new(0.0, 2.0 * PI).unwrap();
-    let cos_distribution = Uniform::new(-1.0, 1.0).unwrap();
+    let (theta_min, theta_max) = distribution.theta_range();
+    let cos_distribution = Uniform::new_inclusive(theta_max.cos(), theta_min.cos()).unwrap();
     // Generate uniform random spherical coordinates
-    let phi = angle_distribution.sample(&mut rng); // azimuthal angle [0, 2π]
-    let alpha = angle_distribution.sample(&mut rng); // velocity direction [0, 2π]
-    let cos_theta: f64 = cos_distribution.sample(&mut rng); // uniform cos(θ) [-1, 1]
+    let phi = angle_distribution.sample(rng); // azimuthal angle [0, 2π]
+    let alpha = angle_distribution.sample(rng); // velocity direction [0, 2π]
+    let cos_theta: f64 = cos_distribution.sample(rng); // uniform cos(θ) in the allowed range
     let theta = cos_theta.acos(); // polar angle [0, π]
     (theta, phi, alpha)
 }
 
+/// Concentration above which [`sample_von_mises`] uses the Gaussian limit.
+const VON_MISES_GAUSSIAN_KAPPA: f64 = 1e6;
+
+/// Draws an angle from the von Mises distribution with mean `0` and concentration `kappa`.
+///
+/// Uses the Best–Fisher rejection sampler, which accepts with probability above 0.65
+/// for every `kappa`. `kappa = 0` is the uniform distribution on `(-π, π]`; above
+/// [`VON_MISES_GAUSSIAN_KAPPA`] the sampler loses precision and the normal
+/// approximation `N(0, 1/kappa)` is used instead, which is exact for `kappa = ∞`.
+fn sample_von_mises<R: rand::Rng + ?Sized>(rng: &mut R, kappa: f64) -> f64 {
+    use rand::prelude::*;
+    use rand_distr::Normal;
+    use std::f64::consts::PI;
+
+    if kappa <= 0.0 {
+        return rng.random_range(-PI..PI);
+    }
+    if kappa > VON_MISES_GAUSSIAN_KAPPA {
+        return Normal::new(0.0, 1.0 / kappa.sqrt()).unwrap().sample(rng);
+    }
+
+    let tau = 1.0 + (1.0 + 4.0 * kappa * kappa).sqrt();
+    let rho = (tau - (2.0 * tau).sqrt()) / (2.0 * kappa);
+    let r = (1.0 + rho * rho) / (2.0 * rho);
+
+    loop {
+        let z = (PI * rng.random::<f64>()).cos();
+        let f = (1.0 + r * z) / (r + z);
+        let c = kappa * (r - f);
+        let u: f64 = rng.random();
+        if c * (2.0 - c) > u || (c / u).ln() + 1.0 - c >= 0.0 {
+            let angle = f.clamp(-1.0, 1.0).acos();
+            return if rng.random::<bool>() { angle } else { -angle };
+        }
+    }
+}
+
+/// Rejection-samples `params.num_birds` birds satisfying the minimum distance constraint.
+///
+/// Shared sampling core of every generation path. Candidates are drawn with
+/// `random_bird()` from `rng` and accepted only if their geodesic distance to all
+/// previously accepted birds is at least `params.min_distance`.
+fn sample_birds<R: rand::Rng + ?Sized>(params: &EntryGenerationParams, rng: &mut R) -> Vec<Bird> {
+    let mut birds = Vec::with_capacity(params.num_birds);
+
+    while birds.len() < params.num_birds {
+        let (theta, phi, random_alpha) = random_bird(rng, params.distribution);
+        let alpha = match params.velocity_init {
+            VelocityInit::Random => random_alpha,
+            VelocityInit::Aligned { alpha } => alpha,
+            VelocityInit::VonMises { mean_alpha, kappa } => {
+                mean_alpha + sample_von_mises(rng, kappa)
+            }
+        };
+
+        // Create new bird from spherical coordinates
+        let candidate_bird = Bird::from_spherical(params.radius, theta, phi, params.speed, alpha);
+
+        // Check if this bird is too close to any existing bird
+        let too_close = birds.iter().any(|existing_bird| {
+            candidate_bird.distance_from(existing_bird, params.radius) < params.min_distance
+        });
+
+        // If not too close, add to ensemble
+        if !too_close {
+            birds.push(candidate_bird);
+        }
+    }
+
+    birds
+}
+
+/// Derives the RNG seed of a single entry from a batch-wide base seed.
+///
+/// Uses the SplitMix64 finalizer so that consecutive entry ids map to well-separated,
+/// statistically independent seeds while remaining a pure function of `(base_seed, entry_id)`.
+/// This is the seed [`generate_parallel`] uses for entry `entry_id`, so any entry of a
+/// seeded batch can be rebuilt on its own with [`generate_seeded_entry`].
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::ensemble::{entry_seed, generate_parallel, generate_seeded_entry};
+/// # use flocking_lib::ensemble::{EntryGenerationParams, InitialDistribution, VelocityInit};
+/// let params = EntryGenerationParams {
+///     num_birds: 5, radius: 1.0, speed: 1.0, min_distance: 0.1,
+///     distribution: InitialDistribution::Uniform, velocity_init: VelocityInit::Random,
+/// };
+/// let batch = generate_parallel(7, 4, params, Some(42), None).unwrap();
+/// let entry = generate_seeded_entry(7, 2, params, entry_seed(42, 2));
+/// assert_eq!(entry.birds, batch[2].birds);
+/// ```
+pub fn entry_seed(base_seed: u64, entry_id: usize) -> u64 {
+    let mut z = base_seed.wrapping_add((entry_id as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+/// Generates entry `id` of ensemble `tag` from an explicit per-entry `seed`.
+///
+/// With `seed = entry_seed(base_seed, id)` the result is identical to entry `id` of a
+/// batch generated with `base_seed`, so a stored dataset can be reproduced from just
+/// `(base_seed, id)` and the generation parameters.
+pub fn generate_seeded_entry(
+    tag: usize,
+    id: usize,
+    params: EntryGenerationParams,
+    seed: u64,
+) -> EntryResult {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    let mut rng = StdRng::seed_from_u64(seed);
+    EntryResult {
+        schema_version: ENSEMBLE_SCHEMA_VERSION,
+        id,
+        tag,
+        birds: sample_birds(&params, &mut rng),
+        params,
+    }
+}
+
+/// Permutes `birds` in place with a permutation determined by `seed`.
+///
+/// Test-only: the physics must not depend on the order of the birds, so a run from
+/// shuffled initial values should reach the same states (up to floating-point rounding
+/// of the neighbor sums) as the unshuffled run. The same seed always yields the same
+/// permutation for slices of equal length.
+#[cfg(test)]
+pub(crate) fn shuffle_birds(birds: &mut [Bird], seed: u64) {
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+    use rand::seq::SliceRandom;
+
+    birds.shuffle(&mut StdRng::seed_from_u64(seed));
+}
+
+/// Generates one complete entry in memory, seeded from `base_seed` when supplied.
+///
+/// Without a seed the thread-local generator is used, matching [`generate`].
+fn generate_one_entry(request: EntryGenerationRequest, base_seed: Option<u64>) -> EntryResult {
+    if let Some(seed) = base_seed {
+        return generate_seeded_entry(
+            request.tag,
+            request.id,
+            request.params,
+            entry_seed(seed, request.id),
+        );
+    }
+
+    let birds = sample_birds(&request.params, &mut rand::rng());
+
+    EntryResult {
+        schema_version: ENSEMBLE_SCHEMA_VERSION,
+        id: request.id,
+        tag: request.tag,
+        birds,
+        params: request.params,
+    }
+}
+
 /// Generates a single ensemble entry using rejection sampling with minimum distance constraints.
 ///
 /// This private function is the core ensemble generation algorithm that creates exactly
@@ -292,34 +802,11 @@ fn generate_entry(
     request: EntryGenerationRequest,
     tx: mpsc::Sender<EntryResult>,
 ) -> Result<(), String> {
-    let mut birds = Vec::with_capacity(request.params.num_birds);
-
-    while birds.len() < request.params.num_birds {
-        let (theta, phi, alpha) = random_bird();
-
-        // Create new bird from spherical coordinates
-        let candidate_bird = Bird::from_spherical(
-            request.params.radius,
-            theta,
-            phi,
-            request.params.speed,
-            alpha,
-        );
-
-        // Check if this bird is too close to any existing bird
-        let too_close = birds.iter().any(|existing_bird| {
-            candidate_bird.distance_from(existing_bird, request.params.radius)
-                < request.params.min_distance
-        });
-
-        // If not too close, add to ensemble
-        if !too_close {
-            birds.push(candidate_bird);
-        }
-    }
+    let birds = sample_birds(&request.params, &mut rand::rng());
 
     // Create the ensemble result with metadata
     let result = EntryResult {
+        schema_version: ENSEMBLE_SCHEMA_VERSION,
         id: request.id,
         tag: request.tag,
         birds,
@@ -349,12 +836,12 @@ fn generate_entry(
 /// 1. **Parallel Generation**: Uses Rayon's `par_iter()` for CPU-optimal thread distribution
 /// 2. **Communication**: MPSC channels coordinate between generation and I/O threads
 /// 3. **Thread Management**: Automatic optimal thread count based on CPU capabilities
-/// 4. **Progress Tracking**: Real-time progress reporting via console output
+/// 4. **Progress Tracking**: Optional caller-supplied callback, no console output
 /// 5. **Error Handling**: Comprehensive error propagation with descriptive messages
 ///
 /// # I/O Integration
 ///
-/// - Automatically creates necessary data directory structure (`./data/ensemble/`)
+/// - Automatically creates necessary data directory structure (`{root}/ensemble/`)
 /// - Spawns dedicated I/O thread to prevent blocking generation workers
 /// - Saves ensembles concurrently as they complete for optimal performance
 /// - Ensures all data is persisted before function returns
@@ -364,6 +851,9 @@ fn generate_entry(
 /// * `tag` - Numerical tag used for file naming and ensemble categorization
 /// * `number_of_entries` - Total number of ensemble entries to generate
 /// * `params` - Physical parameters controlling ensemble generation (particles, physics, constraints)
+/// * `root` - [`DataRoot`] below which the entries are saved, `./data` by default
+/// * `progress` - Optional [`ProgressCallback`] invoked as `(completed, total)` once per
+///   generated entry, letting callers route progress to a bar, a log, or nowhere
 ///
 /// # Returns
 ///
@@ -379,6 +869,7 @@ fn generate_entry(
 ///
 /// # Error Conditions
 ///
+/// - Invalid or infeasible `params` (see [`EntryGenerationParams::validate`])
 /// - Failed data directory creation (filesystem permissions)
 /// - Individual ensemble generation failures (impossible constraints)
 /// - I/O thread failures (disk space, write permissions)
@@ -386,14 +877,18 @@ fn generate_entry(
 ///
 /// # File Output
 ///
-/// Generated ensembles are saved to `./data/ensemble/` with filenames following
-/// the pattern `t{tag}-i{id}.json`, where `tag` and `id` correspond
+/// Generated ensembles are saved to `{root}/ensemble/` with filenames following
+/// the pattern `t{tag}-i{id}.bin`, where `tag` and `id` correspond
 /// to the function parameters and individual entry identifiers.
 pub fn generate(
     tag: usize,
     number_of_entries: usize,
     params: EntryGenerationParams,
+    root: &DataRoot,
+    progress: Option<ProgressCallback>,
 ) -> Result<(), String> {
+    params.validate()?;
+
     debug!("--- Parallel Ensemble Generation ---");
     debug!(
         "Generating {} ensemble entries with tag '{}'",
@@ -412,6 +907,9 @@ pub fn generate(
         .map(|id| EntryGenerationRequest { id, tag, params })
         .collect::<Vec<EntryGenerationRequest>>();
 
+    // shared completion counter for progress reporting
+    let completed = AtomicUsize::new(0);
+
     // parallel run
     requests
         .par_iter()
@@ -424,13 +922,18 @@ pub fn generate(
                     error!("Failed to generate entry {}: {}", request.id, e);
                 }
             }
+
+            let done = completed.fetch_add(1, Ordering::Relaxed) + 1;
+            if let Some(callback) = &progress {
+                callback(done, number_of_entries);
+            }
         });
 
     // Drop the original sender so the receiver will know when all threads are done
     drop(entry_tx);
 
     // Start I/O receiver thread for concurrent saving
-    let io_handle = io::start_receiver_thread(entry_rx);
+    let io_handle = io::start_receiver_thread(entry_rx, root.clone());
 
     // Wait for I/O thread to complete saving
     match io_handle.join() {
@@ -450,7 +953,84 @@ pub fn generate(
         "Successfully generated {} ensemble entries",
         number_of_entries
     );
-    info!("Ensemble entries saved to: ./data/ensemble/");
+    info!(
+        "Ensemble entries saved to: {}",
+        root.folder(DataType::Ensemble).display()
+    );
 
     Ok(())
 }
+
+/// Generates multiple ensemble entries in parallel and returns them in memory.
+///
+/// This is a rayon-only alternative to [`generate`]: entries are produced with
+/// `into_par_iter().map(...)` on rayon's global thread pool and collected into a
+/// `Vec` ordered by entry id, without any MPSC channel or dedicated I/O thread.
+///
+/// # Reproducibility
+///
+/// When `base_seed` is `Some`, every entry draws from its own `StdRng` seeded with a
+/// value derived from `(base_seed, id)`. The output is therefore identical across runs
+/// and independent of the number of threads. With `None`, the thread-local generator
+/// is used and results are not reproducible.
+///
+/// # Arguments
+///
+/// * `tag` - Numerical tag used for file naming and ensemble categorization
+/// * `number_of_entries` - Total number of ensemble entries to generate
+/// * `params` - Physical parameters controlling ensemble generation
+/// * `base_seed` - Optional base seed for reproducible generation
+/// * `save` - Optional closure invoked on each entry as soon as it is generated, e.g.
+///   to persist it with [`crate::io::bin::save_file`]
+///
+/// # Returns
+///
+/// * `Ok(Vec<EntryResult>)` - All generated entries, sorted by id
+/// * `Err(String)` - The message of [`EntryGenerationParams::validate`] for invalid or
+///   infeasible `params`, or the first error reported by the `save` closure
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::ensemble::{generate_parallel, EntryGenerationParams};
+/// # use flocking_lib::ensemble::{InitialDistribution, VelocityInit};
+/// let params = EntryGenerationParams {
+///     num_birds: 5, radius: 1.0, speed: 1.0, min_distance: 0.1,
+///     distribution: InitialDistribution::Uniform, velocity_init: VelocityInit::Random,
+/// };
+/// let entries = generate_parallel(0, 3, params, Some(42), None).unwrap();
+/// assert_eq!(entries.len(), 3);
+/// ```
+pub fn generate_parallel(
+    tag: usize,
+    number_of_entries: usize,
+    params: EntryGenerationParams,
+    base_seed: Option<u64>,
+    save: Option<&SaveFn<'_>>,
+) -> Result<Vec<EntryResult>, String> {
+    params.validate()?;
+
+    debug!(
+        "Generating {} ensemble entries with tag '{}' (seed: {:?})",
+        number_of_entries, tag, base_seed
+    );
+
+    let entries = (0..number_of_entries)
+        .into_par_iter()
+        .map(|id| {
+            let entry = generate_one_entry(EntryGenerationRequest { id, tag, params }, base_seed);
+            if let Some(save) = save {
+                save(&entry).map_err(|e| format!("Failed to save entry {}: {}", id, e))?;
+            }
+            trace!("Successfully generated entry {}", id);
+            Ok(entry)
+        })
+        .collect::<Result<Vec<EntryResult>, String>>()?;
+
+    info!(
+        "Successfully generated {} ensemble entries",
+        number_of_entries
+    );
+
+    Ok(entries)
+}
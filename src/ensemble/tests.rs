@@ -7,8 +7,12 @@
 #[cfg(test)]
 mod units {
     use crate::bird::Bird;
-    use crate::ensemble::{EntryGenerationParams, EntryResult, generate};
-    use crate::io::bin;
+    use crate::ensemble::{
+        ENSEMBLE_SCHEMA_VERSION, EntryGenerationParams, EntryResult, InitialDistribution,
+        ProgressCallback, VelocityInit, entry_seed, generate, generate_parallel,
+        generate_seeded_entry,
+    };
+    use crate::io::{DataPersistence, DataRoot, DataType, FILE_MAGIC, bin};
     use std::fs;
     use std::path::Path;
     use std::sync::mpsc;
@@ -25,6 +29,8 @@ mod units {
             radius: 1.0,
             speed: 1.0,
             min_distance: 0.1,
+            distribution: InitialDistribution::Uniform,
+            velocity_init: VelocityInit::Random,
         }
     }
 
@@ -35,6 +41,8 @@ mod units {
             radius: 1.0,
             speed: 1.0,
             min_distance: 0.8, // High constraint relative to sphere
+            distribution: InitialDistribution::Uniform,
+            velocity_init: VelocityInit::Random,
         }
     }
 
@@ -93,7 +101,7 @@ mod units {
 
         // Call the private generate_entry function through the public API
         // We'll test via the single entry generation
-        let result = generate(tag, 1, params);
+        let result = generate(tag, 1, params, &DataRoot::default(), None);
         assert!(result.is_ok());
 
         // Load the generated file to validate
@@ -143,7 +151,7 @@ mod units {
         let params = tight_constraint_params();
         let tag = 2;
 
-        let result = generate(tag, 1, params);
+        let result = generate(tag, 1, params, &DataRoot::default(), None);
         assert!(result.is_ok());
 
         // Load and validate the generated entry
@@ -190,10 +198,12 @@ mod units {
             radius: 2.5,
             speed: 3.0,
             min_distance: 0.2,
+            distribution: InitialDistribution::Uniform,
+            velocity_init: VelocityInit::Random,
         };
         let tag = 3;
 
-        let result = generate(tag, 1, params);
+        let result = generate(tag, 1, params, &DataRoot::default(), None);
         assert!(result.is_ok());
 
         let data_path = Path::new("./data/ensemble");
@@ -255,7 +265,7 @@ mod units {
             }
         }
 
-        let result = generate(tag, num_entries, params);
+        let result = generate(tag, num_entries, params, &DataRoot::default(), None);
         assert!(result.is_ok());
 
         // Verify all entries were transmitted and saved via MPSC
@@ -290,7 +300,7 @@ mod units {
         let params = test_params();
         let tag = 5;
 
-        let result = generate(tag, 1, params);
+        let result = generate(tag, 1, params, &DataRoot::default(), None);
         assert!(result.is_ok());
 
         // Check file naming convention
@@ -320,7 +330,7 @@ mod units {
         let tag = 6;
         let num_entries = 5;
 
-        let result = generate(tag, num_entries, params);
+        let result = generate(tag, num_entries, params, &DataRoot::default(), None);
         assert!(result.is_ok());
 
         // Verify all files are created with correct naming
@@ -352,11 +362,13 @@ mod units {
             radius: 1.0,
             speed: 1.0,
             min_distance: 0.2,
+            distribution: InitialDistribution::Uniform,
+            velocity_init: VelocityInit::Random,
         };
         let tag = 7;
         let num_entries = 50; // Large but manageable for CI
 
-        let result = generate(tag, num_entries, params);
+        let result = generate(tag, num_entries, params, &DataRoot::default(), None);
         assert!(result.is_ok());
 
         // Verify all entries were created
@@ -403,11 +415,13 @@ mod units {
             radius: 1.0,
             speed: 1.0,
             min_distance: 1.0, // Challenging but possible for 3 birds
+            distribution: InitialDistribution::Uniform,
+            velocity_init: VelocityInit::Random,
         };
         let tag = 8;
 
         // This should complete but may take longer
-        let result = generate(tag, 1, challenging_params);
+        let result = generate(tag, 1, challenging_params, &DataRoot::default(), None);
 
         // Should succeed with reasonable constraints
         match result {
@@ -443,10 +457,11 @@ mod units {
         use crate::ensemble::io::start_receiver_thread;
 
         let (tx, rx) = mpsc::channel();
-        let handle = start_receiver_thread(rx);
+        let handle = start_receiver_thread(rx, DataRoot::default());
 
         // Create a test entry
         let test_entry = EntryResult {
+            schema_version: ENSEMBLE_SCHEMA_VERSION,
             id: 0,
             tag: 10,
             birds: vec![Bird::from_spherical(1.0, 0.0, 0.0, 1.0, 0.0)],
@@ -481,7 +496,7 @@ mod units {
         use crate::ensemble::io::start_receiver_thread;
 
         let (tx, rx) = mpsc::channel();
-        let handle = start_receiver_thread(rx);
+        let handle = start_receiver_thread(rx, DataRoot::default());
 
         let num_entries = 5;
         let tag = 11;
@@ -489,6 +504,7 @@ mod units {
         // Send multiple entries
         for i in 0..num_entries {
             let entry = EntryResult {
+                schema_version: ENSEMBLE_SCHEMA_VERSION,
                 id: i,
                 tag,
                 birds: vec![
@@ -549,7 +565,7 @@ mod units {
         }
 
         // Generate entries
-        let result = generate(tag, num_entries, params);
+        let result = generate(tag, num_entries, params, &DataRoot::default(), None);
         assert!(result.is_ok());
 
         // Verify directory creation and file naming
@@ -584,6 +600,7 @@ mod units {
 
         // Manually test directory creation by using a custom path
         let entry = EntryResult {
+            schema_version: ENSEMBLE_SCHEMA_VERSION,
             id: 0,
             tag: 13,
             birds: vec![Bird::from_spherical(1.0, 0.0, 0.0, 1.0, 0.0)],
@@ -594,8 +611,8 @@ mod units {
         fs::create_dir_all(&test_dir).unwrap();
         let custom_path = test_dir.join("t13-i0.bin");
 
-        // Test manual save and load
-        let serialized = bincode::serialize(&entry).unwrap();
+        // Test manual save and load in the on-disk format (magic, then bincode)
+        let serialized = [FILE_MAGIC.as_slice(), &bincode::serialize(&entry).unwrap()].concat();
         fs::write(&custom_path, serialized).unwrap();
 
         // Verify file exists and is readable
@@ -606,13 +623,18 @@ mod units {
 
         // Test overwrite behavior
         let new_entry = EntryResult {
+            schema_version: ENSEMBLE_SCHEMA_VERSION,
             id: 1,
             tag: 13,
             birds: vec![Bird::from_spherical(1.0, 1.0, 1.0, 1.0, 1.0)],
             params: test_params(),
         };
 
-        let new_serialized = bincode::serialize(&new_entry).unwrap();
+        let new_serialized = [
+            FILE_MAGIC.as_slice(),
+            &bincode::serialize(&new_entry).unwrap(),
+        ]
+        .concat();
         fs::write(&custom_path, new_serialized).unwrap();
 
         let reloaded: EntryResult = bin::load_file(&custom_path).unwrap();
@@ -629,6 +651,7 @@ mod units {
     /// - Binary format is compact and efficient
     /// - Cross-platform compatibility is maintained
     #[test]
+    #[allow(clippy::approx_constant)]
     fn binary_serialization_correctness() {
         // Create an entry with precise floating-point values
         let precise_bird = Bird::from_spherical(
@@ -640,6 +663,7 @@ mod units {
         );
 
         let entry = EntryResult {
+            schema_version: ENSEMBLE_SCHEMA_VERSION,
             id: 42,
             tag: 14,
             birds: vec![precise_bird],
@@ -648,6 +672,8 @@ mod units {
                 radius: 1.23456789012345,
                 speed: 2.71828182845905,
                 min_distance: 0.123456789,
+                distribution: InitialDistribution::Uniform,
+                velocity_init: VelocityInit::Random,
             },
         };
 
@@ -696,6 +722,8 @@ mod units {
             radius: 1.0,
             speed: 1.0,
             min_distance: 0.15,
+            distribution: InitialDistribution::Uniform,
+            velocity_init: VelocityInit::Random,
         };
 
         let params2 = EntryGenerationParams {
@@ -703,6 +731,8 @@ mod units {
             radius: 2.0,
             speed: 0.5,
             min_distance: 0.3,
+            distribution: InitialDistribution::Uniform,
+            velocity_init: VelocityInit::Random,
         };
 
         let tag1 = 15;
@@ -710,9 +740,13 @@ mod units {
         let entries_per_tag = 3;
 
         // Run two generation tasks concurrently
-        let handle1 = thread::spawn(move || generate(tag1, entries_per_tag, params1));
+        let handle1 = thread::spawn(move || {
+            generate(tag1, entries_per_tag, params1, &DataRoot::default(), None)
+        });
 
-        let handle2 = thread::spawn(move || generate(tag2, entries_per_tag, params2));
+        let handle2 = thread::spawn(move || {
+            generate(tag2, entries_per_tag, params2, &DataRoot::default(), None)
+        });
 
         // Wait for both to complete
         let result1 = handle1.join().unwrap();
@@ -746,4 +780,518 @@ mod units {
             fs::remove_file(file2).ok();
         }
     }
+
+    /// Tests the `generate()` progress callback.
+    ///
+    /// Validates that:
+    /// - The callback fires exactly once per generated entry
+    /// - The reported total matches the requested entry count
+    /// - The final reported completion count equals the total
+    #[test]
+    fn generate_progress_callback() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let params = test_params();
+        let tag = 90;
+        let num_entries = 4;
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let max_completed = Arc::new(AtomicUsize::new(0));
+        let calls_cb = Arc::clone(&calls);
+        let max_cb = Arc::clone(&max_completed);
+
+        let progress: ProgressCallback = Box::new(move |completed, total| {
+            assert_eq!(total, num_entries);
+            assert!(completed >= 1 && completed <= total);
+            calls_cb.fetch_add(1, Ordering::SeqCst);
+            max_cb.fetch_max(completed, Ordering::SeqCst);
+        });
+
+        let result = generate(
+            tag,
+            num_entries,
+            params,
+            &DataRoot::default(),
+            Some(progress),
+        );
+        assert!(result.is_ok());
+
+        assert_eq!(calls.load(Ordering::SeqCst), num_entries);
+        assert_eq!(max_completed.load(Ordering::SeqCst), num_entries);
+
+        // Cleanup
+        for i in 0..num_entries {
+            let file = Path::new("./data/ensemble").join(format!("t{}-i{}.bin", tag, i));
+            fs::remove_file(file).ok();
+        }
+    }
+
+    /// Tests `generate_parallel()` in-memory generation.
+    ///
+    /// Validates that:
+    /// - The requested number of entries is returned, ordered by id
+    /// - Every entry satisfies the physics and distance constraints
+    /// - Nothing is written to disk when no save closure is given
+    #[test]
+    fn generate_parallel_in_memory() {
+        let params = test_params();
+        let tag = 91;
+        let num_entries = 6;
+
+        let entries = generate_parallel(tag, num_entries, params, None, None).unwrap();
+
+        assert_eq!(entries.len(), num_entries);
+        for (i, entry) in entries.iter().enumerate() {
+            assert_eq!(entry.id, i);
+            assert_eq!(entry.tag, tag);
+            assert_eq!(entry.birds.len(), params.num_birds);
+            assert!(validate_on_sphere(&entry.birds, params.radius, 1e-10));
+            assert!(validate_tangent_velocities(&entry.birds, 1e-10));
+            assert!(validate_speed(&entry.birds, params.speed, 1e-10));
+            assert!(validate_distance_constraints(
+                &entry.birds,
+                params.min_distance,
+                params.radius
+            ));
+        }
+
+        let file = Path::new("./data/ensemble").join(format!("t{}-i0.bin", tag));
+        assert!(!file.exists());
+    }
+
+    /// Tests `generate_parallel()` reproducibility.
+    ///
+    /// Validates that:
+    /// - Identical base seeds produce bit-identical entries
+    /// - Different base seeds produce different entries
+    /// - Entries within one batch differ from each other
+    #[test]
+    fn generate_parallel_seeded_reproducible() {
+        let params = test_params();
+
+        let first = generate_parallel(0, 4, params, Some(1234), None).unwrap();
+        let second = generate_parallel(0, 4, params, Some(1234), None).unwrap();
+        let other = generate_parallel(0, 4, params, Some(4321), None).unwrap();
+
+        for (a, b) in first.iter().zip(second.iter()) {
+            for (bird_a, bird_b) in a.birds.iter().zip(b.birds.iter()) {
+                assert_eq!(bird_a, bird_b);
+            }
+        }
+
+        assert_ne!(first[0].birds[0].position, other[0].birds[0].position);
+        assert_ne!(first[0].birds[0].position, first[1].birds[0].position);
+    }
+
+    /// Tests `generate_parallel()` save closure integration.
+    ///
+    /// Validates that:
+    /// - The closure is invoked once per entry
+    /// - Entries saved through the closure load back correctly
+    /// - Closure errors are propagated to the caller
+    #[test]
+    fn generate_parallel_save_closure() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let params = test_params();
+        let tag = 92;
+        let num_entries = 3;
+        let saved = AtomicUsize::new(0);
+
+        let save = |entry: &EntryResult| {
+            saved.fetch_add(1, Ordering::SeqCst);
+            bin::save_file(entry, &DataRoot::default()).map_err(|e| e.to_string())
+        };
+        let entries = generate_parallel(tag, num_entries, params, Some(7), Some(&save)).unwrap();
+        assert_eq!(saved.load(Ordering::SeqCst), num_entries);
+
+        for entry in &entries {
+            let file = Path::new("./data/ensemble").join(format!("t{}-i{}.bin", tag, entry.id));
+            let loaded: EntryResult = bin::load_file(&file).unwrap();
+            assert_eq!(loaded.birds[0].position, entry.birds[0].position);
+            fs::remove_file(file).ok();
+        }
+
+        let failing = |_: &EntryResult| Err("disk full".to_string());
+        let result = generate_parallel(tag, num_entries, params, None, Some(&failing));
+        assert!(result.unwrap_err().contains("disk full"));
+    }
+
+    /// Tests `EntryGenerationParams::validate()`.
+    ///
+    /// Validates that:
+    /// - The parameters used throughout these tests are accepted
+    /// - Overcrowded packings and invalid values are rejected
+    #[test]
+    fn validate_params() {
+        assert!(test_params().validate().is_ok());
+        assert!(tight_constraint_params().validate().is_ok());
+
+        // 100 caps of angular radius 0.25 cover ~155% of the unit sphere
+        let crowded = EntryGenerationParams {
+            num_birds: 100,
+            min_distance: 0.5,
+            ..test_params()
+        };
+        assert!(crowded.validate().unwrap_err().contains("infeasible"));
+
+        // A single bird is always feasible
+        let single = EntryGenerationParams {
+            num_birds: 1,
+            min_distance: 10.0,
+            ..test_params()
+        };
+        assert!(single.validate().is_ok());
+
+        for invalid in [
+            EntryGenerationParams {
+                num_birds: 0,
+                ..test_params()
+            },
+            EntryGenerationParams {
+                radius: 0.0,
+                ..test_params()
+            },
+            EntryGenerationParams {
+                speed: -1.0,
+                ..test_params()
+            },
+            EntryGenerationParams {
+                min_distance: f64::NAN,
+                ..test_params()
+            },
+        ] {
+            assert!(invalid.validate().is_err());
+        }
+    }
+
+    /// Tests that the public generators validate their parameters up front.
+    ///
+    /// Validates that:
+    /// - An inverted band is an error instead of a panic in a worker thread
+    /// - An over-packed sphere is an error instead of a sampling loop that never ends
+    /// - Nothing is written for rejected parameters
+    #[test]
+    fn generators_reject_invalid_params() {
+        let inverted = EntryGenerationParams {
+            distribution: InitialDistribution::Band {
+                theta_min: 2.0,
+                theta_max: 1.0,
+            },
+            ..test_params()
+        };
+        // 100 caps of angular radius 0.25 cover ~155% of the unit sphere
+        let overpacked = EntryGenerationParams {
+            num_birds: 100,
+            min_distance: 0.5,
+            ..test_params()
+        };
+
+        for params in [inverted, overpacked] {
+            let temp_dir = setup_temp_dir();
+            let root = DataRoot::new(temp_dir.path());
+            let err = generate(0, 2, params, &root, None).unwrap_err();
+            assert_eq!(err, params.validate().unwrap_err());
+            assert!(!root.folder(DataType::Ensemble).exists());
+
+            let err = generate_parallel(0, 2, params, Some(1), None).unwrap_err();
+            assert_eq!(err, params.validate().unwrap_err());
+        }
+    }
+
+    /// Tests generation restricted by `InitialDistribution`.
+    ///
+    /// Validates that:
+    /// - No bird of a polar cap ensemble exceeds `max_theta`
+    /// - Band ensembles stay within `[theta_min, theta_max]`
+    /// - Cap samples have uniform area density (mean of cos θ at the range midpoint)
+    /// - Invalid angle ranges and overcrowded regions are rejected
+    #[test]
+    fn initial_distribution_restricts_polar_angle() {
+        let theta_of = |bird: &Bird| (bird.position.z / bird.position.norm()).acos();
+
+        let max_theta = 0.6;
+        let cap = EntryGenerationParams {
+            num_birds: 200,
+            min_distance: 0.0,
+            distribution: InitialDistribution::PolarCap { max_theta },
+            ..test_params()
+        };
+        assert!(cap.validate().is_ok());
+        let entries = generate_parallel(0, 1, cap, Some(5), None).unwrap();
+        let birds = &entries[0].birds;
+        assert!(birds.iter().all(|b| theta_of(b) <= max_theta + 1e-12));
+        assert!(validate_on_sphere(birds, cap.radius, 1e-10));
+        assert!(validate_tangent_velocities(birds, 1e-10));
+
+        // Uniform area density: cos θ uniform on [cos(max_theta), 1]
+        let mean_cos = birds.iter().map(|b| theta_of(b).cos()).sum::<f64>() / birds.len() as f64;
+        let expected = (1.0 + max_theta.cos()) / 2.0;
+        assert!((mean_cos - expected).abs() < 0.01);
+
+        let band = EntryGenerationParams {
+            num_birds: 100,
+            min_distance: 0.01,
+            distribution: InitialDistribution::Band {
+                theta_min: 1.2,
+                theta_max: 1.8,
+            },
+            ..test_params()
+        };
+        let entries = generate_parallel(0, 1, band, Some(6), None).unwrap();
+        assert!(entries[0].birds.iter().all(|b| {
+            let theta = theta_of(b);
+            (1.2 - 1e-12..=1.8 + 1e-12).contains(&theta)
+        }));
+
+        let inverted = EntryGenerationParams {
+            distribution: InitialDistribution::Band {
+                theta_min: 2.0,
+                theta_max: 1.0,
+            },
+            ..test_params()
+        };
+        assert!(inverted.validate().is_err());
+
+        // Feasible on the whole sphere but not within a tiny cap
+        let crowded_cap = EntryGenerationParams {
+            distribution: InitialDistribution::PolarCap { max_theta: 0.2 },
+            ..test_params()
+        };
+        assert!(test_params().validate().is_ok());
+        assert!(crowded_cap.validate().unwrap_err().contains("infeasible"));
+    }
+
+    /// Tests `VelocityInit::Aligned`.
+    ///
+    /// Validates that:
+    /// - Meridional flow in a narrow equatorial band has polarization near 1
+    /// - Azimuthal flow on the full sphere is a rigid rotation (rotational order near 1)
+    /// - Random directions are disordered
+    #[test]
+    fn aligned_velocity_init_is_ordered() {
+        use crate::analysis;
+        use std::f64::consts::FRAC_PI_2;
+
+        let band = EntryGenerationParams {
+            num_birds: 50,
+            min_distance: 0.01,
+            distribution: InitialDistribution::Band {
+                theta_min: FRAC_PI_2 - 0.1,
+                theta_max: FRAC_PI_2 + 0.1,
+            },
+            velocity_init: VelocityInit::Aligned { alpha: FRAC_PI_2 },
+            ..test_params()
+        };
+        let birds = &generate_parallel(0, 1, band, Some(1), None).unwrap()[0].birds;
+        assert!(analysis::polarization(birds) > 0.99);
+        assert!(validate_tangent_velocities(birds, 1e-10));
+        assert!(validate_speed(birds, band.speed, 1e-10));
+
+        let rotating = EntryGenerationParams {
+            num_birds: 50,
+            velocity_init: VelocityInit::Aligned { alpha: 0.0 },
+            ..test_params()
+        };
+        let birds = &generate_parallel(0, 1, rotating, Some(2), None).unwrap()[0].birds;
+        let spins = analysis::angular_momentum(birds);
+        assert!(spins.normalize().z > 0.99);
+
+        let random = EntryGenerationParams {
+            num_birds: 200,
+            ..test_params()
+        };
+        let birds = &generate_parallel(0, 1, random, Some(3), None).unwrap()[0].birds;
+        assert!(analysis::polarization(birds) < 0.3);
+
+        let invalid = EntryGenerationParams {
+            velocity_init: VelocityInit::Aligned { alpha: f64::NAN },
+            ..test_params()
+        };
+        assert!(invalid.validate().is_err());
+    }
+
+    /// Tests `VelocityInit::VonMises`.
+    ///
+    /// Validates that:
+    /// - Polarization in an equatorial band grows with the concentration `kappa`
+    /// - `kappa = ∞` reproduces the aligned initialization
+    /// - Negative or NaN concentrations are rejected
+    #[test]
+    fn von_mises_concentration_orders_headings() {
+        use crate::analysis;
+        use std::f64::consts::FRAC_PI_2;
+
+        let band = |kappa: f64| EntryGenerationParams {
+            num_birds: 100,
+            min_distance: 0.01,
+            distribution: InitialDistribution::Band {
+                theta_min: FRAC_PI_2 - 0.1,
+                theta_max: FRAC_PI_2 + 0.1,
+            },
+            velocity_init: VelocityInit::VonMises {
+                mean_alpha: FRAC_PI_2,
+                kappa,
+            },
+            ..test_params()
+        };
+        let mean_order = |kappa: f64| {
+            let entries = generate_parallel(0, 8, band(kappa), Some(5), None).unwrap();
+            entries
+                .iter()
+                .map(|entry| analysis::polarization(&entry.birds))
+                .sum::<f64>()
+                / entries.len() as f64
+        };
+
+        let orders: Vec<f64> = [0.0, 1.0, 4.0, 50.0].into_iter().map(mean_order).collect();
+        assert!(orders[0] < 0.25, "{orders:?}");
+        assert!(
+            orders.windows(2).all(|pair| pair[0] < pair[1]),
+            "{orders:?}"
+        );
+        assert!(orders[3] > 0.95, "{orders:?}");
+
+        let birds = &generate_parallel(0, 1, band(f64::INFINITY), Some(6), None).unwrap()[0].birds;
+        assert!(
+            birds
+                .iter()
+                .all(|bird| (bird.heading() - FRAC_PI_2).abs() < 1e-9)
+        );
+        assert!(validate_tangent_velocities(birds, 1e-10));
+
+        assert!(band(-1.0).validate().is_err());
+        assert!(band(f64::NAN).validate().is_err());
+    }
+
+    /// Tests `EntryResult::validate()`.
+    ///
+    /// Validates that:
+    /// - Freshly generated entries pass
+    /// - Each corrupted invariant is reported, and all violations are collected
+    /// - NaN components are treated as violations
+    #[test]
+    fn entry_result_validate_detects_corruption() {
+        use crate::vector::Vec3;
+
+        let entry = generate_parallel(0, 1, test_params(), Some(9), None)
+            .unwrap()
+            .remove(0);
+        assert!(entry.validate(1e-10).is_ok());
+
+        // Pushed off the sphere
+        let mut off_sphere = entry.clone();
+        off_sphere.birds[2].position *= 1.5;
+        let errors = off_sphere.validate(1e-10).unwrap_err();
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].starts_with("bird 2:") && errors[0].contains("radius"));
+
+        // Radial velocity and wrong speed on two different birds
+        let mut corrupted = entry.clone();
+        let radial = corrupted.birds[0].position.normalize();
+        corrupted.birds[0].velocity += radial * 0.5;
+        corrupted.birds[5].velocity *= 3.0;
+        let errors = corrupted.validate(1e-10).unwrap_err();
+        assert!(
+            errors
+                .iter()
+                .any(|e| e.contains("bird 0") && e.contains("tangent"))
+        );
+        assert!(
+            errors
+                .iter()
+                .any(|e| e.contains("bird 5") && e.contains("speed"))
+        );
+        assert!(!errors.iter().any(|e| e.contains("radius")));
+
+        // Missing bird and NaN velocity
+        let mut truncated = entry.clone();
+        truncated.birds.pop();
+        truncated.birds[1].velocity = Vec3::new(f64::NAN, 0.0, 0.0);
+        let errors = truncated.validate(1e-10).unwrap_err();
+        assert!(errors[0].contains("expected 10 birds, found 9"));
+        assert!(errors.iter().any(|e| e.contains("bird 1")));
+    }
+
+    #[test]
+    fn merge_disjoint_and_overlapping_entries() {
+        let params = |distribution| EntryGenerationParams {
+            num_birds: 20,
+            radius: 1.0,
+            speed: 1.0,
+            min_distance: 0.05,
+            distribution,
+            velocity_init: VelocityInit::Random,
+        };
+        let north = generate_parallel(
+            0,
+            1,
+            params(InitialDistribution::PolarCap { max_theta: 0.5 }),
+            Some(1),
+            None,
+        )
+        .unwrap()
+        .remove(0);
+        let south = generate_parallel(
+            1,
+            1,
+            params(InitialDistribution::Band {
+                theta_min: 2.5,
+                theta_max: std::f64::consts::PI,
+            }),
+            Some(2),
+            None,
+        )
+        .unwrap()
+        .remove(0);
+
+        let merged = EntryResult::merge(vec![north.clone(), south.clone()], 0.05, 1.0).unwrap();
+        assert_eq!(merged.birds.len(), 40);
+        assert_eq!((merged.id, merged.tag), (north.id, north.tag));
+        assert_eq!(merged.params.num_birds, 40);
+        assert_eq!(merged.params.distribution, InitialDistribution::Uniform);
+        assert_eq!(merged.birds[20], south.birds[0]);
+        assert!(merged.validate(1e-10).is_ok());
+
+        // The same cap twice puts birds on top of each other
+        let err = EntryResult::merge(vec![north.clone(), north.clone()], 0.05, 1.0).unwrap_err();
+        assert!(err.contains("closer than 0.05"), "{err}");
+
+        // Pairs inside an entry were only generated 0.05 apart
+        let err = EntryResult::merge(vec![north.clone(), south.clone()], 0.06, 1.0).unwrap_err();
+        assert!(err.contains("min_distance 0.05, below 0.06"), "{err}");
+
+        // Speeds are matched within the relative tolerance only
+        let mut faster = south.clone();
+        faster.params.speed = 1.0 + 1e-13;
+        assert!(EntryResult::merge(vec![north.clone(), faster.clone()], 0.05, 1.0).is_ok());
+        faster.params.speed = 1.1;
+        let err = EntryResult::merge(vec![north.clone(), faster], 0.05, 1.0).unwrap_err();
+        assert!(err.contains("has speed 1.1"), "{err}");
+
+        assert!(EntryResult::merge(Vec::new(), 0.05, 1.0).is_err());
+        assert!(EntryResult::merge(vec![north, south], 0.05, 2.0).is_err());
+    }
+
+    #[test]
+    fn seeded_entry_regenerates_stored_birds() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = DataRoot::new(temp_dir.path());
+        let base_seed = 2024;
+
+        let save = |entry: &EntryResult| bin::save_file(entry, &root).map_err(|e| e.to_string());
+        generate_parallel(6, 5, test_params(), Some(base_seed), Some(&save)).unwrap();
+
+        // Rebuild entry 3 alone from (base_seed, id) and compare with the stored file
+        let stored: EntryResult =
+            bin::load_file(&EntryResult::binary_path_for(&root, 6, 3)).unwrap();
+        let regenerated = generate_seeded_entry(6, 3, test_params(), entry_seed(base_seed, 3));
+        assert_eq!((regenerated.tag, regenerated.id), (6, 3));
+        assert_eq!(regenerated.birds, stored.birds);
+
+        // Neighboring ids get different seeds
+        assert_ne!(entry_seed(base_seed, 3), entry_seed(base_seed, 4));
+        assert_ne!(entry_seed(base_seed, 3), entry_seed(base_seed + 1, 3));
+    }
 }
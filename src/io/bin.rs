@@ -1,41 +1,235 @@
-use crate::io::DataPersistence;
+use crate::io::{DataPersistence, DataRoot, FILE_MAGIC, incompatible_schema};
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use log::warn;
 use std::fs;
+use std::io::{BufReader, BufWriter, Read, Write};
 use std::path::{Path, PathBuf};
 
-pub fn save_file<T: DataPersistence>(data: &T) -> Result<(), Box<dyn std::error::Error>> {
-    let file_path = data.binary_path();
+/// Saves `data` as [`FILE_MAGIC`] followed by its bincode encoding to `binary_path`.
+///
+/// [`DataPersistence::after_save`] runs once the file is written; its failure is logged
+/// as a warning and does not fail the save.
+pub fn save_file<T: DataPersistence>(
+    data: &T,
+    root: &DataRoot,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let file_path = data.binary_path(root);
 
     if let Some(parent) = file_path.parent() {
         std::fs::create_dir_all(parent)?;
     }
 
-    let binary_data = bincode::serialize(data)?;
+    let mut binary_data = FILE_MAGIC.to_vec();
+    bincode::serialize_into(&mut binary_data, data)?;
     std::fs::write(&file_path, binary_data)?;
 
+    run_after_save(data, root);
     Ok(())
 }
 
+/// Saves `data` like [`save_file`], but gzip-compressed to `{binary_path}.gz`.
+pub fn save_file_compressed<T: DataPersistence>(
+    data: &T,
+    root: &DataRoot,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let file_path = data.compressed_binary_path(root);
+
+    if let Some(parent) = file_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let file = fs::File::create(&file_path)?;
+    let mut encoder = GzEncoder::new(BufWriter::new(file), Compression::default());
+    encoder.write_all(&FILE_MAGIC)?;
+    bincode::serialize_into(&mut encoder, data)?;
+    encoder.finish()?.flush()?;
+
+    run_after_save(data, root);
+    Ok(())
+}
+
+/// Runs [`DataPersistence::after_save`], logging a failure instead of returning it: the
+/// file itself is safely written, so bookkeeping such as the simulation index must not
+/// fail the save.
+fn run_after_save<T: DataPersistence>(data: &T, root: &DataRoot) {
+    if let Err(e) = data.after_save(root) {
+        warn!(
+            "Saved {} but its after-save hook failed: {}",
+            data.binary_path(root).display(),
+            e
+        );
+    }
+}
+
+/// Loads a bincode file, transparently decompressing it when the extension is `.gz`.
+///
+/// The [`FILE_MAGIC`] and the schema version after it are checked before decoding: files
+/// written with a different [`DataPersistence::SCHEMA_VERSION`], and legacy files without
+/// the magic, are handed to [`DataPersistence::migrate`], which by default returns an
+/// "incompatible schema version" or "unversioned file" error.
 pub fn load_file<T: DataPersistence>(file_path: &Path) -> Result<T, Box<dyn std::error::Error>> {
-    let binary_data = std::fs::read(file_path)?;
-    let data: T = bincode::deserialize(&binary_data)?;
+    let binary_data = if is_compressed(file_path) {
+        let file = fs::File::open(file_path)?;
+        let mut bytes = Vec::new();
+        GzDecoder::new(BufReader::new(file)).read_to_end(&mut bytes)?;
+        bytes
+    } else {
+        std::fs::read(file_path)?
+    };
+
+    let Some(encoded) = binary_data.strip_prefix(&FILE_MAGIC) else {
+        return Ok(T::migrate(None, &binary_data)?);
+    };
+    let version = leading_version(encoded).ok_or_else(|| incompatible_schema::<T>(None))?;
+    if version != T::SCHEMA_VERSION {
+        return Ok(T::migrate(Some(version), encoded)?);
+    }
+
+    let data: T = bincode::deserialize(encoded)?;
     Ok(data)
 }
 
-pub fn list_files<T: DataPersistence>() -> Result<Vec<PathBuf>, std::io::Error> {
-    let dir_path = Path::new("./data").join(T::data_type().folder());
+/// Reads the little-endian `u32` at the start of `bytes`, where versioned layouts keep
+/// their schema version; `None` if there are fewer than four bytes.
+pub fn leading_version(bytes: &[u8]) -> Option<u32> {
+    let version = bytes.get(..4)?;
+    Some(u32::from_le_bytes([
+        version[0], version[1], version[2], version[3],
+    ]))
+}
+
+/// Decodes `bytes` as `T` with the encoding of [`save_file`], failing unless every byte
+/// is consumed.
+///
+/// Meant for [`DataPersistence::migrate`]: a legacy layout that decodes exactly is very
+/// unlikely to be a misread file of another layout.
+pub fn decode_exact<T: serde::de::DeserializeOwned>(bytes: &[u8]) -> Result<T, bincode::Error> {
+    use bincode::Options;
+
+    bincode::DefaultOptions::new()
+        .with_fixint_encoding()
+        .reject_trailing_bytes()
+        .deserialize(bytes)
+}
+
+fn is_compressed(file_path: &Path) -> bool {
+    file_path.extension().and_then(|s| s.to_str()) == Some("gz")
+}
+
+/// Lists the binary files (`.bin` and `.bin.gz`) of type `T` below `root`, sorted.
+///
+/// Both layouts are searched: files directly in the data type's folder and files one
+/// level down in per-tag sub-folders (see [`DataRoot::with_tag_directories`]). An item
+/// saved both plain and compressed is listed once, by its plain `.bin` file.
+pub fn list_files<T: DataPersistence>(root: &DataRoot) -> Result<Vec<PathBuf>, std::io::Error> {
+    let dir_path = root.folder(T::data_type());
 
+    let mut files = Vec::new();
+    for path in binary_files_in(&dir_path)? {
+        if path.is_dir() {
+            files.extend(binary_files_in(&path)?.into_iter().filter(|p| !p.is_dir()));
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(prefer_plain(files))
+}
+
+/// Lists the binary files of type `T` stored under `tag`, sorted.
+///
+/// Only the location given by the root's layout is searched: `t{tag}-i*.bin[.gz]` in the
+/// flat layout, or the `{tag}` sub-folder with tag directories. As in [`list_files`], an
+/// item saved in both formats is listed once.
+pub fn list_files_for_tag<T: DataPersistence>(
+    root: &DataRoot,
+    tag: usize,
+) -> Result<Vec<PathBuf>, std::io::Error> {
+    let (dir_path, prefix) = if root.tag_directories() {
+        (
+            root.folder(T::data_type()).join(tag.to_string()),
+            "i".to_string(),
+        )
+    } else {
+        (root.folder(T::data_type()), format!("t{}-i", tag))
+    };
+
+    let files: Vec<PathBuf> = binary_files_in(&dir_path)?
+        .into_iter()
+        .filter(|path| {
+            !path.is_dir()
+                && path
+                    .file_name()
+                    .and_then(|s| s.to_str())
+                    .is_some_and(|name| name.starts_with(&prefix))
+        })
+        .collect();
+    Ok(prefer_plain(files))
+}
+
+/// Sorts `files` and drops every `.bin.gz` file whose plain `.bin` twin is also present.
+fn prefer_plain(mut files: Vec<PathBuf>) -> Vec<PathBuf> {
+    files.sort();
+    let plain: std::collections::HashSet<PathBuf> = files
+        .iter()
+        .filter(|path| !is_compressed(path))
+        .cloned()
+        .collect();
+    files.retain(|path| !is_compressed(path) || !plain.contains(&path.with_extension("")));
+    files
+}
+
+/// Returns the sub-directories and binary files directly inside `dir_path`.
+fn binary_files_in(dir_path: &Path) -> Result<Vec<PathBuf>, std::io::Error> {
     if !dir_path.exists() {
         return Ok(Vec::new());
     }
 
-    let mut files = Vec::new();
+    let mut paths = Vec::new();
     for entry in fs::read_dir(dir_path)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.extension().and_then(|s| s.to_str()) == Some("bin") {
-            files.push(path);
+        let path = entry?.path();
+        let name = path
+            .file_name()
+            .and_then(|s| s.to_str())
+            .unwrap_or_default();
+        if path.is_dir() || name.ends_with(".bin") || name.ends_with(".bin.gz") {
+            paths.push(path);
         }
     }
-    files.sort();
-    Ok(files)
+    Ok(paths)
+}
+
+/// Lists the `(tag, id)` pairs of every readable stored item of type `T`, sorted.
+///
+/// Every file returned by [`list_files`] is deserialized; files that fail to load
+/// (truncated, corrupted, or of a different format) are skipped with a logged warning
+/// so that a single bad file does not hide the rest of the data set. Use
+/// [`list_tags_and_ids_strict`] to fail instead.
+pub fn list_tags_and_ids<T: DataPersistence>(
+    root: &DataRoot,
+) -> Result<Vec<(usize, usize)>, std::io::Error> {
+    let mut pairs = Vec::new();
+    for path in list_files::<T>(root)? {
+        match load_file::<T>(&path) {
+            Ok(data) => pairs.push((data.tag(), data.id())),
+            Err(e) => warn!("Skipping unreadable file {}: {}", path.display(), e),
+        }
+    }
+    pairs.sort();
+    Ok(pairs)
+}
+
+/// Like [`list_tags_and_ids`], but returns an error naming the first unreadable file.
+pub fn list_tags_and_ids_strict<T: DataPersistence>(
+    root: &DataRoot,
+) -> Result<Vec<(usize, usize)>, Box<dyn std::error::Error>> {
+    let mut pairs = Vec::new();
+    for path in list_files::<T>(root)? {
+        let data = load_file::<T>(&path)
+            .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+        pairs.push((data.tag(), data.id()));
+    }
+    pairs.sort();
+    Ok(pairs)
 }
@@ -1,7 +1,10 @@
-use crate::io::DataPersistence;
+use crate::io::{DataPersistence, DataRoot};
 
-pub fn export_file<T: DataPersistence>(data: &T) -> Result<(), Box<dyn std::error::Error>> {
-    let file_path = data.json_path();
+pub fn export_file<T: DataPersistence>(
+    data: &T,
+    root: &DataRoot,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let file_path = data.json_path(root);
 
     if let Some(parent) = file_path.parent() {
         std::fs::create_dir_all(parent)?;
@@ -22,20 +22,178 @@ impl DataType {
     }
 }
 
+/// Base directory under which all persisted data is organized.
+///
+/// Each [`DataType`] is stored in its own sub-folder of the root, e.g.
+/// `{root}/ensemble/t{tag}-i{id}.bin`. The default root is `./data`; pointing
+/// different experiments (or tests) at different roots keeps their files apart and
+/// makes the library usable from any working directory.
+///
+/// With [`DataRoot::with_tag_directories`] every tag gets its own sub-folder instead,
+/// e.g. `{root}/simulation/{tag}/i{id}.bin`, which keeps large campaigns organized.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataRoot {
+    path: PathBuf,
+    tag_directories: bool,
+}
+
+impl DataRoot {
+    /// Creates a data root at an arbitrary base path, using the flat file layout.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        DataRoot {
+            path: path.into(),
+            tag_directories: false,
+        }
+    }
+
+    /// Switches to the per-tag layout `{folder}/{tag}/i{id}.{ext}`.
+    pub fn with_tag_directories(mut self) -> Self {
+        self.tag_directories = true;
+        self
+    }
+
+    /// Returns whether files are stored in per-tag sub-folders.
+    pub fn tag_directories(&self) -> bool {
+        self.tag_directories
+    }
+
+    /// Returns the default root for JSON exports consumed by the Python plotting scripts.
+    pub fn plots() -> Self {
+        DataRoot::new("./plots/data")
+    }
+
+    /// Returns the base path of this root.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the folder holding files of the given data type.
+    pub fn folder(&self, data_type: DataType) -> PathBuf {
+        self.path.join(data_type.folder())
+    }
+
+    /// Returns the path of item `(tag, id)` of the given data type with `extension`.
+    ///
+    /// This is `{folder}/t{tag}-i{id}.{extension}` in the flat layout and
+    /// `{folder}/{tag}/i{id}.{extension}` with tag directories.
+    pub fn item_path(
+        &self,
+        data_type: DataType,
+        tag: usize,
+        id: usize,
+        extension: &str,
+    ) -> PathBuf {
+        let folder = self.folder(data_type);
+        if self.tag_directories {
+            folder
+                .join(tag.to_string())
+                .join(format!("i{}.{}", id, extension))
+        } else {
+            folder.join(format!("t{}-i{}.{}", tag, id, extension))
+        }
+    }
+
+    /// Creates the folders for every [`DataType`] below this root if missing.
+    pub fn ensure_data_directories(&self) -> std::io::Result<()> {
+        for data_type in [DataType::Ensemble, DataType::Simulation, DataType::Analysis] {
+            std::fs::create_dir_all(self.folder(data_type))?;
+        }
+        Ok(())
+    }
+}
+
+/// Bytes opening every file written by [`bin::save_file`] and [`bin::save_file_compressed`].
+///
+/// The schema version follows directly, so a loader can tell a versioned file from a
+/// legacy one whose first four bytes happen to equal a valid version (e.g. the `id` of
+/// files written before schema versions existed).
+pub const FILE_MAGIC: [u8; 4] = *b"VSIM";
+
+/// Returns the error message for a file whose layout this build cannot read.
+///
+/// `version` is the stored schema version, or `None` for a legacy file without
+/// [`FILE_MAGIC`]; see [`DataPersistence::migrate`].
+pub fn incompatible_schema<T: DataPersistence>(version: Option<u32>) -> String {
+    match version {
+        Some(version) => format!(
+            "incompatible {} schema version {} (this build reads version {})",
+            T::data_type().folder(),
+            version,
+            T::SCHEMA_VERSION
+        ),
+        None => format!(
+            "unversioned {} file without magic number (this build reads version {})",
+            T::data_type().folder(),
+            T::SCHEMA_VERSION
+        ),
+    }
+}
+
+impl Default for DataRoot {
+    fn default() -> Self {
+        DataRoot::new("./data")
+    }
+}
+
 pub trait DataPersistence: serde::Serialize + for<'de> serde::Deserialize<'de> {
+    /// Schema version written by this build.
+    ///
+    /// Implementors store it as their first field (`schema_version: u32`), so bincode
+    /// encodes it right after the [`FILE_MAGIC`] of every file and [`bin::load_file`] can
+    /// check it before decoding the rest. Bump it whenever the serialized layout changes.
+    const SCHEMA_VERSION: u32;
+
     fn data_type() -> DataType;
     fn id(&self) -> usize;
     fn tag(&self) -> usize;
 
-    fn binary_path(&self) -> PathBuf {
-        Path::new("./data")
-            .join(Self::data_type().folder())
-            .join(format!("t{}-i{}.bin", self.tag(), self.id()))
+    fn binary_path(&self, root: &DataRoot) -> PathBuf {
+        Self::binary_path_for(root, self.tag(), self.id())
+    }
+
+    /// Returns the binary file path of the item identified by `(tag, id)` below `root`.
+    fn binary_path_for(root: &DataRoot, tag: usize, id: usize) -> PathBuf {
+        root.item_path(Self::data_type(), tag, id, "bin")
+    }
+
+    /// Returns the path of the gzip-compressed binary file, i.e. `binary_path` plus `.gz`.
+    fn compressed_binary_path(&self, root: &DataRoot) -> PathBuf {
+        let mut path = self.binary_path(root).into_os_string();
+        path.push(".gz");
+        PathBuf::from(path)
+    }
+
+    /// Migration hook for files written with another schema version.
+    ///
+    /// Called by [`bin::load_file`] with the raw (decompressed) bytes whenever the file
+    /// cannot be decoded as the current layout:
+    ///
+    /// - `Some(version)` with `version != Self::SCHEMA_VERSION` for a file opening with
+    ///   [`FILE_MAGIC`]; `bytes` is the bincode encoding after the magic.
+    /// - `None` for a legacy file without the magic; `bytes` is the whole file, which
+    ///   starts with the schema version if it was written after versions were introduced
+    ///   and with the `id` otherwise, so its first four bytes cannot be trusted.
+    ///
+    /// The default rejects the file with [`incompatible_schema`]; override it to upgrade
+    /// older layouts when the struct changes.
+    fn migrate(version: Option<u32>, _bytes: &[u8]) -> Result<Self, String>
+    where
+        Self: Sized,
+    {
+        Err(incompatible_schema::<Self>(version))
+    }
+
+    /// Hook run by [`bin::save_file`] and [`bin::save_file_compressed`] once the file
+    /// has been written below `root`.
+    ///
+    /// An error is logged as a warning and does not fail the save, since the file itself
+    /// has been written. The default does nothing; simulation results use it to keep
+    /// their index current.
+    fn after_save(&self, _root: &DataRoot) -> Result<(), Box<dyn std::error::Error>> {
+        Ok(())
     }
 
-    fn json_path(&self) -> PathBuf {
-        Path::new("./plots/data")
-            .join(Self::data_type().folder())
-            .join(format!("t{}-i{}.json", self.tag(), self.id()))
+    fn json_path(&self, root: &DataRoot) -> PathBuf {
+        root.item_path(Self::data_type(), self.tag(), self.id(), "json")
     }
 }
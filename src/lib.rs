@@ -27,6 +27,7 @@
 //! The library is organized into focused modules:
 //!
 //! - [`bird`]: Core particle representation and physics
+//! - [`constants`]: Named numerical tolerances shared across modules
 //! - [`vector`]: 3D vector mathematics optimized for performance
 //! - [`simulation`]: High-performance simulation engine with parallel processing
 //! - [`ensemble`]: Initial condition generation with uniform sphere distribution
@@ -79,6 +80,7 @@
 
 // Complete full
 pub mod bird;
+pub mod constants;
 // Complete
 pub mod ensemble;
 pub mod io;
@@ -1,15 +1,11 @@
-fn main() {
-    // This is the entry point of the simulation application.
-    // The main function initializes the simulation environment,
-    // sets up necessary configurations, and starts the simulation loop.
-
-    // For now, we will just print a message indicating that the simulation has started.
-    println!("Simulation started. Implement your simulation logic here.");
+use clap::Parser;
+use flocking_lib::cli::{self, Cli};
 
-    // In a complete implementation, you would initialize your Simulation struct,
-    // set up any necessary parameters, and start the simulation loop.
+fn main() {
+    env_logger::init();
 
-    // Example:
-    // let simulation = Simulation::new();
-    // simulation.run();
+    if let Err(e) = cli::run(Cli::parse()) {
+        eprintln!("Error: {}", e);
+        std::process::exit(1);
+    }
 }
@@ -0,0 +1,172 @@
+//! Batch execution of independent simulations with ensemble-averaged observables.
+//!
+//! Batches can also be described declaratively in a TOML or JSON [`BatchSpec`] file
+//! and executed with [`run_batch`].
+
+use super::*;
+use crate::analysis;
+use crate::ensemble::{self, EntryGenerationParams};
+use rayon::prelude::*;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Runs every request and averages an observable over the runs, step by step.
+///
+/// Members are independent realizations (typically different ensemble entries with the
+/// same parameters) and are executed in parallel with [`run_in_memory`]. For each
+/// snapshot `step` present in at least one run, `observable` is evaluated on the
+/// matching snapshot of every run holding it and summarized with
+/// [`analysis::mean_with_error`].
+///
+/// # Returns
+///
+/// `(step, mean, standard_error)` triples sorted by step. With runs of equal
+/// `total_iterations` and `frame_interval` every step averages over all members; the
+/// error is `NaN` at steps reached by a single run.
+pub fn ensemble_average(
+    requests: Vec<SimulationRequest>,
+    observable: impl Fn(&SimulationSnapshot) -> f64 + Sync,
+) -> Vec<(usize, f64, f64)> {
+    let samples_per_run: Vec<Vec<(usize, f64)>> = requests
+        .into_par_iter()
+        .map(|request| {
+            run_in_memory(request)
+                .snapshots
+                .iter()
+                .map(|snapshot| (snapshot.step, observable(snapshot)))
+                .collect()
+        })
+        .collect();
+
+    let mut by_step: BTreeMap<usize, Vec<f64>> = BTreeMap::new();
+    for (step, value) in samples_per_run.into_iter().flatten() {
+        by_step.entry(step).or_default().push(value);
+    }
+
+    by_step
+        .into_iter()
+        .map(|(step, samples)| {
+            let (mean, error) = analysis::mean_with_error(&samples);
+            (step, mean, error)
+        })
+        .collect()
+}
+
+/// Declarative description of a batch of simulations.
+///
+/// # Format
+///
+/// ```toml
+/// parallel = true
+///
+/// [[runs]]
+/// tag = 1
+/// id = 0
+/// source = { kind = "stored", tag = 1, id = 0 }
+/// params = { num_birds = 500, radius = 1.0, speed = 1.0, dt = 0.01, interaction_radius = 0.5, eta = 0.1, total_iterations = 2000, frame_interval = 10 }
+///
+/// [[runs]]
+/// tag = 1
+/// id = 1
+/// source = { kind = "generated", seed = 42, params = { num_birds = 500, radius = 1.0, speed = 1.0, min_distance = 0.01 } }
+/// params = { num_birds = 500, radius = 1.0, speed = 1.0, dt = 0.01, interaction_radius = 0.5, eta = 0.1, total_iterations = 2000, frame_interval = 10 }
+/// ```
+///
+/// The same structure is accepted as JSON. Generated sources record their seed, so the
+/// initial conditions of every run are reproducible from the spec alone; the angular
+/// noise of the simulation itself is drawn from the thread-local generator.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BatchSpec {
+    /// Execute the runs in parallel on rayon's global thread pool.
+    #[serde(default)]
+    pub parallel: bool,
+    /// Simulations to execute, in order.
+    pub runs: Vec<BatchRun>,
+}
+
+/// A single simulation of a [`BatchSpec`].
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct BatchRun {
+    /// Tag of the saved simulation result.
+    pub tag: usize,
+    /// Id of the saved simulation result.
+    pub id: usize,
+    /// Where the initial conditions come from.
+    pub source: EnsembleSource,
+    /// Simulation parameters; must match the source's bird count, radius, and speed.
+    pub params: SimulationParams,
+}
+
+/// Initial conditions of a [`BatchRun`].
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum EnsembleSource {
+    /// A stored ensemble entry, loaded with [`ensemble::io::load_ensemble`].
+    Stored { tag: usize, id: usize },
+    /// A single entry generated in memory from `params` with the given seed.
+    Generated {
+        seed: u64,
+        params: EntryGenerationParams,
+    },
+}
+
+impl BatchSpec {
+    /// Reads a spec from `path`, parsed as JSON for a `.json` extension and as TOML otherwise.
+    pub fn load(path: &Path) -> Result<BatchSpec, Box<dyn std::error::Error>> {
+        let contents = std::fs::read_to_string(path)?;
+        let spec = if path.extension().and_then(|s| s.to_str()) == Some("json") {
+            serde_json::from_str(&contents)?
+        } else {
+            toml::from_str(&contents)?
+        };
+        Ok(spec)
+    }
+}
+
+impl BatchRun {
+    /// Resolves the source and builds the corresponding [`SimulationRequest`].
+    pub fn to_request(&self, root: &DataRoot) -> Result<SimulationRequest, String> {
+        let entry = match &self.source {
+            EnsembleSource::Stored { tag, id } => ensemble::io::load_ensemble(root, *tag, *id)
+                .map_err(|e| format!("failed to load ensemble t{}-i{}: {}", tag, id, e))?,
+            EnsembleSource::Generated { seed, params } => {
+                params.validate()?;
+                ensemble::generate_parallel(self.tag, 1, *params, Some(*seed), None)?
+                    .pop()
+                    .ok_or("ensemble generation produced no entry")?
+            }
+        };
+
+        SimulationRequest::from_ensemble(entry, self.params, self.id, self.tag)
+    }
+}
+
+/// Executes every run of `spec` and saves the results below `root`.
+///
+/// Each run is resolved with [`BatchRun::to_request`] and executed with [`run`]. With
+/// `spec.parallel` the runs are distributed over rayon's global thread pool; otherwise
+/// they execute in order.
+///
+/// # Errors
+///
+/// Returns the message of the first failing run, prefixed with its position in the spec
+/// and its `(tag, id)`. In parallel mode, runs already in progress still complete.
+pub fn run_batch(spec: &BatchSpec, root: &DataRoot) -> Result<(), String> {
+    let execute = |(index, member): (usize, &BatchRun)| {
+        member
+            .to_request(root)
+            .and_then(|request| run(request, root))
+            .map_err(|e| {
+                format!(
+                    "run {} (t{}-i{}) failed: {}",
+                    index, member.tag, member.id, e
+                )
+            })
+    };
+
+    if spec.parallel {
+        spec.runs.par_iter().enumerate().try_for_each(execute)
+    } else {
+        spec.runs.iter().enumerate().try_for_each(execute)
+    }
+}
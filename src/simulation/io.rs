@@ -1,9 +1,18 @@
 use super::*;
-use crate::io::{DataPersistence, DataType, bin};
+use crate::bird::LegacyBird;
+use crate::io::{DataPersistence, DataRoot, DataType, bin, incompatible_schema};
+use log::warn;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
 use std::sync::mpsc::Receiver;
 use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
 
 impl DataPersistence for SimulationResult {
+    const SCHEMA_VERSION: u32 = SIMULATION_SCHEMA_VERSION;
+
     fn data_type() -> DataType {
         DataType::Simulation
     }
@@ -15,6 +24,172 @@ impl DataPersistence for SimulationResult {
     fn tag(&self) -> usize {
         self.tag
     }
+
+    fn after_save(&self, root: &DataRoot) -> Result<(), Box<dyn std::error::Error>> {
+        update_index(root, IndexEntry::from_result(self, SystemTime::now()))
+    }
+
+    /// Upgrades schema 5 results, which predate bird species and
+    /// [`SimulationParams::species_alignment`], and schema 6 results, which predate
+    /// [`SimulationParams::warmup_steps`]; also reads current results saved before files
+    /// carried the [`FILE_MAGIC`](crate::io::FILE_MAGIC).
+    ///
+    /// A file without the magic is only accepted if it decodes exactly as the layout named
+    /// by its first four bytes; otherwise it predates schema versions and is rejected.
+    fn migrate(version: Option<u32>, bytes: &[u8]) -> Result<Self, String> {
+        let stored = version.or_else(|| bin::leading_version(bytes));
+        let migrated = match stored {
+            Some(5) => bin::decode_exact::<SimulationResultV5>(bytes).map(SimulationResult::from),
+            Some(6) => bin::decode_exact::<SimulationResultV6>(bytes).map(SimulationResult::from),
+            Some(SIMULATION_SCHEMA_VERSION) if version.is_none() => bin::decode_exact(bytes),
+            _ => return Err(incompatible_schema::<Self>(version)),
+        };
+
+        migrated.map_err(|e| match version {
+            Some(version) => format!("corrupt simulation file of schema version {version}: {e}"),
+            None => incompatible_schema::<Self>(None),
+        })
+    }
+}
+
+/// Layout of [`SimulationParams`] in simulation schema 5, before species existed.
+#[derive(serde::Deserialize)]
+struct SimulationParamsV5 {
+    num_birds: usize,
+    radius: f64,
+    speed: f64,
+    dt: f64,
+    interaction_radius: f64,
+    eta: f64,
+    total_iterations: usize,
+    frame_interval: usize,
+    alignment_strength: f64,
+    kernel: AlignmentKernel,
+    drift_correction: bool,
+    cluster_cutoff: Option<f64>,
+    neighbor_tolerance: f64,
+    store_snapshots: bool,
+    alignment_fallback: AlignmentFallback,
+    enable_alignment: bool,
+    repulsion_radius: f64,
+    repulsion_strength: f64,
+    speed_control: SpeedControl,
+}
+
+impl From<SimulationParamsV5> for SimulationParams {
+    fn from(legacy: SimulationParamsV5) -> Self {
+        SimulationParams {
+            num_birds: legacy.num_birds,
+            radius: legacy.radius,
+            speed: legacy.speed,
+            dt: legacy.dt,
+            interaction_radius: legacy.interaction_radius,
+            eta: legacy.eta,
+            total_iterations: legacy.total_iterations,
+            frame_interval: legacy.frame_interval,
+            alignment_strength: legacy.alignment_strength,
+            kernel: legacy.kernel,
+            drift_correction: legacy.drift_correction,
+            cluster_cutoff: legacy.cluster_cutoff,
+            neighbor_tolerance: legacy.neighbor_tolerance,
+            store_snapshots: legacy.store_snapshots,
+            alignment_fallback: legacy.alignment_fallback,
+            enable_alignment: legacy.enable_alignment,
+            repulsion_radius: legacy.repulsion_radius,
+            repulsion_strength: legacy.repulsion_strength,
+            speed_control: legacy.speed_control,
+            species_alignment: SpeciesAlignment::default(),
+            warmup_steps: 0,
+        }
+    }
+}
+
+/// Layout of [`SimulationParams`] in simulation schema 6, before `warmup_steps` existed.
+#[derive(serde::Deserialize)]
+struct SimulationParamsV6 {
+    base: SimulationParamsV5,
+    species_alignment: SpeciesAlignment,
+}
+
+impl From<SimulationParamsV6> for SimulationParams {
+    fn from(legacy: SimulationParamsV6) -> Self {
+        SimulationParams {
+            species_alignment: legacy.species_alignment,
+            ..legacy.base.into()
+        }
+    }
+}
+
+/// Layout of [`SimulationSnapshot`] in simulation schema 5, before birds had a species.
+#[derive(serde::Deserialize)]
+struct SimulationSnapshotV5 {
+    step: usize,
+    timestamp: f64,
+    birds: Vec<LegacyBird>,
+    cluster_count: Option<usize>,
+}
+
+impl From<SimulationSnapshotV5> for SimulationSnapshot {
+    fn from(legacy: SimulationSnapshotV5) -> Self {
+        SimulationSnapshot {
+            step: legacy.step,
+            timestamp: legacy.timestamp,
+            birds: legacy.birds.into_iter().map(Bird::from).collect(),
+            cluster_count: legacy.cluster_count,
+        }
+    }
+}
+
+/// Layout of [`SimulationResult`] in simulation schema 5.
+#[derive(serde::Deserialize)]
+struct SimulationResultV5 {
+    _schema_version: u32,
+    id: usize,
+    tag: usize,
+    ensemble_entry_id: usize,
+    params: SimulationParamsV5,
+    snapshots: Vec<SimulationSnapshotV5>,
+    final_state: Vec<LegacyBird>,
+}
+
+impl From<SimulationResultV5> for SimulationResult {
+    fn from(legacy: SimulationResultV5) -> Self {
+        SimulationResult {
+            schema_version: SIMULATION_SCHEMA_VERSION,
+            id: legacy.id,
+            tag: legacy.tag,
+            ensemble_entry_id: legacy.ensemble_entry_id,
+            params: legacy.params.into(),
+            snapshots: legacy.snapshots.into_iter().map(Into::into).collect(),
+            final_state: legacy.final_state.into_iter().map(Bird::from).collect(),
+        }
+    }
+}
+
+/// Layout of [`SimulationResult`] in simulation schema 6.
+#[derive(serde::Deserialize)]
+struct SimulationResultV6 {
+    _schema_version: u32,
+    id: usize,
+    tag: usize,
+    ensemble_entry_id: usize,
+    params: SimulationParamsV6,
+    snapshots: Vec<SimulationSnapshot>,
+    final_state: Vec<Bird>,
+}
+
+impl From<SimulationResultV6> for SimulationResult {
+    fn from(legacy: SimulationResultV6) -> Self {
+        SimulationResult {
+            schema_version: SIMULATION_SCHEMA_VERSION,
+            id: legacy.id,
+            tag: legacy.tag,
+            ensemble_entry_id: legacy.ensemble_entry_id,
+            params: legacy.params.into(),
+            snapshots: legacy.snapshots,
+            final_state: legacy.final_state,
+        }
+    }
 }
 
 pub fn start_receiver_thread(
@@ -23,25 +198,620 @@ pub fn start_receiver_thread(
     id: usize,
     tag: usize,
     ensemble_entry_id: usize,
+    root: DataRoot,
 ) -> thread::JoinHandle<Result<(), String>> {
     thread::spawn(move || {
-        let expected_snapshots =
-            (params.total_iterations + params.frame_interval - 1) / params.frame_interval;
+        let expected_snapshots = match params.frame_interval {
+            _ if !params.store_snapshots => 1,
+            0 => 0,
+            interval => params.total_iterations.saturating_sub(params.warmup_steps) / interval + 1,
+        };
         let mut snapshots = Vec::with_capacity(expected_snapshots);
 
         while let Ok(snapshot) = rx.recv() {
             snapshots.push(snapshot);
         }
 
-        let result = SimulationResult {
-            id,
-            tag,
-            ensemble_entry_id,
-            params,
-            snapshots,
-        };
+        let result = SimulationResult::from_frames(id, tag, ensemble_entry_id, params, snapshots);
 
-        bin::save_file(&result).map_err(|e| e.to_string())?;
+        bin::save_file(&result, &root).map_err(|e| e.to_string())?;
         Ok(())
     })
 }
+
+/// Like [`start_receiver_thread`], but appends every snapshot to disk as it arrives.
+///
+/// Snapshots are written through a [`SnapshotStreamWriter`] to
+/// [`stream_path_for`]`(root, tag, id)`, so memory use stays bounded regardless of
+/// run length. The header is written once the channel closes.
+pub fn start_streaming_receiver_thread(
+    rx: Receiver<SimulationSnapshot>,
+    params: SimulationParams,
+    id: usize,
+    tag: usize,
+    ensemble_entry_id: usize,
+    root: DataRoot,
+) -> thread::JoinHandle<Result<(), String>> {
+    thread::spawn(move || {
+        let path = stream_path_for(&root, tag, id);
+        let mut writer = SnapshotStreamWriter::create(&path).map_err(|e| e.to_string())?;
+
+        while let Ok(snapshot) = rx.recv() {
+            writer.append(&snapshot).map_err(|e| e.to_string())?;
+        }
+
+        writer
+            .finalize(StreamHeader {
+                id,
+                tag,
+                ensemble_entry_id,
+                params,
+                num_snapshots: 0,
+            })
+            .map_err(|e| e.to_string())
+    })
+}
+
+/// Returns the path of the streamed result identified by `(tag, id)` below `root`.
+pub fn stream_path_for(root: &DataRoot, tag: usize, id: usize) -> PathBuf {
+    root.item_path(DataType::Simulation, tag, id, "stream")
+}
+
+/// Metadata of a streamed simulation result, written when the stream is finalized.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct StreamHeader {
+    /// Unique identifier of the simulation run.
+    pub id: usize,
+    /// Tag grouping related simulation runs.
+    pub tag: usize,
+    /// Identifier of the ensemble entry used as initial condition.
+    pub ensemble_entry_id: usize,
+    /// Parameters the simulation was run with.
+    pub params: SimulationParams,
+    /// Number of snapshot records in the stream.
+    pub num_snapshots: usize,
+}
+
+/// Appends snapshots to a file one at a time instead of holding them in memory.
+///
+/// # File Layout
+///
+/// - One record per snapshot: a little-endian `u64` byte length followed by the
+///   bincode-encoded [`SimulationSnapshot`]
+/// - The bincode-encoded [`StreamHeader`]
+/// - A little-endian `u64` holding the header's byte length
+///
+/// A file without the trailing header (e.g. from an interrupted run) is rejected by
+/// [`SnapshotStreamReader::open`].
+pub struct SnapshotStreamWriter {
+    writer: BufWriter<File>,
+    num_snapshots: usize,
+}
+
+impl SnapshotStreamWriter {
+    /// Creates the stream file at `path`, creating parent directories if missing.
+    pub fn create(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        Ok(SnapshotStreamWriter {
+            writer: BufWriter::new(File::create(path)?),
+            num_snapshots: 0,
+        })
+    }
+
+    /// Appends one snapshot record.
+    pub fn append(
+        &mut self,
+        snapshot: &SimulationSnapshot,
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        let record = bincode::serialize(snapshot)?;
+        self.writer
+            .write_all(&(record.len() as u64).to_le_bytes())?;
+        self.writer.write_all(&record)?;
+        self.num_snapshots += 1;
+        Ok(())
+    }
+
+    /// Writes the header and flushes the file.
+    ///
+    /// The header's `num_snapshots` is overwritten with the number of appended records.
+    pub fn finalize(mut self, mut header: StreamHeader) -> Result<(), Box<dyn std::error::Error>> {
+        header.num_snapshots = self.num_snapshots;
+        let encoded = bincode::serialize(&header)?;
+        self.writer.write_all(&encoded)?;
+        self.writer
+            .write_all(&(encoded.len() as u64).to_le_bytes())?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Reads a file written by [`SnapshotStreamWriter`] one snapshot at a time.
+///
+/// Iterating yields the snapshots in the order they were appended.
+pub struct SnapshotStreamReader {
+    reader: BufReader<File>,
+    header: StreamHeader,
+    remaining: usize,
+    /// Bytes left before the header, which bound the length of every further record.
+    record_bytes: u64,
+}
+
+impl SnapshotStreamReader {
+    /// Opens the stream at `path` and reads its header.
+    ///
+    /// The length fields stored in the file are checked against its size, so a truncated
+    /// or corrupted stream yields an error here or from the iterator instead of a panic
+    /// or an oversized allocation.
+    pub fn open(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let not_a_stream = || format!("{} is not a finalized snapshot stream", path.display());
+        let mut file = File::open(path)?;
+
+        let mut length = [0u8; 8];
+        let header_end = file
+            .metadata()?
+            .len()
+            .checked_sub(8)
+            .ok_or_else(not_a_stream)?;
+        file.seek(SeekFrom::Start(header_end))?;
+        file.read_exact(&mut length)?;
+        let header_length = u64::from_le_bytes(length);
+        let record_bytes = header_end
+            .checked_sub(header_length)
+            .ok_or_else(not_a_stream)?;
+
+        file.seek(SeekFrom::Start(record_bytes))?;
+        let mut encoded = vec![0u8; header_length as usize];
+        file.read_exact(&mut encoded)?;
+        let header: StreamHeader = bincode::deserialize(&encoded)?;
+
+        file.seek(SeekFrom::Start(0))?;
+        Ok(SnapshotStreamReader {
+            reader: BufReader::new(file),
+            remaining: header.num_snapshots,
+            header,
+            record_bytes,
+        })
+    }
+
+    /// Returns the stream's header.
+    pub fn header(&self) -> &StreamHeader {
+        &self.header
+    }
+
+    /// Reads all remaining snapshots into a [`SimulationResult`].
+    pub fn into_result(self) -> Result<SimulationResult, Box<dyn std::error::Error>> {
+        let header = self.header.clone();
+        let snapshots = self.collect::<Result<Vec<_>, _>>()?;
+
+        Ok(SimulationResult::from_frames(
+            header.id,
+            header.tag,
+            header.ensemble_entry_id,
+            header.params,
+            snapshots,
+        ))
+    }
+
+    fn read_record(&mut self) -> Result<SimulationSnapshot, Box<dyn std::error::Error>> {
+        let available = self
+            .record_bytes
+            .checked_sub(8)
+            .ok_or("snapshot stream ends before the record count of its header")?;
+        let mut length = [0u8; 8];
+        self.reader.read_exact(&mut length)?;
+        let record_length = u64::from_le_bytes(length);
+        if record_length > available {
+            return Err(format!(
+                "snapshot record of {} bytes exceeds the {} bytes left in the stream",
+                record_length, available
+            )
+            .into());
+        }
+        self.record_bytes = available - record_length;
+
+        let mut record = vec![0u8; record_length as usize];
+        self.reader.read_exact(&mut record)?;
+        Ok(bincode::deserialize(&record)?)
+    }
+}
+
+impl Iterator for SnapshotStreamReader {
+    type Item = Result<SimulationSnapshot, Box<dyn std::error::Error>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        self.remaining -= 1;
+        Some(self.read_record())
+    }
+}
+
+/// Exports a stored simulation result as newline-delimited JSON (NDJSON).
+///
+/// Loads the binary result `(tag, id)` from `root` and writes one compact JSON object
+/// per snapshot line, each holding `step`, `timestamp`, and `birds`. Downstream tools
+/// can stream-parse the file frame by frame instead of loading one huge document.
+///
+/// # Arguments
+///
+/// * `root` - [`DataRoot`] holding the binary simulation result
+/// * `tag` - Tag of the stored result
+/// * `id` - Identifier of the stored result
+/// * `output_path` - Destination file; parent directories are created if missing
+pub fn export_to_ndjson(
+    root: &DataRoot,
+    tag: usize,
+    id: usize,
+    output_path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let result: SimulationResult =
+        bin::load_file(&SimulationResult::binary_path_for(root, tag, id))?;
+
+    if let Some(parent) = output_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut writer = BufWriter::new(File::create(output_path)?);
+    for snapshot in &result.snapshots {
+        serde_json::to_writer(&mut writer, snapshot)?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Exports a stored simulation result as a single JSON document.
+///
+/// Loads the binary result `(tag, id)` from `root` and writes an object with three keys:
+/// `metadata` (`id`, `tag`, `ensemble_entry_id`, `num_snapshots`, `num_birds`),
+/// `parameters` (the [`SimulationParams`]), and `snapshots`, where each entry holds
+/// `step`, `timestamp`, and a `birds` array of `{position, velocity}` objects.
+///
+/// # Arguments
+///
+/// * `root` - [`DataRoot`] holding the binary simulation result
+/// * `tag` - Tag of the stored result
+/// * `id` - Identifier of the stored result
+/// * `output_path` - Destination file; parent directories are created if missing
+pub fn export_to_json(
+    root: &DataRoot,
+    tag: usize,
+    id: usize,
+    output_path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let result: SimulationResult =
+        bin::load_file(&SimulationResult::binary_path_for(root, tag, id))?;
+
+    if let Some(parent) = output_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let document = serde_json::json!({
+        "metadata": {
+            "schema_version": result.schema_version,
+            "id": result.id,
+            "tag": result.tag,
+            "ensemble_entry_id": result.ensemble_entry_id,
+            "num_snapshots": result.snapshots.len(),
+            "num_birds": result.params.num_birds,
+        },
+        "parameters": result.params,
+        "snapshots": result.snapshots,
+    });
+
+    let mut writer = BufWriter::new(File::create(output_path)?);
+    serde_json::to_writer_pretty(&mut writer, &document)?;
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Loads the binary result `(tag, id)` from `root` and returns its parameters together
+/// with the snapshot recorded at `step`.
+///
+/// # Errors
+///
+/// Fails if the result cannot be loaded or holds no snapshot at `step` (e.g. because
+/// `step` is not a multiple of its `frame_interval`).
+pub fn load_snapshot_at(
+    root: &DataRoot,
+    tag: usize,
+    id: usize,
+    step: usize,
+) -> Result<(SimulationParams, SimulationSnapshot), Box<dyn std::error::Error>> {
+    let result: SimulationResult =
+        bin::load_file(&SimulationResult::binary_path_for(root, tag, id))?;
+    let snapshot = result
+        .snapshots
+        .into_iter()
+        .find(|snapshot| snapshot.step == step)
+        .ok_or_else(|| format!("result t{}-i{} has no snapshot at step {}", tag, id, step))?;
+
+    Ok((result.params, snapshot))
+}
+
+/// Exports the interaction graph of one stored snapshot as a plain-text edge list.
+///
+/// Loads the binary result `(tag, id)` from `root`, takes the snapshot recorded at
+/// `step`, and writes one `i j` line per edge of [`crate::analysis::interaction_graph`],
+/// using the run's `radius` and `interaction_radius + neighbor_tolerance` so the edges are the
+/// neighborhoods the alignment step saw. A leading `#` comment records the step and the
+/// bird count, since isolated birds have no edge. The format is read directly by
+/// common network tools (e.g. networkx's `read_edgelist`).
+///
+/// # Arguments
+///
+/// * `root` - [`DataRoot`] holding the binary simulation result
+/// * `tag` - Tag of the stored result
+/// * `id` - Identifier of the stored result
+/// * `step` - Step of the snapshot to export; it must have been stored
+/// * `output_path` - Destination file; parent directories are created if missing
+pub fn export_interaction_graph(
+    root: &DataRoot,
+    tag: usize,
+    id: usize,
+    step: usize,
+    output_path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let (params, snapshot) = load_snapshot_at(root, tag, id, step)?;
+    let edges = crate::analysis::interaction_graph(
+        &snapshot.birds,
+        params.radius,
+        params.interaction_radius + params.neighbor_tolerance,
+    );
+
+    if let Some(parent) = output_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut writer = BufWriter::new(File::create(output_path)?);
+    writeln!(writer, "# step {} birds {}", step, snapshot.birds.len())?;
+    for (i, j) in edges {
+        writeln!(writer, "{} {}", i, j)?;
+    }
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Exports the heading histogram of one stored snapshot as CSV.
+///
+/// Loads the binary result `(tag, id)` from `root`, takes the snapshot recorded at
+/// `step`, and writes a `bin_center,count` header followed by one line per bin of
+/// [`crate::analysis::heading_histogram`], with bin centers in radians. The columns
+/// feed a polar bar plot directly (e.g. matplotlib's `bar` on a polar axis).
+///
+/// # Arguments
+///
+/// * `root` - [`DataRoot`] holding the binary simulation result
+/// * `tag` - Tag of the stored result
+/// * `id` - Identifier of the stored result
+/// * `step` - Step of the snapshot to export; it must have been stored
+/// * `bins` - Number of angular bins; must be positive
+/// * `output_path` - Destination file; parent directories are created if missing
+pub fn export_heading_histogram(
+    root: &DataRoot,
+    tag: usize,
+    id: usize,
+    step: usize,
+    bins: usize,
+    output_path: &Path,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if bins == 0 {
+        return Err("heading histogram needs at least one bin".into());
+    }
+
+    let (_, snapshot) = load_snapshot_at(root, tag, id, step)?;
+    let counts = crate::analysis::heading_histogram(&snapshot.birds, bins);
+
+    if let Some(parent) = output_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let width = std::f64::consts::TAU / bins as f64;
+    let mut writer = BufWriter::new(File::create(output_path)?);
+    writeln!(writer, "bin_center,count")?;
+    for (k, count) in counts.into_iter().enumerate() {
+        writeln!(writer, "{},{}", (k as f64 + 0.5) * width, count)?;
+    }
+    writer.flush()?;
+
+    Ok(())
+}
+
+/// Lists the `(tag, id)` pairs of all readable simulation results below `root`.
+///
+/// Unreadable files are skipped with a warning; see [`bin::list_tags_and_ids`].
+pub fn list_simulation_tags_and_ids(
+    root: &DataRoot,
+) -> Result<Vec<(usize, usize)>, std::io::Error> {
+    bin::list_tags_and_ids::<SimulationResult>(root)
+}
+
+/// Summary of one stored simulation result, as recorded in the index.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct IndexEntry {
+    /// Tag of the stored result.
+    pub tag: usize,
+    /// Identifier of the stored result.
+    pub id: usize,
+    /// Number of birds simulated.
+    pub num_birds: usize,
+    /// Total number of steps of the run (`total_iterations`).
+    pub iterations: usize,
+    /// Noise strength of the run.
+    pub eta: f64,
+    /// Time the result was saved, in seconds since the Unix epoch.
+    pub created_at: u64,
+}
+
+impl IndexEntry {
+    fn from_result(result: &SimulationResult, created_at: SystemTime) -> Self {
+        IndexEntry {
+            tag: result.tag,
+            id: result.id,
+            num_birds: result.params.num_birds,
+            iterations: result.params.total_iterations,
+            eta: result.params.eta,
+            created_at: created_at
+                .duration_since(UNIX_EPOCH)
+                .map(|duration| duration.as_secs())
+                .unwrap_or_default(),
+        }
+    }
+}
+
+/// Serializes appends to and rewrites of index files within this process.
+static INDEX_LOCK: Mutex<()> = Mutex::new(());
+
+/// Returns the path of the simulation index below `root`, `{root}/simulation/index.json`.
+///
+/// The index sits at the top of the simulation folder in both file layouts. It is a JSON
+/// array of [`IndexEntry`] objects, one per line; a later entry for the same `(tag, id)`
+/// supersedes the earlier ones.
+pub fn index_path(root: &DataRoot) -> PathBuf {
+    root.folder(DataType::Simulation).join("index.json")
+}
+
+/// Lists the stored simulation results recorded in the index, sorted by `(tag, id)`.
+///
+/// Only the small JSON index is read, so this stays fast for large campaigns where
+/// [`list_simulation_tags_and_ids`] would load every result. Saving a result with
+/// [`bin::save_file`] or [`bin::save_file_compressed`] adds its entry, which replaces
+/// any earlier entry for the same `(tag, id)`; results written by other means (streamed
+/// runs, copied files, other processes saving at the same time) are missing until
+/// [`rebuild_index`] is called. A missing index lists nothing.
+///
+/// # Errors
+///
+/// Returns an error if the index exists but cannot be read or parsed.
+pub fn list_index(root: &DataRoot) -> Result<Vec<IndexEntry>, Box<dyn std::error::Error>> {
+    let path = index_path(root);
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let entries: Vec<IndexEntry> = serde_json::from_reader(BufReader::new(File::open(&path)?))
+        .map_err(|e| format!("failed to read index {}: {}", path.display(), e))?;
+    let latest: std::collections::BTreeMap<(usize, usize), IndexEntry> = entries
+        .into_iter()
+        .map(|entry| ((entry.tag, entry.id), entry))
+        .collect();
+    Ok(latest.into_values().collect())
+}
+
+/// Recreates the index from the stored simulation results and returns its entries.
+///
+/// Every file found by [`bin::list_files`] is loaded; unreadable files are skipped with
+/// a warning, as in [`list_simulation_tags_and_ids`]. `created_at` is taken from the
+/// file's modification time. The new index holds a single entry per result, so this
+/// also compacts an index grown by repeated saves. Use it to recover a lost or
+/// corrupted index, or to pick up results saved without it.
+pub fn rebuild_index(root: &DataRoot) -> Result<Vec<IndexEntry>, Box<dyn std::error::Error>> {
+    let _guard = INDEX_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    rebuild_index_locked(root)
+}
+
+fn rebuild_index_locked(root: &DataRoot) -> Result<Vec<IndexEntry>, Box<dyn std::error::Error>> {
+    let mut entries = std::collections::BTreeMap::new();
+    for path in bin::list_files::<SimulationResult>(root)? {
+        match bin::load_file::<SimulationResult>(&path) {
+            Ok(result) => {
+                let modified = std::fs::metadata(&path)?.modified()?;
+                entries.insert(
+                    (result.tag, result.id),
+                    IndexEntry::from_result(&result, modified),
+                );
+            }
+            Err(e) => warn!("Skipping unreadable file {}: {}", path.display(), e),
+        }
+    }
+
+    let entries: Vec<IndexEntry> = entries.into_values().collect();
+    write_index(root, &entries)?;
+    Ok(entries)
+}
+
+/// Adds `entry` to the index, superseding any previous entry for its `(tag, id)`.
+///
+/// The entry is written in place of the array's closing bracket, so the cost of a save
+/// does not grow with the size of the index. An index that does not end in a JSON
+/// array is rebuilt from the stored results instead, which already include the result
+/// just saved.
+fn update_index(root: &DataRoot, entry: IndexEntry) -> Result<(), Box<dyn std::error::Error>> {
+    let _guard = INDEX_LOCK
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+    let path = index_path(root);
+    if !path.exists() {
+        return write_index(root, &[entry]);
+    }
+
+    let mut file = File::options().read(true).write(true).open(&path)?;
+    let Some((bracket, empty)) = find_array_end(&mut file)? else {
+        warn!(
+            "Rebuilding simulation index {}: no closing bracket",
+            path.display()
+        );
+        rebuild_index_locked(root)?;
+        return Ok(());
+    };
+
+    let separator = if empty { "" } else { "," };
+    let tail = format!("{}\n{}\n]\n", separator, serde_json::to_string(&entry)?);
+    file.seek(SeekFrom::Start(bracket))?;
+    file.write_all(tail.as_bytes())?;
+    file.set_len(bracket + tail.len() as u64)?;
+
+    Ok(())
+}
+
+/// Locates the closing `]` of the JSON array in `file`, reading only its last bytes.
+///
+/// Returns the bracket's offset and whether the array is empty, or `None` if the file
+/// does not end in `]` (apart from whitespace).
+fn find_array_end(file: &mut File) -> Result<Option<(u64, bool)>, std::io::Error> {
+    let length = file.metadata()?.len();
+    let start = length.saturating_sub(64);
+    let mut tail = Vec::new();
+    file.seek(SeekFrom::Start(start))?;
+    file.read_to_end(&mut tail)?;
+
+    let trimmed = tail.trim_ascii_end();
+    if trimmed.last() != Some(&b']') {
+        return Ok(None);
+    }
+    let bracket = trimmed.len() - 1;
+    let empty = trimmed[..bracket].trim_ascii_end().ends_with(b"[");
+    Ok(Some((start + bracket as u64, empty)))
+}
+
+/// Writes the index through a temporary file, so readers never see a partial index.
+fn write_index(root: &DataRoot, entries: &[IndexEntry]) -> Result<(), Box<dyn std::error::Error>> {
+    let path = index_path(root);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let temp_path = path.with_extension("json.tmp");
+    let mut writer = BufWriter::new(File::create(&temp_path)?);
+    write!(writer, "[")?;
+    for (i, entry) in entries.iter().enumerate() {
+        writeln!(writer, "{}", if i == 0 { "" } else { "," })?;
+        serde_json::to_writer(&mut writer, entry)?;
+    }
+    writeln!(writer, "\n]")?;
+    writer.flush()?;
+    drop(writer);
+    std::fs::rename(&temp_path, &path)?;
+
+    Ok(())
+}
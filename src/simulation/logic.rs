@@ -2,27 +2,103 @@
 //!
 
 use super::*;
+use crate::analysis;
 use crate::bird::Bird;
+use crate::constants::{COINCIDENT_DISTANCE, DEGENERATE_VELOCITY};
 use crate::vector::Vec3;
-use log::error;
+use log::{error, warn};
 use rayon::prelude::*;
-use std::sync::mpsc;
 
 impl Engine {
     /// Creates a new simulation instance from a request with optimized memory allocation.
     ///
-    pub(super) fn new(request: SimulationRequest, tx: mpsc::Sender<SimulationSnapshot>) -> Self {
+    /// Frames are sent through `tx`, an `mpsc::Sender` or `mpsc::SyncSender` (see
+    /// [`FrameSender`]). Driving the engine directly with [`Engine::step_many`] or
+    /// [`Engine::run_until`] is meant for embedding and benchmarks; the `run*` functions
+    /// wrap it with validation and I/O.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `request.params.num_birds` is zero or differs from the number of
+    /// initial values, a leader index is out of range, a forbidden band is not a valid
+    /// polar angle range, or a bird's species is not below [`MAX_SPECIES`].
+    pub fn new(request: SimulationRequest, tx: impl Into<FrameSender>) -> Self {
         if request.params.num_birds < 1 {
             panic!("Simulation requires at least one bird")
         }
+        if request.initial_values.len() != request.params.num_birds {
+            panic!(
+                "Simulation expects {} birds (params.num_birds) but got {} initial values",
+                request.params.num_birds,
+                request.initial_values.len()
+            )
+        }
+        let mut pinned = vec![false; request.params.num_birds];
+        for &leader in &request.leaders {
+            if leader >= request.params.num_birds {
+                panic!(
+                    "Leader index {} out of range for {} birds",
+                    leader, request.params.num_birds
+                )
+            }
+            pinned[leader] = true;
+        }
+        for &(theta_min, theta_max) in &request.forbidden_bands {
+            if !(0.0 <= theta_min && theta_min < theta_max && theta_max <= std::f64::consts::PI) {
+                panic!("Invalid forbidden band [{}, {}]", theta_min, theta_max)
+            }
+        }
+        check_species(&request.initial_values);
         Engine {
             particles_a: request.initial_values,
             particles_b: vec![Bird::default(); request.params.num_birds],
             params: request.params,
             step_count: 0,
             current_timestamp: 0.0,
-            frame_sender: tx,
+            frame_sender: tx.into(),
+            predators: request.predators,
+            pinned,
+            forbidden_bands: request.forbidden_bands,
+            rule: None,
+        }
+    }
+
+    /// Replaces the built-in alignment step with a user-defined [`InteractionRule`].
+    ///
+    /// The rule supersedes `enable_alignment`, `alignment_strength`, `kernel`, and
+    /// `species_alignment`; noise, repulsion, predators, leaders, and forbidden bands
+    /// still apply. The rule survives [`Engine::reset`].
+    pub fn with_rule(mut self, rule: Box<dyn InteractionRule>) -> Self {
+        self.rule = Some(rule);
+        self
+    }
+
+    /// Restarts the engine from new initial conditions, reusing the particle buffers.
+    ///
+    /// Both buffers keep their capacity, so repeated runs of equal size (e.g. in a
+    /// parameter sweep) do not reallocate. The step counter and simulation time are
+    /// reset to zero and `params.num_birds` is updated to the new bird count;
+    /// parameters, predators, leaders, forbidden bands, and the frame channel are kept.
+    /// Leader indices beyond the new bird count are dropped.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `initial_values` is empty or holds a species not below [`MAX_SPECIES`].
+    pub fn reset(&mut self, initial_values: Vec<Bird>) {
+        if initial_values.is_empty() {
+            panic!("Simulation requires at least one bird")
         }
+        check_species(&initial_values);
+
+        self.particles_a.clear();
+        self.particles_a.extend_from_slice(&initial_values);
+        self.particles_b.clear();
+        self.particles_b
+            .resize(initial_values.len(), Bird::default());
+        self.pinned.resize(initial_values.len(), false);
+        self.params.num_birds = initial_values.len();
+        self.step_count = 0;
+        self.current_timestamp = 0.0;
     }
 
     /// Returns an immutable reference to the simulation configuration parameters.
@@ -45,26 +121,152 @@ impl Engine {
         &self.particles_a
     }
 
+    /// Returns the polarization of the current state.
+    ///
+    /// Equal to `snapshot().order_parameter()`, but computed on the live particle buffer
+    /// without cloning it, so it is cheap enough for per-step stopping conditions.
+    pub fn current_order_parameter(&self) -> f64 {
+        analysis::polarization(&self.particles_a)
+    }
+
+    /// Captures the current state as a snapshot, without sending it.
+    ///
+    /// The snapshot is identical to the frame the engine would emit at this step,
+    /// including the cluster count when [`SimulationParams::cluster_cutoff`] is set, so
+    /// GUIs and tests can sample the state at arbitrary moments.
+    pub fn snapshot(&self) -> SimulationSnapshot {
+        SimulationSnapshot {
+            step: self.step_count,
+            timestamp: self.current_timestamp,
+            birds: self.particles_a.clone(),
+            cluster_count: self.params.cluster_cutoff.map(|cutoff| {
+                analysis::find_clusters(&self.particles_a, self.params.radius, cutoff).len()
+            }),
+        }
+    }
+
+    /// Returns the indices of the current neighbors of bird `index`, in ascending order.
+    ///
+    /// Uses the same inclusion rule as the alignment step: other birds strictly closer
+    /// than `interaction_radius + neighbor_tolerance` (geodesic) and not at the same
+    /// position. Bird `index` itself is never listed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of range.
+    pub fn neighbors(&self, index: usize) -> Vec<usize> {
+        let bird = &self.particles_a[index];
+        self.particles_a
+            .iter()
+            .enumerate()
+            .filter(|&(other_index, other)| {
+                other_index != index
+                    && is_neighbor(bird.distance_from(other, self.params.radius), &self.params)
+            })
+            .map(|(other_index, _)| other_index)
+            .collect()
+    }
+
     /// Executes the complete simulation with responsive stop control and frame capture.
     ///
+    /// With [`SimulationParams::store_snapshots`] off, no frames are emitted while
+    /// stepping and a single frame with the final state is sent at the end instead.
     pub fn run(&mut self) {
-        for _ in 0..self.params.total_iterations {
+        if self.params.store_snapshots {
+            self.step_many(self.params.total_iterations);
+        } else {
+            for _ in 0..self.params.total_iterations {
+                self.step();
+            }
+            self.send_frame_data();
+        }
+    }
+
+    /// Advances the simulation by `n` time steps in one batch.
+    ///
+    /// Both particle buffers are reused across all steps, so no per-step allocation
+    /// happens beyond frame emission. A snapshot is sent only when the global step
+    /// counter reaches a multiple of `frame_interval`, exactly as in [`Engine::run`],
+    /// so calling `step_many` repeatedly yields the same frames as one long run. No
+    /// frames are sent before step [`SimulationParams::warmup_steps`], nor at all with a
+    /// `frame_interval` of zero.
+    pub fn step_many(&mut self, n: usize) {
+        let frame_interval = self.params.frame_interval;
+        let warmup_steps = self.params.warmup_steps;
+        for _ in 0..n {
             self.step();
 
-            // Send frame data if interval reached
-            if self.step_count % self.params.frame_interval == 0 {
+            // Send frame data if interval reached after the warm-up
+            if frame_interval > 0
+                && self.step_count >= warmup_steps
+                && self.step_count.is_multiple_of(frame_interval)
+            {
                 self.send_frame_data();
             }
         }
     }
 
+    /// Runs until the order parameter stabilizes or `max_iterations` steps have elapsed.
+    ///
+    /// The polarization ([`analysis::polarization`]) is measured every `frame_interval`
+    /// steps (every step if it is zero). The run is considered converged once the last
+    /// `window + 1` measurements, i.e. the last `window` measurement intervals, all lie
+    /// within a spread (max - min) of at most `tolerance`. Frames are emitted exactly as in
+    /// [`Engine::step_many`]; `max_iterations` counts steps taken by this call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window` is zero.
+    pub fn run_until(
+        &mut self,
+        tolerance: f64,
+        window: usize,
+        max_iterations: usize,
+    ) -> Termination {
+        assert!(window > 0, "convergence window must be positive");
+
+        // Without frames (interval 0) the order parameter is measured every step
+        let interval = self.params.frame_interval.max(1);
+        let mut history = std::collections::VecDeque::with_capacity(window + 1);
+        history.push_back(self.current_order_parameter());
+
+        let mut taken = 0;
+        while taken < max_iterations {
+            let n = interval.min(max_iterations - taken);
+            self.step_many(n);
+            taken += n;
+
+            if history.len() == window + 1 {
+                history.pop_front();
+            }
+            history.push_back(self.current_order_parameter());
+
+            if history.len() == window + 1 {
+                let (min, max) = history
+                    .iter()
+                    .fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), &x| {
+                        (min.min(x), max.max(x))
+                    });
+                if max - min <= tolerance {
+                    return Termination::Converged;
+                }
+            }
+        }
+
+        Termination::MaxIterations
+    }
+
     /// Advances the simulation by one time step using optimized parallel processing.
     ///
-    fn step(&mut self) {
+    pub(super) fn step(&mut self) {
         // Extract parameters needed for computation to avoid borrowing conflicts
         let params = self.params;
         // Get immutable reference to current state for reading
         let current_state = &self.particles_a;
+        let predators = &self.predators;
+        let pinned = &self.pinned;
+        let forbidden_bands = &self.forbidden_bands;
+        let rule = self.rule.as_deref();
 
         // Parallel computation using rayon for maximum CPU utilization
         // Each thread processes a subset of particles independently
@@ -72,8 +274,18 @@ impl Engine {
             .par_iter_mut()
             .enumerate()
             .for_each(|(i, particle_next)| {
-                // Calculate the new state for particle i based on current state
-                *particle_next = update_particle_state(i, current_state, params);
+                // Calculate the new state for particle i based on current state;
+                // pinned leaders keep their velocity and only move
+                *particle_next = if pinned[i] {
+                    advance_on_sphere(i, current_state[i], params)
+                } else {
+                    let moved = update_particle_state(i, current_state, params, predators, rule);
+                    if forbidden_bands.is_empty() {
+                        moved
+                    } else {
+                        reflect_off_bands(i, &current_state[i], moved, forbidden_bands, params)
+                    }
+                };
             });
 
         // Swap buffers - this is extremely cheap (just pointer swaps)
@@ -89,15 +301,10 @@ impl Engine {
     /// clones vector (bottleneck) and sends it to the receiver.
     fn send_frame_data(&self) {
         let sender = &self.frame_sender;
-
-        let frame = SimulationSnapshot {
-            step: self.step_count,
-            timestamp: self.current_timestamp,
-            birds: self.particles_a.clone(),
-        };
+        let frame = self.snapshot();
 
         // Non-blocking send - if receiver is gone, just continue
-        let _ = sender.send(frame).unwrap_or_else(|err| {
+        sender.send(frame).unwrap_or_else(|err| {
             error!("Failed to send frame data: {}", err);
         });
     }
@@ -113,17 +320,30 @@ impl Engine {
 ///
 /// 1. **Neighbor Detection**: Identifies particles within the interaction radius
 /// 2. **Velocity Transport**: Applies parallel transport for velocities on curved geometry  
-/// 3. **Alignment Computation**: Calculates averaged velocity from all neighbors
+/// 3. **Alignment Computation**: Calculates the kernel- and species-weighted average velocity
+///    of all neighbors (see [`SpeciesAlignment`])
 /// 4. **Noise Application**: Adds stochastic perturbations to prevent artificial ordering
-/// 5. **Motion Integration**: Updates position using spherical geodesic motion
+/// 5. **Bird Repulsion**: Steers away from birds within the repulsion radius, if enabled
+/// 6. **Predator Repulsion**: Steers away from predators within the interaction radius
+/// 7. **Motion Integration**: Updates position using spherical geodesic motion
+///
+/// With a `rule` (see [`Engine::with_rule`]), steps 2-3 instead sum the rule's
+/// contributions over all neighbors and combine them into the target velocity.
+///
+/// With `enable_alignment` off or an `alignment_strength` of zero, steps 2-3 are skipped
+/// and the current velocity of every bird, isolated or not, is only perturbed by noise
+/// before the repulsion steps. Without repulsion this is a persistent random walk whose
+/// heading diffuses with rotational diffusion coefficient `η² / (2 dt)`.
 ///
 /// # Flocking Behavior
 ///
 /// The function implements classic flocking rules adapted for spherical topology:
-/// - **Alignment**: Particles tend to match their neighbors' velocity directions
+/// - **Alignment**: Particles tend to match their neighbors' velocity directions, blended
+///   with their own velocity according to `alignment_strength`
 /// - **Noise**: Random perturbations introduce realistic behavioral variations
 /// - **Isolation Handling**: Particles without neighbors maintain current velocity
-/// - **Speed Regulation**: All particles maintain constant speed magnitude
+/// - **Speed Regulation**: All particles maintain constant speed magnitude, or with
+///   [`SpeedControl::Capped`] a speed of at most the cap
 ///
 /// # Performance Optimizations
 ///
@@ -137,6 +357,8 @@ impl Engine {
 /// * `particle_index` - Index of the particle to update in the state array
 /// * `current_state` - Immutable reference to all particle states at current time
 /// * `params` - Simulation parameters including interaction radius and noise level
+/// * `predators` - Fixed repulsive stimuli as `(position, strength)` pairs
+/// * `rule` - Optional interaction rule replacing the built-in alignment
 ///
 /// # Returns
 ///
@@ -145,67 +367,342 @@ fn update_particle_state(
     particle_index: usize,
     current_state: &[Bird],
     params: SimulationParams,
+    predators: &[(Vec3, f64)],
+    rule: Option<&dyn InteractionRule>,
 ) -> Bird {
     let current_bird = &current_state[particle_index];
 
     // Collect velocities from neighboring particles within interaction radius
     // Apply parallel transport to maintain tangent space consistency on sphere
-    let transported_velocities: Vec<Vec3> = current_state
-        .iter()
-        .enumerate()
-        .filter_map(|(neighbor_index, neighbor_bird)| {
-            // Exclude self-interaction to prevent trivial alignment
-            if neighbor_index == particle_index {
-                return None;
-            }
+    let aligning = rule.is_none() && params.enable_alignment && params.alignment_strength != 0.0;
+    let transported_velocities: Vec<(f64, f64, Vec3)> = if !aligning {
+        Vec::new()
+    } else {
+        current_state
+            .iter()
+            .enumerate()
+            .filter_map(|(neighbor_index, neighbor_bird)| {
+                // Exclude self-interaction to prevent trivial alignment
+                if neighbor_index == particle_index {
+                    return None;
+                }
 
-            // Calculate geodesic distance between particles on sphere surface
-            let geodesic_distance = current_bird.distance_from(neighbor_bird, params.radius);
+                // Species that do not couple ignore each other entirely
+                let coupling = params
+                    .species_alignment
+                    .coupling(current_bird.species, neighbor_bird.species);
+                if coupling <= 0.0 {
+                    return None;
+                }
 
-            // Include neighbor if within interaction radius and not at same position
-            if geodesic_distance > f64::EPSILON && geodesic_distance < params.interaction_radius {
-                Some(neighbor_bird.parallel_transport_velocity(current_bird))
-            } else {
-                None
-            }
-        })
-        .collect();
+                // Calculate geodesic distance between particles on sphere surface
+                let geodesic_distance = current_bird.distance_from(neighbor_bird, params.radius);
+
+                if is_neighbor(geodesic_distance, &params) {
+                    Some((
+                        geodesic_distance,
+                        coupling,
+                        neighbor_bird.parallel_transport_velocity(current_bird),
+                    ))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    };
 
     // Compute alignment velocity based on neighbor interactions
-    let transport_velocity = if transported_velocities.is_empty() {
+    let transport_velocity = if let Some(rule) = rule {
+        // User-defined rule: sum the contributions of all neighbors
+        let mut total = Vec3::zero();
+        let mut count = 0;
+        for (neighbor_index, neighbor_bird) in current_state.iter().enumerate() {
+            if neighbor_index == particle_index {
+                continue;
+            }
+            let geodesic_distance = current_bird.distance_from(neighbor_bird, params.radius);
+            if is_neighbor(geodesic_distance, &params) {
+                total += rule.contribution(current_bird, neighbor_bird, geodesic_distance);
+                count += 1;
+            }
+        }
+        steer(
+            rule.combine(current_bird.velocity, total, count),
+            current_bird,
+            &params,
+        )
+    } else if !aligning {
+        // No alignment: only noise (and repulsion below) reorients the bird
+        Bird::add_noise(current_bird.velocity, current_bird, params.eta)
+    } else if transported_velocities.is_empty() {
         // Isolated particle maintains current velocity direction
         current_bird.velocity
     } else {
         // Compute vector sum of all transported neighbor velocities
-        let velocity_sum = transported_velocities
-            .iter()
-            .fold(Vec3::zero(), |accumulator, velocity| {
-                accumulator + *velocity
-            });
+        let mean_velocity = match params.kernel {
+            AlignmentKernel::Uniform => {
+                let velocity_sum: Vec3 = transported_velocities
+                    .iter()
+                    .map(|(_, coupling, velocity)| *velocity * *coupling)
+                    .sum();
+                let coupling_sum: f64 = transported_velocities
+                    .iter()
+                    .map(|(_, coupling, _)| coupling)
+                    .sum();
+
+                // Calculate mean velocity direction from neighbors
+                velocity_sum / coupling_sum
+            }
+            kernel => {
+                // Distance-weighted mean of the neighbor velocities
+                let mut velocity_sum = Vec3::zero();
+                let mut weight_sum = 0.0;
+                for (distance, coupling, velocity) in &transported_velocities {
+                    let weight = coupling * kernel.weight(*distance, params.interaction_radius);
+                    velocity_sum += *velocity * weight;
+                    weight_sum += weight;
+                }
 
-        // Calculate mean velocity direction from neighbors
-        let mean_velocity = velocity_sum / transported_velocities.len() as f64;
+                if weight_sum > f64::EPSILON {
+                    velocity_sum / weight_sum
+                } else {
+                    Vec3::zero()
+                }
+            }
+        };
 
-        // Handle near-zero alignment case to prevent numerical instability
-        if mean_velocity.norm() < 1e-6 {
-            // Apply noise to current velocity when alignment is negligible
-            Bird::add_noise(current_bird.velocity, current_bird, params.eta)
+        // Blend own velocity with the neighbor average; s = 1 is the pure Vicsek rule
+        let strength = params.alignment_strength;
+        let target_velocity = current_bird.velocity * (1.0 - strength) + mean_velocity * strength;
+
+        steer(target_velocity, current_bird, &params)
+    };
+
+    // Steer away from crowding birds, if repulsion is enabled
+    let transport_velocity = if params.repulsion_strength > 0.0 && params.repulsion_radius > 0.0 {
+        let crowding: Vec<(Vec3, f64)> = current_state
+            .iter()
+            .enumerate()
+            .filter(|&(other_index, other)| {
+                let distance = current_bird.distance_from(other, params.radius);
+                other_index != particle_index
+                    && distance > COINCIDENT_DISTANCE
+                    && distance < params.repulsion_radius
+            })
+            .map(|(_, other)| (other.position, params.repulsion_strength))
+            .collect();
+        if crowding.is_empty() {
+            transport_velocity
         } else {
-            // Normalize and scale to target speed, then apply noise
-            Bird::add_noise(
-                mean_velocity.normalize() * params.speed,
+            repel(
+                transport_velocity,
                 current_bird,
-                params.eta,
+                &crowding,
+                params.repulsion_radius,
+                params,
             )
         }
+    } else {
+        transport_velocity
+    };
+
+    // Steer away from nearby predators, if any
+    let transport_velocity = if predators.is_empty() {
+        transport_velocity
+    } else {
+        repel(
+            transport_velocity,
+            current_bird,
+            predators,
+            params.interaction_radius,
+            params,
+        )
     };
 
     // Create intermediate bird state with updated velocity
     let updated_bird = Bird {
-        position: current_bird.position,
         velocity: transport_velocity,
+        ..*current_bird
     };
 
     // Integrate motion on sphere surface for one time step
-    updated_bird.move_on_sphere(params.dt, params.radius, params.speed)
+    advance_on_sphere(particle_index, updated_bird, params)
+}
+
+/// Returns a velocity of magnitude `speed` in a uniformly random tangent direction at `bird`.
+fn random_tangent_velocity(bird: &Bird, speed: f64) -> Vec3 {
+    use rand::Rng;
+
+    let alpha = rand::rng().random_range(0.0..std::f64::consts::TAU);
+    let (e_theta, e_phi) = bird.tangent_basis();
+    (e_phi * alpha.cos() + e_theta * alpha.sin()) * speed
+}
+
+/// Keeps a bird out of the forbidden latitude bands.
+///
+/// `moved` is the unconstrained result of stepping `old`. If its path enters or crosses a
+/// band, the step is retaken from `old` with the meridional velocity component reversed;
+/// if that is blocked too, the bird stays at `old` with the reflected velocity. See
+/// [`SimulationRequest::forbidden_bands`].
+fn reflect_off_bands(
+    particle_index: usize,
+    old: &Bird,
+    moved: Bird,
+    forbidden_bands: &[(f64, f64)],
+    params: SimulationParams,
+) -> Bird {
+    let theta_old = old.position.angle_between(&Vec3::z_hat());
+    let blocked = |bird: &Bird| {
+        let theta_new = bird.position.angle_between(&Vec3::z_hat());
+        let (low, high) = (theta_old.min(theta_new), theta_old.max(theta_new));
+        forbidden_bands
+            .iter()
+            .any(|&(theta_min, theta_max)| low < theta_max && high > theta_min)
+    };
+
+    if !blocked(&moved) {
+        return moved;
+    }
+
+    // Reflect the velocity the bird was about to move with off the local parallel
+    let (e_theta, _) = old.tangent_basis();
+    let velocity = moved.parallel_transport_velocity(old);
+    let reflected = Bird {
+        velocity: velocity - e_theta * (2.0 * velocity.dot(&e_theta)),
+        ..*old
+    };
+
+    let retried = advance_on_sphere(particle_index, reflected, params);
+    if blocked(&retried) {
+        reflected
+    } else {
+        retried
+    }
+}
+
+/// Panics if a bird's species has no row in [`SpeciesAlignment`].
+fn check_species(birds: &[Bird]) {
+    if let Some((index, bird)) = birds
+        .iter()
+        .enumerate()
+        .find(|(_, bird)| bird.species as usize >= MAX_SPECIES)
+    {
+        panic!(
+            "Bird {} has species {}, but at most {} species are supported",
+            index, bird.species, MAX_SPECIES
+        )
+    }
+}
+
+/// Neighbor inclusion rule shared by the alignment step and [`Engine::neighbors`].
+///
+/// A bird at `geodesic_distance` is a neighbor if it lies strictly within the interaction
+/// radius (widened by the tolerance band) and not at the same position.
+fn is_neighbor(geodesic_distance: f64, params: &SimulationParams) -> bool {
+    geodesic_distance > COINCIDENT_DISTANCE
+        && geodesic_distance < params.interaction_radius + params.neighbor_tolerance
+}
+
+/// Moves `bird` along the sphere surface for one time step.
+///
+/// A bird that has drifted off the constraint surface is projected back (with a
+/// warning) instead of aborting the run; with `params.drift_correction` every result
+/// is renormalized as well.
+fn advance_on_sphere(particle_index: usize, bird: Bird, params: SimulationParams) -> Bird {
+    // Capped birds move at their own speed
+    let speed = match params.speed_control {
+        SpeedControl::Fixed => params.speed,
+        SpeedControl::Capped { .. } => bird.velocity.norm(),
+    };
+
+    let moved = match bird.try_move_on_sphere(params.dt, params.radius, speed) {
+        Ok(moved) => moved,
+        Err(message) => {
+            warn!(
+                "Bird {}: {}; projecting back onto the sphere",
+                particle_index, message
+            );
+            bird.project_onto_sphere(params.radius, speed)
+                .move_on_sphere(params.dt, params.radius, speed)
+        }
+    };
+
+    if params.drift_correction {
+        moved.project_onto_sphere(params.radius, speed)
+    } else {
+        moved
+    }
+}
+
+/// Turns a target velocity into the bird's noisy pre-repulsion velocity.
+///
+/// The target is rescaled (or capped) by [`control_speed`] and perturbed by noise. A
+/// near-zero target, e.g. from cancelling neighbors, would amplify rounding noise into an
+/// arbitrary heading, so `params.alignment_fallback` is applied instead.
+fn steer(target_velocity: Vec3, bird: &Bird, params: &SimulationParams) -> Vec3 {
+    if target_velocity.norm() < DEGENERATE_VELOCITY {
+        match params.alignment_fallback {
+            // Apply noise to current velocity when alignment is negligible
+            AlignmentFallback::KeepVelocity => Bird::add_noise(bird.velocity, bird, params.eta),
+            AlignmentFallback::RandomDirection => random_tangent_velocity(bird, params.speed),
+        }
+    } else {
+        // Normalize and scale to target speed (or cap it), then apply noise
+        Bird::add_noise(control_speed(target_velocity, params), bird, params.eta)
+    }
+}
+
+/// Applies the speed rule of `params.speed_control` to a proposed velocity.
+///
+/// [`SpeedControl::Fixed`] rescales to exactly `params.speed`; [`SpeedControl::Capped`]
+/// only shortens velocities faster than the cap.
+fn control_speed(velocity: Vec3, params: &SimulationParams) -> Vec3 {
+    match params.speed_control {
+        SpeedControl::Fixed => velocity.normalize() * params.speed,
+        SpeedControl::Capped { max } => {
+            let speed = velocity.norm();
+            if speed > max {
+                velocity * (max / speed)
+            } else {
+                velocity
+            }
+        }
+    }
+}
+
+/// Adds tangent-plane repulsion away from every source within `cutoff`.
+///
+/// Sources are predators (with `cutoff` the interaction radius) or crowding birds (with
+/// the repulsion radius). For a source of strength `s` at geodesic distance below
+/// `cutoff`, the vector `s · speed · â` is added to `velocity`, where `â` is the unit
+/// tangent at the bird pointing along the great circle away from the source. The result
+/// is projected back onto the tangent plane and rescaled to `speed` (or capped, see
+/// [`SpeedControl`]), keeping the speed constraint of [`Bird::move_on_sphere`].
+/// A source at (or exactly opposite to) the bird's position defines no direction and is
+/// ignored.
+fn repel(
+    velocity: Vec3,
+    bird: &Bird,
+    sources: &[(Vec3, f64)],
+    cutoff: f64,
+    params: SimulationParams,
+) -> Vec3 {
+    let normal = bird.position.normalize();
+
+    let repulsion = sources
+        .iter()
+        .filter(|(position, _)| normal.angle_between(position) * params.radius < cutoff)
+        .fold(Vec3::zero(), |accumulator, (position, strength)| {
+            let direction = position.normalize();
+            let toward = direction - normal * normal.dot(&direction);
+            accumulator - toward.normalize() * (*strength * params.speed)
+        });
+
+    let steered = velocity + repulsion;
+    let tangent = steered - normal * normal.dot(&steered);
+    if tangent.norm() > f64::EPSILON {
+        control_speed(tangent, &params)
+    } else {
+        velocity
+    }
 }
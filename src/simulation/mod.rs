@@ -2,11 +2,23 @@
 //!
 //! create!
 
+pub mod batch;
 pub mod io;
 pub mod logic;
+pub mod results;
+pub mod rules;
+pub mod sweep;
 pub mod tests;
 
+pub use batch::{BatchRun, BatchSpec, EnsembleSource, ensemble_average, run_batch};
+pub use rules::{InteractionRule, SoftRepulsion, VicsekAlignment};
+pub use sweep::{SweepParameter, sweep};
+
 use crate::bird::Bird;
+use crate::constants::PARAMETER_MATCH_TOLERANCE;
+use crate::ensemble::EntryResult;
+use crate::io::DataRoot;
+use crate::vector::Vec3;
 use log::debug;
 use std::sync::mpsc;
 
@@ -30,9 +42,293 @@ pub struct SimulationParams {
     /// Noise parameter controlling random perturbations in bird behavior.
     pub eta: f64,
     /// Maximum number of simulation steps to execute.
+    ///
+    /// Files written before the rename store this as `iterations`, which is still read.
+    #[serde(alias = "iterations")]
     pub total_iterations: usize,
     /// Interval controlling snapshot capture frequency.
+    ///
+    /// A snapshot is emitted every `frame_interval` steps. `0` means the engine never
+    /// emits frames; the saving entry points such as [`run`] reject it. Parameters
+    /// saved before the field existed recorded every step and load with `1`.
+    #[serde(default = "default_frame_interval")]
     pub frame_interval: usize,
+    /// Weight `s` of the neighbor average against the bird's own velocity.
+    ///
+    /// The pre-noise heading is `normalize((1 - s) · own + s · neighbor_avg)`; `s = 1`
+    /// is the standard Vicsek rule and `s = 0` ignores neighbors entirely, leaving a
+    /// noise-driven random walk (see [`crate::analysis::estimate_rotational_diffusion`]).
+    #[serde(default = "default_alignment_strength")]
+    pub alignment_strength: f64,
+    /// Distance weighting of neighbors in the alignment average.
+    #[serde(default)]
+    pub kernel: AlignmentKernel,
+    /// Renormalize positions and velocities after every step to stop numerical drift.
+    ///
+    /// Uses [`Bird::move_on_sphere_corrected`]; recommended for long runs.
+    #[serde(default)]
+    pub drift_correction: bool,
+    /// Linking distance for counting clusters in every emitted snapshot, if any.
+    ///
+    /// When set, [`SimulationSnapshot::cluster_count`] holds the number of clusters
+    /// found by [`crate::analysis::find_clusters`] with this geodesic cutoff.
+    #[serde(default)]
+    pub cluster_cutoff: Option<f64>,
+    /// Width of the band beyond `interaction_radius` within which neighbors still count.
+    ///
+    /// A bird aligns with every other bird at geodesic distance `d` with
+    /// `0 < d < interaction_radius + neighbor_tolerance`; a neighbor exactly at the
+    /// radius is excluded when the tolerance is zero. A small positive tolerance keeps
+    /// birds sitting on the boundary from flickering in and out of the neighborhood
+    /// through rounding.
+    #[serde(default)]
+    pub neighbor_tolerance: f64,
+    /// Keep every emitted snapshot in the result.
+    ///
+    /// When false, [`Engine::run`] emits no intermediate frames and the stored
+    /// [`SimulationResult`] holds only [`SimulationResult::final_state`], which saves
+    /// most of the disk space in large sweeps that only need the end configuration.
+    #[serde(default = "default_true")]
+    pub store_snapshots: bool,
+    /// Velocity rule when the blended alignment target (nearly) cancels out.
+    #[serde(default)]
+    pub alignment_fallback: AlignmentFallback,
+    /// Align with neighbors; when false, velocities change only through noise and
+    /// repulsion, which isolates steric effects in packing studies.
+    #[serde(default = "default_true")]
+    pub enable_alignment: bool,
+    /// Geodesic range of the bird-bird repulsion; zero disables it.
+    #[serde(default)]
+    pub repulsion_radius: f64,
+    /// Strength of the bird-bird repulsion, in units of `speed` per crowding neighbor.
+    ///
+    /// Every other bird closer than `repulsion_radius` pushes the velocity along the
+    /// great circle away from it, after alignment and noise; the result is rescaled to
+    /// `speed`. Zero disables the repulsion.
+    #[serde(default)]
+    pub repulsion_strength: f64,
+    /// How the step keeps bird speeds in check; [`SpeedControl::Fixed`] is the Vicsek rule.
+    #[serde(default)]
+    pub speed_control: SpeedControl,
+    /// How strongly each species aligns with each other species; see [`SpeciesAlignment`].
+    #[serde(default)]
+    pub species_alignment: SpeciesAlignment,
+    /// Number of initial steps during which no snapshots are emitted.
+    ///
+    /// The warm-up is part of `total_iterations`, and the step counter and simulation
+    /// time advance through it as usual, so the first frame is the first multiple of
+    /// `frame_interval` at or after `warmup_steps`. Skipping the transient saves disk
+    /// space and makes a later burn-in (e.g. in
+    /// [`SimulationResult::steady_state_order`]) unnecessary.
+    #[serde(default)]
+    pub warmup_steps: usize,
+}
+
+fn default_frame_interval() -> usize {
+    1
+}
+
+fn default_alignment_strength() -> f64 {
+    1.0
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Matches the defaults of the `simulate` command: 500 birds on the unit sphere at unit
+/// speed, `dt = 0.01`, `interaction_radius = 1`, `eta = 0.1`, 2000 steps with a snapshot
+/// every 10. Every other field takes the value older files load with, which gives the
+/// standard Vicsek model.
+impl Default for SimulationParams {
+    fn default() -> Self {
+        SimulationParams {
+            num_birds: 500,
+            radius: 1.0,
+            speed: 1.0,
+            dt: 0.01,
+            interaction_radius: 1.0,
+            eta: 0.1,
+            total_iterations: 2000,
+            frame_interval: 10,
+            alignment_strength: default_alignment_strength(),
+            kernel: AlignmentKernel::default(),
+            drift_correction: false,
+            cluster_cutoff: None,
+            neighbor_tolerance: 0.0,
+            store_snapshots: default_true(),
+            alignment_fallback: AlignmentFallback::default(),
+            enable_alignment: default_true(),
+            repulsion_radius: 0.0,
+            repulsion_strength: 0.0,
+            speed_control: SpeedControl::default(),
+            species_alignment: SpeciesAlignment::default(),
+            warmup_steps: 0,
+        }
+    }
+}
+
+impl SimulationParams {
+    /// Checks the parameters that the stored-run entry points cannot work without.
+    ///
+    /// # Errors
+    ///
+    /// Returns a descriptive message if `num_birds` is zero, if `frame_interval` is
+    /// zero, which would leave a saved run without a defined snapshot spacing, if the
+    /// warm-up outlasts `total_iterations`, if a speed cap is invalid, or if a species
+    /// coupling is negative or non-finite.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.num_birds < 1 {
+            return Err("num_birds must be at least 1".to_string());
+        }
+        if self.frame_interval < 1 {
+            return Err(
+                "frame_interval must be at least 1 (number of steps between snapshots)".to_string(),
+            );
+        }
+        if self.warmup_steps > self.total_iterations {
+            return Err(format!(
+                "warmup_steps ({}) must not exceed total_iterations ({})",
+                self.warmup_steps, self.total_iterations
+            ));
+        }
+        if let SpeedControl::Capped { max } = self.speed_control
+            && !(max.is_finite() && max > 0.0 && self.speed <= max)
+        {
+            return Err(format!(
+                "speed cap must be positive and at least speed ({}), got {}",
+                self.speed, max
+            ));
+        }
+        self.species_alignment.validate()?;
+        Ok(())
+    }
+}
+
+/// Weighting of neighbor velocities by geodesic distance `d` in the alignment average.
+///
+/// All kernels only apply within `interaction_radius` `R`; the averaged velocity is
+/// `Σ w(dⱼ) vⱼ / Σ w(dⱼ)`.
+#[derive(Debug, Default, Copy, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum AlignmentKernel {
+    /// `w = 1`: every neighbor counts equally (standard Vicsek model)
+    #[default]
+    Uniform,
+    /// `w = 1 - d / R`: weight decreases linearly to zero at the interaction radius
+    Linear,
+    /// `w = exp(-d² / (2σ²))`: Gaussian decay with width `sigma`
+    Gaussian { sigma: f64 },
+}
+
+impl AlignmentKernel {
+    /// Returns the weight of a neighbor at geodesic `distance` for the given `interaction_radius`.
+    pub fn weight(&self, distance: f64, interaction_radius: f64) -> f64 {
+        match *self {
+            AlignmentKernel::Uniform => 1.0,
+            AlignmentKernel::Linear => (1.0 - distance / interaction_radius).max(0.0),
+            AlignmentKernel::Gaussian { sigma } => {
+                (-distance * distance / (2.0 * sigma * sigma)).exp()
+            }
+        }
+    }
+}
+
+/// Number of species distinguished by [`SpeciesAlignment`]; [`Bird::species`] must be below it.
+pub const MAX_SPECIES: usize = 4;
+
+/// Alignment couplings between species.
+///
+/// Entry `[i][j]` scales how strongly a bird of species `i` aligns with a neighbor of
+/// species `j`. Couplings multiply the kernel weights of the alignment average, so the
+/// average becomes `Σ cᵢⱼ w(dⱼ) vⱼ / Σ cᵢⱼ w(dⱼ)`; a neighbor with zero coupling is
+/// ignored as if it were out of range. The matrix need not be symmetric.
+///
+/// The default couples every pair with `1`, which is the single-species model.
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::simulation::SpeciesAlignment;
+/// // Species 0 and 1 align only within their own group
+/// let alignment = SpeciesAlignment::independent();
+/// assert_eq!(alignment.coupling(0, 0), 1.0);
+/// assert_eq!(alignment.coupling(0, 1), 0.0);
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(transparent)]
+pub struct SpeciesAlignment(pub [[f64; MAX_SPECIES]; MAX_SPECIES]);
+
+impl Default for SpeciesAlignment {
+    fn default() -> Self {
+        SpeciesAlignment([[1.0; MAX_SPECIES]; MAX_SPECIES])
+    }
+}
+
+impl SpeciesAlignment {
+    /// Couples every species only to itself, so species do not interact.
+    pub fn independent() -> Self {
+        let mut matrix = [[0.0; MAX_SPECIES]; MAX_SPECIES];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[i] = 1.0;
+        }
+        SpeciesAlignment(matrix)
+    }
+
+    /// Returns how strongly species `from` aligns with species `to`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either species is not below [`MAX_SPECIES`].
+    pub fn coupling(&self, from: u8, to: u8) -> f64 {
+        self.0[from as usize][to as usize]
+    }
+
+    /// Checks that every coupling is finite and non-negative.
+    pub fn validate(&self) -> Result<(), String> {
+        for (i, row) in self.0.iter().enumerate() {
+            for (j, &coupling) in row.iter().enumerate() {
+                if !coupling.is_finite() || coupling < 0.0 {
+                    return Err(format!(
+                        "species coupling [{}][{}] must be finite and non-negative, got {}",
+                        i, j, coupling
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Speed rule applied whenever the step produces a new velocity.
+///
+/// Alignment, kernels, and repulsion all propose velocities whose magnitude differs
+/// from `speed`. Rigid-speed Vicsek dynamics rescale every proposal to exactly `speed`;
+/// capped-speed active-matter models instead let birds slow down (e.g. when neighbor
+/// headings cancel) and only limit the top speed.
+#[derive(Debug, Default, Copy, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum SpeedControl {
+    /// Every bird moves at exactly `speed` (standard Vicsek model)
+    #[default]
+    Fixed,
+    /// Birds keep the magnitude of their proposed velocity, shortened to at most `max`;
+    /// `speed` is then only the initial speed and must not exceed `max`
+    Capped { max: f64 },
+}
+
+/// Velocity update used when the alignment target has (almost) zero magnitude.
+///
+/// Opposing neighbor velocities, or a partial `alignment_strength` against an opposing
+/// neighbor average, can cancel to a vector shorter than
+/// [`DEGENERATE_VELOCITY`](crate::constants::DEGENERATE_VELOCITY) that has no usable
+/// direction. Published Vicsek variants break this tie differently, and the choice shifts
+/// the transition point, so it is configurable.
+#[derive(Debug, Default, Copy, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum AlignmentFallback {
+    /// Keep the current velocity and apply the usual angular noise
+    #[default]
+    KeepVelocity,
+    /// Pick a uniformly random tangent direction at the prescribed speed (no extra noise)
+    RandomDirection,
 }
 
 /// Simulation execution request containing initial conditions and configuration.
@@ -52,6 +348,83 @@ pub struct SimulationRequest {
     pub initial_values: Vec<Bird>,
     /// Complete physics and execution parameters for the simulation.
     pub params: SimulationParams,
+    /// Fixed repulsive stimuli as `(position, strength)` pairs; empty for the plain model.
+    ///
+    /// Only the direction of `position` matters. Birds within `interaction_radius`
+    /// (geodesic) of a predator are steered away from it by `strength · speed` before
+    /// their velocity is renormalized to the common speed.
+    pub predators: Vec<(Vec3, f64)>,
+    /// Indices of pinned "leader" birds; empty for the plain model.
+    ///
+    /// Leaders skip the alignment and noise update and keep moving along their
+    /// current great circle, while the other birds still count them as neighbors.
+    pub leaders: Vec<usize>,
+    /// Forbidden latitude bands as `(theta_min, theta_max)` polar angle pairs; empty for
+    /// the plain model.
+    ///
+    /// Polar angles are measured from the north pole (+Z), with
+    /// `0 ≤ theta_min < theta_max ≤ π`. A bird whose step would end inside a band, or
+    /// cross it, is reflected off the band edge: the meridional (`θ̂`) component of its
+    /// velocity is reversed, a specular reflection off the parallel, and the step is
+    /// retaken from the old position. If the reflected step is blocked as well, the
+    /// bird stays put with the reflected velocity. Positions and velocities stay on the
+    /// sphere and tangent to it. Pinned leaders are not deflected, and birds starting
+    /// inside a band are not pushed out.
+    pub forbidden_bands: Vec<(f64, f64)>,
+}
+
+impl SimulationRequest {
+    /// Builds a request that simulates a generated ensemble entry.
+    ///
+    /// The entry's birds become the initial values and its id is recorded as
+    /// `ensemble_entry_id`; no predators or leaders are set.
+    ///
+    /// # Errors
+    ///
+    /// Returns a descriptive message if `params.num_birds` differs from the number of
+    /// birds in the entry, or if `params.radius` / `params.speed` differ from the
+    /// values the entry was generated with beyond the relative
+    /// [`PARAMETER_MATCH_TOLERANCE`] (the integrator would reject the birds).
+    pub fn from_ensemble(
+        ensemble: EntryResult,
+        params: SimulationParams,
+        id: usize,
+        tag: usize,
+    ) -> Result<SimulationRequest, String> {
+        if params.num_birds != ensemble.birds.len() {
+            return Err(format!(
+                "params.num_birds is {} but ensemble entry {} holds {} birds",
+                params.num_birds,
+                ensemble.id,
+                ensemble.birds.len()
+            ));
+        }
+        let radius_tolerance = PARAMETER_MATCH_TOLERANCE * ensemble.params.radius;
+        let speed_tolerance = PARAMETER_MATCH_TOLERANCE * ensemble.params.speed;
+        if (params.radius - ensemble.params.radius).abs() > radius_tolerance
+            || (params.speed - ensemble.params.speed).abs() > speed_tolerance
+        {
+            return Err(format!(
+                "params radius/speed ({}, {}) do not match ensemble entry {} ({}, {})",
+                params.radius,
+                params.speed,
+                ensemble.id,
+                ensemble.params.radius,
+                ensemble.params.speed
+            ));
+        }
+
+        Ok(SimulationRequest {
+            id,
+            tag,
+            ensemble_entry_id: ensemble.id,
+            initial_values: ensemble.birds,
+            params,
+            predators: Vec::new(),
+            leaders: Vec::new(),
+            forbidden_bands: Vec::new(),
+        })
+    }
 }
 
 /// Temporal snapshot of simulation state for analysis and visualization.
@@ -68,8 +441,15 @@ pub struct SimulationSnapshot {
     pub timestamp: f64,
     /// Complete state vector of all birds at this temporal moment.
     pub birds: Vec<Bird>,
+    /// Number of clusters recorded during the run; `None` unless
+    /// [`SimulationParams::cluster_cutoff`] was set.
+    #[serde(default)]
+    pub cluster_count: Option<usize>,
 }
 
+/// Current schema version of stored [`SimulationResult`] files.
+pub const SIMULATION_SCHEMA_VERSION: u32 = 7;
+
 /// Complete simulation execution results with metadata.
 ///
 /// This comprehensive result structure contains all data necessary for post-simulation
@@ -78,6 +458,9 @@ pub struct SimulationSnapshot {
 /// studies and enables reproducible research workflows.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct SimulationResult {
+    /// On-disk layout version; [`SIMULATION_SCHEMA_VERSION`] for results built here.
+    /// Must stay the first field so loaders can check it before decoding the rest.
+    pub schema_version: u32,
     /// Unique identifier matching the original simulation request.
     pub id: usize,
     /// Descriptive tag inherited from the simulation request.
@@ -87,7 +470,87 @@ pub struct SimulationResult {
     /// Complete simulation configuration used for this execution.
     pub params: SimulationParams,
     /// Time-ordered sequence of simulation state snapshots.
+    ///
+    /// Empty when the run was made with [`SimulationParams::store_snapshots`] off.
     pub snapshots: Vec<SimulationSnapshot>,
+    /// Bird state after the last step when snapshots were not stored; empty otherwise,
+    /// in which case the last snapshot holds the latest recorded state.
+    pub final_state: Vec<Bird>,
+}
+
+impl SimulationResult {
+    /// Assembles a result from the frames emitted by [`Engine::run`].
+    ///
+    /// With [`SimulationParams::store_snapshots`] off the engine emits a single frame at
+    /// the end of the run, which becomes `final_state`.
+    pub(crate) fn from_frames(
+        id: usize,
+        tag: usize,
+        ensemble_entry_id: usize,
+        params: SimulationParams,
+        mut frames: Vec<SimulationSnapshot>,
+    ) -> Self {
+        let final_state = if params.store_snapshots {
+            Vec::new()
+        } else {
+            frames.pop().map(|frame| frame.birds).unwrap_or_default()
+        };
+
+        SimulationResult {
+            schema_version: SIMULATION_SCHEMA_VERSION,
+            id,
+            tag,
+            ensemble_entry_id,
+            params,
+            snapshots: frames,
+            final_state,
+        }
+    }
+}
+
+/// How [`Engine::run_until`] stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Termination {
+    /// The order parameter settled within the requested tolerance.
+    Converged,
+    /// The iteration budget was exhausted first.
+    MaxIterations,
+}
+
+/// Sending half of the engine's frame channel.
+///
+/// With an unbounded channel the engine never waits, but frames pile up in memory
+/// whenever the consumer (e.g. the I/O thread) is slower than the simulation. A bounded
+/// channel holds at most its capacity in flight and blocks the simulation loop until the
+/// consumer catches up, trading throughput for bounded memory. Frames are never
+/// dropped in either mode, so stored results stay complete; dropping frames would keep
+/// the simulation at full speed but leave gaps in the trajectory.
+pub enum FrameSender {
+    /// Unbounded `mpsc::channel`; sending never blocks.
+    Unbounded(mpsc::Sender<SimulationSnapshot>),
+    /// Bounded `mpsc::sync_channel`; sending blocks while the channel is full.
+    Bounded(mpsc::SyncSender<SimulationSnapshot>),
+}
+
+impl FrameSender {
+    fn send(&self, frame: SimulationSnapshot) -> Result<(), mpsc::SendError<SimulationSnapshot>> {
+        match self {
+            FrameSender::Unbounded(sender) => sender.send(frame),
+            FrameSender::Bounded(sender) => sender.send(frame),
+        }
+    }
+}
+
+impl From<mpsc::Sender<SimulationSnapshot>> for FrameSender {
+    fn from(sender: mpsc::Sender<SimulationSnapshot>) -> Self {
+        FrameSender::Unbounded(sender)
+    }
+}
+
+impl From<mpsc::SyncSender<SimulationSnapshot>> for FrameSender {
+    fn from(sender: mpsc::SyncSender<SimulationSnapshot>) -> Self {
+        FrameSender::Bounded(sender)
+    }
 }
 
 /// High-performance flocking simulation engine with parallel processing and memory optimization.
@@ -103,10 +566,83 @@ pub struct Engine {
     /// Continuous simulation time in physical units.
     current_timestamp: f64,
     /// Asynchronous channel for transmitting frame data to external consumers.
-    frame_sender: mpsc::Sender<SimulationSnapshot>,
+    frame_sender: FrameSender,
+    /// Fixed repulsive stimuli as `(position, strength)` pairs.
+    predators: Vec<(Vec3, f64)>,
+    /// Per-bird flag marking pinned leaders that skip the velocity update.
+    pinned: Vec<bool>,
+    /// Forbidden latitude bands as `(theta_min, theta_max)` pairs.
+    forbidden_bands: Vec<(f64, f64)>,
+    /// User-defined interaction rule replacing the built-in alignment, if any.
+    rule: Option<Box<dyn InteractionRule>>,
 }
 
-pub fn run(request: SimulationRequest) -> Result<(), String> {
+/// Runs a simulation entirely in memory and returns the collected result.
+///
+/// Snapshots are gathered from the engine's frame channel into a `Vec` on the calling
+/// thread once the run finishes; no I/O thread is spawned and nothing is written to
+/// disk. This is the preferred entry point for tests, embedding, and analysis
+/// pipelines that consume results directly.
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::bird::Bird;
+/// # use flocking_lib::simulation::{run_in_memory, SimulationParams, SimulationRequest};
+/// let birds = vec![
+///     Bird::from_spherical(1.0, 1.0, 0.0, 1.0, 0.0),
+///     Bird::from_spherical(1.0, 1.2, 0.3, 1.0, 0.5),
+/// ];
+/// let params = SimulationParams {
+///     num_birds: 2, radius: 1.0, speed: 1.0, dt: 0.01,
+///     interaction_radius: 0.5, eta: 0.1, total_iterations: 10, frame_interval: 5,
+///     ..Default::default()
+/// };
+/// let request = SimulationRequest {
+///     id: 0, tag: 0, ensemble_entry_id: 0, initial_values: birds, params, predators: vec![],
+///     leaders: vec![], forbidden_bands: vec![],
+/// };
+/// let result = run_in_memory(request);
+/// assert_eq!(result.snapshots.len(), 2);
+/// ```
+pub fn run_in_memory(request: SimulationRequest) -> SimulationResult {
+    debug!(
+        "Starting in-memory simulation run: id={}, tag={}, ensemble_entry_id={}",
+        request.id, request.tag, request.ensemble_entry_id
+    );
+
+    let (frame_tx, frame_rx) = mpsc::channel();
+    let (id, tag, ensemble_entry_id, params) = (
+        request.id,
+        request.tag,
+        request.ensemble_entry_id,
+        request.params,
+    );
+
+    let mut engine = Engine::new(request, frame_tx);
+    engine.run();
+    // Dropping the engine closes the channel so the receiver iterator terminates
+    drop(engine);
+
+    SimulationResult::from_frames(
+        id,
+        tag,
+        ensemble_entry_id,
+        params,
+        frame_rx.into_iter().collect(),
+    )
+}
+
+/// Runs a simulation and saves the result below `root` through a dedicated I/O thread.
+///
+/// A snapshot is stored every `request.params.frame_interval` steps.
+///
+/// # Errors
+///
+/// Returns an error if the parameters are rejected by [`SimulationParams::validate`]
+/// (e.g. a `frame_interval` of zero) or if the I/O thread fails.
+pub fn run(request: SimulationRequest, root: &DataRoot) -> Result<(), String> {
+    request.params.validate()?;
     debug!(
         "Starting simulation run: id={}, tag={}, ensemble_entry_id={}",
         request.id, request.tag, request.ensemble_entry_id
@@ -120,10 +656,13 @@ pub fn run(request: SimulationRequest) -> Result<(), String> {
         request.id,
         request.tag,
         request.ensemble_entry_id,
+        root.clone(),
     );
 
     let mut engine = Engine::new(request, frame_tx);
     engine.run();
+    // Dropping the engine closes the channel so the I/O thread can finalize
+    drop(engine);
 
     match io_handle.join() {
         Ok(Ok(())) => {
@@ -134,3 +673,83 @@ pub fn run(request: SimulationRequest) -> Result<(), String> {
         Err(_) => Err("I/O thread panicked".to_string()),
     }
 }
+
+/// Runs a simulation like [`run`], but with at most `capacity` frames in flight.
+///
+/// The frame channel is bounded, so the simulation blocks whenever the I/O thread falls
+/// `capacity` frames behind instead of buffering without limit. This back-pressure
+/// slows the simulation to the speed of the disk but never drops frames. A capacity of
+/// zero makes every frame a rendezvous with the I/O thread.
+pub fn run_bounded(
+    request: SimulationRequest,
+    root: &DataRoot,
+    capacity: usize,
+) -> Result<(), String> {
+    request.params.validate()?;
+    debug!(
+        "Starting bounded simulation run: id={}, tag={}, ensemble_entry_id={}, capacity={}",
+        request.id, request.tag, request.ensemble_entry_id, capacity
+    );
+
+    let (frame_tx, frame_rx) = mpsc::sync_channel(capacity);
+
+    let io_handle = io::start_receiver_thread(
+        frame_rx,
+        request.params,
+        request.id,
+        request.tag,
+        request.ensemble_entry_id,
+        root.clone(),
+    );
+
+    let mut engine = Engine::new(request, frame_tx);
+    engine.run();
+    // Dropping the engine closes the channel so the I/O thread can finalize
+    drop(engine);
+
+    match io_handle.join() {
+        Ok(Ok(())) => {
+            debug!("Bounded simulation completed successfully");
+            Ok(())
+        }
+        Ok(Err(e)) => Err(format!("I/O thread failed: {}", e)),
+        Err(_) => Err("I/O thread panicked".to_string()),
+    }
+}
+
+/// Runs a simulation like [`run`], but streams snapshots to disk as they are produced.
+///
+/// Only the run's metadata is kept in memory; the result is written to
+/// [`io::stream_path_for`] and can be read back with [`io::SnapshotStreamReader`].
+pub fn run_streaming(request: SimulationRequest, root: &DataRoot) -> Result<(), String> {
+    request.params.validate()?;
+    debug!(
+        "Starting streaming simulation run: id={}, tag={}, ensemble_entry_id={}",
+        request.id, request.tag, request.ensemble_entry_id
+    );
+
+    let (frame_tx, frame_rx) = mpsc::channel();
+
+    let io_handle = io::start_streaming_receiver_thread(
+        frame_rx,
+        request.params,
+        request.id,
+        request.tag,
+        request.ensemble_entry_id,
+        root.clone(),
+    );
+
+    let mut engine = Engine::new(request, frame_tx);
+    engine.run();
+    // Dropping the engine closes the channel so the I/O thread can finalize
+    drop(engine);
+
+    match io_handle.join() {
+        Ok(Ok(())) => {
+            debug!("Streaming simulation completed successfully");
+            Ok(())
+        }
+        Ok(Err(e)) => Err(format!("I/O thread failed: {}", e)),
+        Err(_) => Err("I/O thread panicked".to_string()),
+    }
+}
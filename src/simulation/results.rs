@@ -0,0 +1,168 @@
+//! Post-processing methods for stored snapshots and simulation results.
+//!
+
+use super::*;
+use crate::analysis;
+
+impl SimulationSnapshot {
+    /// Returns the polarization order parameter of this snapshot.
+    ///
+    /// See [`analysis::polarization`] for the definition.
+    pub fn order_parameter(&self) -> f64 {
+        analysis::polarization(&self.birds)
+    }
+}
+
+impl SimulationResult {
+    /// Returns the time-averaged order parameter and its standard error after a transient.
+    ///
+    /// Only snapshots whose `step` is at least `burn_in` contribute. The error is the
+    /// standard error of the mean treating snapshots as independent, so it is a lower
+    /// bound when consecutive frames are correlated; increase `frame_interval` or
+    /// thin the data with a larger stride to reduce correlation.
+    ///
+    /// # Returns
+    ///
+    /// `(mean, standard_error)`. The mean is `NaN` when no snapshot passes the
+    /// burn-in and the error is `NaN` when fewer than two do.
+    pub fn steady_state_order(&self, burn_in: usize) -> (f64, f64) {
+        let samples: Vec<f64> = self
+            .snapshots
+            .iter()
+            .filter(|snapshot| snapshot.step >= burn_in)
+            .map(SimulationSnapshot::order_parameter)
+            .collect();
+
+        analysis::mean_with_error(&samples)
+    }
+
+    /// Returns the timestamp of the first snapshot whose order parameter reaches `threshold`.
+    ///
+    /// The direction is taken from the first snapshot: if its order parameter lies below
+    /// `threshold`, the run is treated as ordering and the first snapshot with order
+    /// `>= threshold` is reported; if it lies above, the run is treated as disordering
+    /// (e.g. after a noise quench) and the first snapshot with order `<= threshold` is
+    /// reported. A first snapshot exactly at `threshold` is reported itself. Snapshots
+    /// are only sampled every `frame_interval` steps, so the crossing may have happened
+    /// up to one interval earlier.
+    ///
+    /// # Returns
+    ///
+    /// The timestamp of the crossing snapshot, or `None` if there are no snapshots or the
+    /// threshold is never reached.
+    pub fn time_to_order(&self, threshold: f64) -> Option<f64> {
+        let first = self.snapshots.first()?.order_parameter();
+        let rising = first <= threshold;
+
+        self.snapshots
+            .iter()
+            .find(|snapshot| {
+                let order = snapshot.order_parameter();
+                if rising {
+                    order >= threshold
+                } else {
+                    order <= threshold
+                }
+            })
+            .map(|snapshot| snapshot.timestamp)
+    }
+
+    /// Returns the susceptibility `χ = N (⟨φ²⟩ - ⟨φ⟩²)` of the run after a transient.
+    ///
+    /// The averages run over the order parameters of snapshots whose `step` is at least
+    /// `burn_in`, and `N` is `params.num_birds`. This is the time-averaged, single-run
+    /// estimate; it equals the ensemble susceptibility only if the run is ergodic and
+    /// long compared to its correlation time. Near the transition a finite flock can
+    /// linger in one state for a long time, so a short run underestimates `χ`, while
+    /// [`analysis::susceptibility`] over independent runs (which omits the factor `N`)
+    /// samples both states. Correlated frames do not bias the estimate but make it
+    /// noisier than their number suggests.
+    ///
+    /// Returns `NaN` when fewer than two snapshots pass the burn-in.
+    pub fn susceptibility(&self, burn_in: usize) -> f64 {
+        let samples: Vec<f64> = self
+            .snapshots
+            .iter()
+            .filter(|snapshot| snapshot.step >= burn_in)
+            .map(SimulationSnapshot::order_parameter)
+            .collect();
+
+        self.params.num_birds as f64 * analysis::susceptibility(&samples)
+    }
+
+    /// Returns a copy keeping every `stride`-th snapshot, always including the last one.
+    ///
+    /// Snapshots at indices `0, stride, 2·stride, …` are kept, followed by the final
+    /// snapshot if it is not already among them. Identifiers and parameters are
+    /// preserved, except that `params.frame_interval` is multiplied by `stride` to
+    /// describe the new spacing (the retained final frame may be closer to its
+    /// predecessor).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `stride` is zero.
+    pub fn downsample(&self, stride: usize) -> SimulationResult {
+        assert!(stride > 0, "downsample stride must be positive");
+
+        let mut snapshots: Vec<SimulationSnapshot> =
+            self.snapshots.iter().step_by(stride).cloned().collect();
+        if let Some(last) = self.snapshots.last()
+            && !(self.snapshots.len() - 1).is_multiple_of(stride)
+        {
+            snapshots.push(last.clone());
+        }
+
+        SimulationResult {
+            schema_version: SIMULATION_SCHEMA_VERSION,
+            id: self.id,
+            tag: self.tag,
+            ensemble_entry_id: self.ensemble_entry_id,
+            params: SimulationParams {
+                frame_interval: self.params.frame_interval * stride,
+                ..self.params
+            },
+            snapshots,
+            final_state: self.final_state.clone(),
+        }
+    }
+
+    /// Returns the total arc length traveled by each bird over the stored snapshots.
+    ///
+    /// For every bird, the geodesic distances ([`Bird::distance_from`] on a sphere of
+    /// `params.radius`) between its positions in consecutive snapshots are summed. Motion
+    /// between snapshots is not resolved, so turns within a frame interval shorten the
+    /// measured path; store frames densely for accurate lengths.
+    ///
+    /// # Returns
+    ///
+    /// One length per bird, in bird order; empty when there are no snapshots.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the snapshots hold different numbers of birds.
+    pub fn path_lengths(&self) -> Vec<f64> {
+        let Some(first) = self.snapshots.first() else {
+            return Vec::new();
+        };
+
+        let mut lengths = vec![0.0; first.birds.len()];
+        for pair in self.snapshots.windows(2) {
+            let (before, after) = (&pair[0], &pair[1]);
+            assert_eq!(
+                before.birds.len(),
+                after.birds.len(),
+                "snapshots at steps {} and {} hold different numbers of birds",
+                before.step,
+                after.step
+            );
+            for (length, (a, b)) in lengths
+                .iter_mut()
+                .zip(before.birds.iter().zip(&after.birds))
+            {
+                *length += a.distance_from(b, self.params.radius);
+            }
+        }
+
+        lengths
+    }
+}
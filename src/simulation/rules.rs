@@ -0,0 +1,105 @@
+//! User-defined interaction rules replacing the built-in alignment step.
+//!
+//! An [`InteractionRule`] installed with [`Engine::with_rule`](super::Engine::with_rule)
+//! computes the pre-noise velocity of every bird from its neighbors. Noise, bird and
+//! predator repulsion, speed control, and the integrator are applied afterwards exactly
+//! as for the built-in rule, so a new model only has to describe how neighbors steer.
+
+use crate::bird::Bird;
+use crate::constants::COINCIDENT_DISTANCE;
+use crate::vector::Vec3;
+
+/// Pairwise interaction between a bird and one of its neighbors.
+///
+/// For each bird, the engine sums [`InteractionRule::contribution`] over all neighbors
+/// (other birds closer than `interaction_radius + neighbor_tolerance`, as in
+/// [`Engine::neighbors`](super::Engine::neighbors)) and passes the total to
+/// [`InteractionRule::combine`] to obtain the target velocity. The target is then
+/// rescaled to the configured speed and perturbed by noise; a target shorter than
+/// [`DEGENERATE_VELOCITY`](crate::constants::DEGENERATE_VELOCITY) falls back to
+/// [`SimulationParams::alignment_fallback`](super::SimulationParams::alignment_fallback).
+///
+/// Rules are evaluated from the rayon worker threads, hence the `Send + Sync` bound.
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::bird::Bird;
+/// # use flocking_lib::simulation::InteractionRule;
+/// # use flocking_lib::vector::Vec3;
+/// /// Ignores all neighbors, so birds only diffuse through noise.
+/// struct Loner;
+///
+/// impl InteractionRule for Loner {
+///     fn contribution(&self, _: &Bird, _: &Bird, _: f64) -> Vec3 {
+///         Vec3::zero()
+///     }
+/// }
+/// ```
+pub trait InteractionRule: Send + Sync {
+    /// Returns the tangent-plane contribution of `neighbor` to `self_bird`'s new velocity.
+    ///
+    /// `distance` is the geodesic distance between the two birds. The result should be
+    /// tangent to the sphere at `self_bird`; use
+    /// [`Bird::parallel_transport_velocity`] to bring neighbor velocities there.
+    fn contribution(&self, self_bird: &Bird, neighbor: &Bird, distance: f64) -> Vec3;
+
+    /// Combines the bird's own velocity with the summed contributions of `count` neighbors.
+    ///
+    /// The default steers the own velocity by the contributions (`own + total`).
+    fn combine(&self, own: Vec3, total: Vec3, count: usize) -> Vec3 {
+        let _ = count;
+        own + total
+    }
+}
+
+/// Standard Vicsek alignment: the new heading is the mean transported neighbor velocity.
+///
+/// Matches the built-in step with [`AlignmentKernel::Uniform`](super::AlignmentKernel),
+/// `alignment_strength = 1`, and a single species, except that an isolated bird is
+/// still perturbed by noise.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct VicsekAlignment;
+
+impl InteractionRule for VicsekAlignment {
+    fn contribution(&self, self_bird: &Bird, neighbor: &Bird, _distance: f64) -> Vec3 {
+        neighbor.parallel_transport_velocity(self_bird)
+    }
+
+    fn combine(&self, own: Vec3, total: Vec3, count: usize) -> Vec3 {
+        if count == 0 {
+            own
+        } else {
+            total / count as f64
+        }
+    }
+}
+
+/// Steering away from neighbors closer than `radius`, without alignment.
+///
+/// Each neighbor at geodesic distance below `radius` adds `strength · |v| · â`, where
+/// `â` is the unit tangent pointing along the great circle away from it and `|v|` the
+/// bird's own speed; this is the push of the built-in bird repulsion
+/// ([`SimulationParams::repulsion_strength`](super::SimulationParams::repulsion_strength)).
+/// Only neighbors within the interaction radius are visited, so `radius` should not
+/// exceed it. Coincident and antipodal neighbors define no direction and are ignored.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct SoftRepulsion {
+    /// Geodesic range of the repulsion.
+    pub radius: f64,
+    /// Push per neighbor, in units of the bird's speed.
+    pub strength: f64,
+}
+
+impl InteractionRule for SoftRepulsion {
+    fn contribution(&self, self_bird: &Bird, neighbor: &Bird, distance: f64) -> Vec3 {
+        if distance <= COINCIDENT_DISTANCE || distance >= self.radius {
+            return Vec3::zero();
+        }
+
+        let normal = self_bird.position.normalize();
+        let direction = neighbor.position.normalize();
+        let toward = direction - normal * normal.dot(&direction);
+        -toward.normalize() * (self.strength * self_bird.velocity.norm())
+    }
+}
@@ -0,0 +1,87 @@
+//! Parameter sweeps running one simulation per value of a single parameter.
+//!
+
+use super::*;
+use rayon::prelude::*;
+
+/// Simulation parameter varied by [`sweep`].
+///
+/// Only parameters that can change without invalidating the initial conditions are
+/// offered; `radius` and `speed` are fixed by the ensemble the birds come from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub enum SweepParameter {
+    /// Noise strength `eta`
+    Eta,
+    /// Alignment neighborhood `interaction_radius`
+    InteractionRadius,
+    /// Integration time step `dt`
+    Dt,
+}
+
+impl SweepParameter {
+    /// Returns a copy of `params` with the selected field set to `value`.
+    pub fn apply(&self, params: SimulationParams, value: f64) -> SimulationParams {
+        match self {
+            SweepParameter::Eta => SimulationParams {
+                eta: value,
+                ..params
+            },
+            SweepParameter::InteractionRadius => SimulationParams {
+                interaction_radius: value,
+                ..params
+            },
+            SweepParameter::Dt => SimulationParams {
+                dt: value,
+                ..params
+            },
+        }
+    }
+}
+
+/// Runs `base` once for every value of `parameter` and returns the results in input order.
+///
+/// Every run starts from the same initial conditions and predators as `base`; only the
+/// selected parameter changes. Runs are distributed over rayon's thread pool and kept in
+/// memory via [`run_in_memory`]. The `k`-th result carries id `base.id + k` so results
+/// can be saved side by side under the base tag.
+///
+/// # Examples
+///
+/// ```rust
+/// # use flocking_lib::bird::Bird;
+/// # use flocking_lib::simulation::{SimulationParams, SimulationRequest};
+/// # use flocking_lib::simulation::sweep::{sweep, SweepParameter};
+/// let birds = vec![
+///     Bird::from_spherical(1.0, 1.0, 0.0, 1.0, 0.0),
+///     Bird::from_spherical(1.0, 1.2, 0.3, 1.0, 0.5),
+/// ];
+/// let params = SimulationParams {
+///     num_birds: 2, radius: 1.0, speed: 1.0, dt: 0.01,
+///     interaction_radius: 0.5, eta: 0.1, total_iterations: 10, frame_interval: 5,
+///     ..Default::default()
+/// };
+/// let base = SimulationRequest {
+///     id: 0, tag: 0, ensemble_entry_id: 0, initial_values: birds, params, predators: vec![],
+///     leaders: vec![], forbidden_bands: vec![],
+/// };
+/// let results = sweep(&base, SweepParameter::Eta, &[0.1, 0.5]);
+/// assert_eq!(results[1].1.params.eta, 0.5);
+/// ```
+pub fn sweep(
+    base: &SimulationRequest,
+    parameter: SweepParameter,
+    values: &[f64],
+) -> Vec<(f64, SimulationResult)> {
+    values
+        .par_iter()
+        .enumerate()
+        .map(|(k, &value)| {
+            let request = SimulationRequest {
+                id: base.id + k,
+                params: parameter.apply(base.params, value),
+                ..base.clone()
+            };
+            (value, run_in_memory(request))
+        })
+        .collect()
+}
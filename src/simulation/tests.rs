@@ -1,5 +1,1713 @@
 //! Unit tests for the `Simulation` module.
 #[cfg(test)]
 mod units {
-    // refer to git for tests
+    use crate::analysis;
+    use crate::bird::Bird;
+    use crate::ensemble::{self, EntryGenerationParams, InitialDistribution, VelocityInit};
+    use crate::io::{DataPersistence, DataRoot, bin};
+    use crate::simulation::{
+        self, AlignmentFallback, AlignmentKernel, BatchSpec, Engine, SIMULATION_SCHEMA_VERSION,
+        SimulationParams, SimulationRequest, SimulationResult, SimulationSnapshot, SpeedControl,
+        Termination, io, run_batch, run_in_memory, run_streaming,
+    };
+    use crate::vector::Vec3;
+    use std::f64::consts::PI;
+    use std::sync::mpsc;
+
+    // =========================================================================
+    // HELPER FUNCTIONS AND TEST UTILITIES
+    // =========================================================================
+
+    /// Creates small-flock parameters suitable for fast tests
+    fn test_params(num_birds: usize) -> SimulationParams {
+        SimulationParams {
+            num_birds,
+            radius: 1.0,
+            speed: 1.0,
+            dt: 0.01,
+            interaction_radius: 0.5,
+            eta: 0.1,
+            total_iterations: 10,
+            frame_interval: 2,
+            ..Default::default()
+        }
+    }
+
+    /// Places `n` birds on a ring of constant polar angle with spread headings
+    fn ring_birds(n: usize, radius: f64, speed: f64) -> Vec<Bird> {
+        (0..n)
+            .map(|i| {
+                let phi = 2.0 * PI * i as f64 / n as f64;
+                Bird::from_spherical(radius, PI / 3.0, phi, speed, 0.3 * i as f64)
+            })
+            .collect()
+    }
+
+    /// Draws `n` birds uniformly on the unit sphere with random headings and unit speed
+    fn random_birds(n: usize, seed: u64) -> Vec<Bird> {
+        let params = EntryGenerationParams {
+            num_birds: n,
+            radius: 1.0,
+            speed: 1.0,
+            min_distance: 0.05,
+            distribution: InitialDistribution::Uniform,
+            velocity_init: VelocityInit::Random,
+        };
+        ensemble::generate_seeded_entry(0, 0, params, seed).birds
+    }
+
+    /// Wraps birds and parameters into a request with fixed metadata
+    fn test_request(birds: Vec<Bird>, params: SimulationParams) -> SimulationRequest {
+        SimulationRequest {
+            id: 3,
+            tag: 7,
+            ensemble_entry_id: 11,
+            initial_values: birds,
+            params,
+            predators: Vec::new(),
+            leaders: Vec::new(),
+            forbidden_bands: Vec::new(),
+        }
+    }
+
+    /// Builds a snapshot whose polarization equals `order` exactly.
+    ///
+    /// Two unit-speed birds whose velocities are tilted by `±acos(order)` from
+    /// the same axis, so the normalized sum has length `order`.
+    fn snapshot_with_order(step: usize, order: f64) -> SimulationSnapshot {
+        let half_angle = order.clamp(0.0, 1.0).acos();
+        let (s, c) = half_angle.sin_cos();
+        SimulationSnapshot {
+            step,
+            timestamp: step as f64 * 0.01,
+            birds: vec![
+                Bird {
+                    position: Vec3::x_hat(),
+                    velocity: Vec3::new(0.0, c, s),
+                    species: 0,
+                },
+                Bird {
+                    position: Vec3::x_hat(),
+                    velocity: Vec3::new(0.0, c, -s),
+                    species: 0,
+                },
+            ],
+            cluster_count: None,
+        }
+    }
+
+    /// Builds a synthetic result from a sequence of per-snapshot order parameters.
+    fn result_with_orders(orders: &[f64], frame_interval: usize) -> SimulationResult {
+        let mut params = test_params(2);
+        params.frame_interval = frame_interval;
+        params.total_iterations = orders.len() * frame_interval;
+        SimulationResult {
+            schema_version: SIMULATION_SCHEMA_VERSION,
+            id: 0,
+            tag: 0,
+            ensemble_entry_id: 0,
+            params,
+            snapshots: orders
+                .iter()
+                .enumerate()
+                .map(|(i, &order)| snapshot_with_order((i + 1) * frame_interval, order))
+                .collect(),
+            final_state: Vec::new(),
+        }
+    }
+
+    // =========================================================================
+    // TESTS
+    // =========================================================================
+
+    /// Tests `run_in_memory()` on a tiny flock.
+    ///
+    /// Validates that:
+    /// - Metadata is carried over from the request
+    /// - One snapshot is recorded per frame interval, in step order
+    /// - Birds stay on the sphere with the configured speed
+    #[test]
+    fn run_in_memory_tiny_flock() {
+        let params = test_params(5);
+        let result = run_in_memory(test_request(ring_birds(5, 1.0, 1.0), params));
+
+        assert_eq!(result.id, 3);
+        assert_eq!(result.tag, 7);
+        assert_eq!(result.ensemble_entry_id, 11);
+        assert_eq!(result.params.num_birds, 5);
+        assert_eq!(result.snapshots.len(), 5);
+
+        for (i, snapshot) in result.snapshots.iter().enumerate() {
+            assert_eq!(snapshot.step, (i + 1) * params.frame_interval);
+            assert!((snapshot.timestamp - snapshot.step as f64 * params.dt).abs() < 1e-12);
+            assert_eq!(snapshot.birds.len(), 5);
+            for bird in &snapshot.birds {
+                assert!((bird.position.norm() - params.radius).abs() < 1e-10);
+                assert!((bird.velocity.norm() - params.speed).abs() < 1e-10);
+            }
+        }
+    }
+
+    /// Tests `export_to_ndjson()` on a stored result.
+    ///
+    /// Validates that:
+    /// - One line is written per snapshot
+    /// - Every line is a standalone JSON object with step, timestamp, and birds
+    #[test]
+    fn export_to_ndjson_one_line_per_snapshot() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = DataRoot::new(temp_dir.path());
+        let result = run_in_memory(test_request(ring_birds(4, 1.0, 1.0), test_params(4)));
+        bin::save_file(&result, &root).unwrap();
+
+        let output = temp_dir.path().join("export").join("frames.ndjson");
+        io::export_to_ndjson(&root, result.tag, result.id, &output).unwrap();
+
+        let contents = std::fs::read_to_string(&output).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), result.snapshots.len());
+
+        for (line, snapshot) in lines.iter().zip(&result.snapshots) {
+            let value: serde_json::Value = serde_json::from_str(line).unwrap();
+            assert_eq!(value["step"].as_u64().unwrap() as usize, snapshot.step);
+            assert!((value["timestamp"].as_f64().unwrap() - snapshot.timestamp).abs() < 1e-12);
+            assert_eq!(value["birds"].as_array().unwrap().len(), 4);
+        }
+    }
+
+    /// Tests `export_interaction_graph()` on a stored result.
+    ///
+    /// Validates that:
+    /// - The edge list matches `analysis::interaction_graph` of the chosen snapshot
+    /// - A step without a stored snapshot is an error
+    #[test]
+    fn export_interaction_graph_edge_list() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = DataRoot::new(temp_dir.path());
+        let mut params = test_params(6);
+        params.interaction_radius = 1.1;
+        let result = run_in_memory(test_request(ring_birds(6, 1.0, 1.0), params));
+        bin::save_file(&result, &root).unwrap();
+
+        let snapshot = &result.snapshots[1];
+        let output = temp_dir.path().join("graphs").join("edges.txt");
+        io::export_interaction_graph(&root, result.tag, result.id, snapshot.step, &output).unwrap();
+
+        let contents = std::fs::read_to_string(&output).unwrap();
+        let mut lines = contents.lines();
+        assert_eq!(
+            lines.next().unwrap(),
+            format!("# step {} birds 6", snapshot.step)
+        );
+        let edges: Vec<(usize, usize)> = lines
+            .map(|line| {
+                let (i, j) = line.split_once(' ').unwrap();
+                (i.parse().unwrap(), j.parse().unwrap())
+            })
+            .collect();
+        assert!(!edges.is_empty());
+        assert_eq!(
+            edges,
+            analysis::interaction_graph(&snapshot.birds, 1.0, params.interaction_radius)
+        );
+
+        assert!(io::export_interaction_graph(&root, result.tag, result.id, 1, &output).is_err());
+    }
+
+    /// Tests `analysis::heading_histogram()` and `export_heading_histogram()`.
+    ///
+    /// Validates that:
+    /// - Birds sharing one heading land in a single bin
+    /// - The CSV lists every bin center with its count
+    /// - Zero bins and a missing step are errors
+    #[test]
+    fn export_heading_histogram_csv() {
+        let birds: Vec<Bird> = (0..5)
+            .map(|i| Bird::from_spherical(1.0, 0.6 + 0.3 * i as f64, 0.9 * i as f64, 1.0, 2.0))
+            .collect();
+        // 2 rad falls into bin 2 of 8 (width π/4)
+        assert_eq!(
+            analysis::heading_histogram(&birds, 8),
+            vec![0, 0, 5, 0, 0, 0, 0, 0]
+        );
+
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = DataRoot::new(temp_dir.path());
+        let mut params = test_params(5);
+        params.interaction_radius = 1e-3;
+        params.eta = 1e-12;
+        let result = run_in_memory(test_request(birds, params));
+        bin::save_file(&result, &root).unwrap();
+
+        let snapshot = &result.snapshots[0];
+        let output = temp_dir.path().join("plots").join("headings.csv");
+        io::export_heading_histogram(&root, result.tag, result.id, snapshot.step, 4, &output)
+            .unwrap();
+
+        let contents = std::fs::read_to_string(&output).unwrap();
+        let mut lines = contents.lines();
+        assert_eq!(lines.next().unwrap(), "bin_center,count");
+        let rows: Vec<(f64, usize)> = lines
+            .map(|line| {
+                let (center, count) = line.split_once(',').unwrap();
+                (center.parse().unwrap(), count.parse().unwrap())
+            })
+            .collect();
+        assert_eq!(rows.len(), 4);
+        for (k, (center, count)) in rows.iter().enumerate() {
+            assert!((center - (k as f64 + 0.5) * PI / 2.0).abs() < 1e-12);
+            assert_eq!(*count, analysis::heading_histogram(&snapshot.birds, 4)[k]);
+        }
+        assert_eq!(rows.iter().map(|(_, count)| count).sum::<usize>(), 5);
+
+        assert!(io::export_heading_histogram(&root, result.tag, result.id, 0, 0, &output).is_err());
+        assert!(io::export_heading_histogram(&root, result.tag, result.id, 1, 4, &output).is_err());
+    }
+
+    /// Tests that parameters written by older versions still deserialize.
+    ///
+    /// Validates that:
+    /// - The legacy `iterations` name maps to `total_iterations`
+    /// - Missing `frame_interval` and later additions take their defaults
+    #[test]
+    fn legacy_params_deserialize() {
+        let legacy = r#"{
+            "num_birds": 20,
+            "radius": 1.0,
+            "speed": 0.5,
+            "dt": 0.01,
+            "interaction_radius": 0.3,
+            "eta": 0.2,
+            "iterations": 400
+        }"#;
+        let params: SimulationParams = serde_json::from_str(legacy).unwrap();
+        assert_eq!(params.total_iterations, 400);
+        assert_eq!(params.frame_interval, 1);
+        assert_eq!(params.alignment_strength, 1.0);
+        assert!(params.store_snapshots && params.enable_alignment);
+        assert_eq!(params.speed_control, SpeedControl::Fixed);
+        assert!(params.validate().is_ok());
+
+        // Current files keep round-tripping under the current names
+        let current: SimulationParams =
+            serde_json::from_str(&serde_json::to_string(&params).unwrap()).unwrap();
+        assert_eq!(current.total_iterations, 400);
+
+        // The same holds for TOML parameter files
+        let toml_params: SimulationParams = toml::from_str(
+            "num_birds = 20\nradius = 1.0\nspeed = 0.5\ndt = 0.01\n\
+             interaction_radius = 0.3\neta = 0.2\niterations = 400\n",
+        )
+        .unwrap();
+        assert_eq!(toml_params.total_iterations, 400);
+    }
+
+    /// Tests `export_to_json()` on a stored result.
+    ///
+    /// Validates that:
+    /// - The document has metadata, parameters, and snapshots sections
+    /// - Every stored snapshot is exported with its birds' positions and velocities
+    #[test]
+    fn export_to_json_structure() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = DataRoot::new(temp_dir.path());
+        let result = run_in_memory(test_request(ring_birds(4, 1.0, 1.0), test_params(4)));
+        bin::save_file(&result, &root).unwrap();
+
+        let output = temp_dir.path().join("export").join("result.json");
+        io::export_to_json(&root, result.tag, result.id, &output).unwrap();
+
+        let value: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&output).unwrap()).unwrap();
+
+        assert_eq!(
+            value["metadata"]["id"].as_u64().unwrap() as usize,
+            result.id
+        );
+        assert_eq!(value["metadata"]["num_birds"].as_u64().unwrap(), 4);
+        assert_eq!(
+            value["metadata"]["num_snapshots"].as_u64().unwrap() as usize,
+            result.snapshots.len()
+        );
+        assert_eq!(
+            value["parameters"]["eta"].as_f64().unwrap(),
+            result.params.eta
+        );
+
+        let snapshots = value["snapshots"].as_array().unwrap();
+        assert_eq!(snapshots.len(), result.snapshots.len());
+        for (exported, snapshot) in snapshots.iter().zip(&result.snapshots) {
+            assert_eq!(exported["step"].as_u64().unwrap() as usize, snapshot.step);
+            let birds = exported["birds"].as_array().unwrap();
+            assert_eq!(birds.len(), 4);
+            assert_eq!(
+                birds[0]["position"]["x"].as_f64().unwrap(),
+                snapshot.birds[0].position.x
+            );
+            assert!(birds[0]["velocity"]["z"].is_number());
+        }
+    }
+
+    /// Tests `run_streaming()` against the in-memory path.
+    ///
+    /// Validates that:
+    /// - The streamed header carries the run metadata and snapshot count
+    /// - Streamed snapshots equal those of `run_in_memory` for a deterministic run
+    #[test]
+    fn streamed_result_matches_in_memory() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = DataRoot::new(temp_dir.path());
+
+        // Isolated birds (tiny interaction radius) evolve deterministically without noise
+        let mut params = test_params(5);
+        params.interaction_radius = 1e-3;
+        let request = test_request(ring_birds(5, 1.0, 1.0), params);
+
+        let expected = run_in_memory(request.clone());
+        run_streaming(request, &root).unwrap();
+
+        let path = io::stream_path_for(&root, expected.tag, expected.id);
+        let reader = io::SnapshotStreamReader::open(&path).unwrap();
+        assert_eq!(reader.header().num_snapshots, expected.snapshots.len());
+        assert_eq!(
+            reader.header().ensemble_entry_id,
+            expected.ensemble_entry_id
+        );
+
+        let streamed = reader.into_result().unwrap();
+        assert_eq!(streamed.id, expected.id);
+        assert_eq!(streamed.tag, expected.tag);
+        assert_eq!(streamed.snapshots.len(), expected.snapshots.len());
+        for (a, b) in streamed.snapshots.iter().zip(&expected.snapshots) {
+            assert_eq!(a.step, b.step);
+            assert_eq!(a.timestamp, b.timestamp);
+            for (x, y) in a.birds.iter().zip(&b.birds) {
+                assert_eq!(x, y);
+            }
+        }
+    }
+
+    /// Tests `SnapshotStreamReader` on damaged stream files.
+    ///
+    /// Validates that:
+    /// - Files too short for a trailer or with an oversized header length fail to open
+    /// - An oversized record length fails that record instead of allocating it
+    /// - A stream cut short of its header's snapshot count ends with an error
+    #[test]
+    fn stream_reader_rejects_damaged_files() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = DataRoot::new(temp_dir.path());
+        let request = test_request(ring_birds(4, 1.0, 1.0), test_params(4));
+        run_streaming(request.clone(), &root).unwrap();
+        let stream = std::fs::read(io::stream_path_for(&root, request.tag, request.id)).unwrap();
+
+        let open = |bytes: &[u8]| {
+            let path = temp_dir.path().join("damaged.stream");
+            std::fs::write(&path, bytes).unwrap();
+            io::SnapshotStreamReader::open(&path)
+        };
+        assert!(open(&stream).is_ok());
+        assert!(open(b"abc").is_err());
+        assert!(open(&u64::MAX.to_le_bytes()).is_err());
+        assert!(open(&[stream.as_slice(), &u64::MAX.to_le_bytes()].concat()).is_err());
+
+        let mut huge_record = stream.clone();
+        huge_record[..8].copy_from_slice(&(u64::MAX / 2).to_le_bytes());
+        let mut reader = open(&huge_record).unwrap();
+        let err = reader.next().unwrap().unwrap_err().to_string();
+        assert!(err.contains("exceeds"), "{err}");
+
+        // Drop the last record but keep the header claiming it
+        let header_length =
+            u64::from_le_bytes(stream[stream.len() - 8..].try_into().unwrap()) as usize;
+        let records_end = stream.len() - 8 - header_length;
+        let first_length = u64::from_le_bytes(stream[..8].try_into().unwrap()) as usize;
+        let cut = [&stream[..8 + first_length], &stream[records_end..]].concat();
+        let results: Vec<_> = open(&cut).unwrap().collect();
+        assert!(results.len() > 1);
+        assert!(results[0].is_ok());
+        assert!(results[1..].iter().any(|record| record.is_err()));
+    }
+
+    /// Tests `BatchSpec::load()` and `run_batch()` on a two-member TOML spec.
+    ///
+    /// Validates that:
+    /// - Stored and generated (seeded) sources are parsed from TOML
+    /// - Both runs execute and save their results
+    /// - A generated source reproduces the entry of its seed
+    #[test]
+    fn batch_spec_runs_stored_and_generated_members() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = DataRoot::new(temp_dir.path());
+
+        let generation = EntryGenerationParams {
+            num_birds: 6,
+            radius: 1.0,
+            speed: 1.0,
+            min_distance: 0.05,
+            distribution: InitialDistribution::Uniform,
+            velocity_init: VelocityInit::Random,
+        };
+        let stored = ensemble::generate_parallel(4, 1, generation, Some(1), None).unwrap();
+        bin::save_file(&stored[0], &root).unwrap();
+
+        let params = "{ num_birds = 6, radius = 1.0, speed = 1.0, dt = 0.01, \
+            interaction_radius = 0.5, eta = 0.1, total_iterations = 20, frame_interval = 5 }";
+        let spec = format!(
+            r#"
+parallel = true
+
+[[runs]]
+tag = 8
+id = 0
+source = {{ kind = "stored", tag = 4, id = 0 }}
+params = {params}
+
+[[runs]]
+tag = 8
+id = 1
+source = {{ kind = "generated", seed = 7, params = {{ num_birds = 6, radius = 1.0, speed = 1.0, min_distance = 0.05 }} }}
+params = {params}
+"#
+        );
+        let spec_path = temp_dir.path().join("batch.toml");
+        std::fs::write(&spec_path, spec).unwrap();
+
+        let spec = BatchSpec::load(&spec_path).unwrap();
+        assert!(spec.parallel);
+        assert_eq!(spec.runs.len(), 2);
+        run_batch(&spec, &root).unwrap();
+
+        for id in 0..2 {
+            let path = SimulationResult::binary_path_for(&root, 8, id);
+            let result: SimulationResult = bin::load_file(&path).unwrap();
+            assert_eq!(result.snapshots.len(), 4);
+        }
+
+        let request = spec.runs[1].to_request(&root).unwrap();
+        let expected = ensemble::generate_parallel(8, 1, generation, Some(7), None).unwrap();
+        assert_eq!(
+            request.initial_values[0].position,
+            expected[0].birds[0].position
+        );
+    }
+
+    /// Tests a bounded frame channel with a deliberately slow consumer.
+    ///
+    /// Validates that:
+    /// - The number of frames in flight never exceeds the channel capacity
+    /// - Every frame still reaches the consumer
+    #[test]
+    fn bounded_channel_limits_frames_in_flight() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let capacity = 2;
+        let mut params = test_params(4);
+        params.frame_interval = 1;
+        let (tx, rx) = mpsc::sync_channel(capacity);
+        let mut engine = Engine::new(test_request(ring_birds(4, 1.0, 1.0), params), tx);
+
+        let sent = Arc::new(AtomicUsize::new(0));
+        let consumer = {
+            let sent = Arc::clone(&sent);
+            std::thread::spawn(move || {
+                let mut received = 0;
+                let mut max_in_flight = 0;
+                for _ in rx {
+                    received += 1;
+                    let in_flight = sent.load(Ordering::SeqCst).saturating_sub(received);
+                    max_in_flight = max_in_flight.max(in_flight);
+                    std::thread::sleep(std::time::Duration::from_millis(2));
+                }
+                (received, max_in_flight)
+            })
+        };
+
+        for _ in 0..30 {
+            engine.step_many(1);
+            sent.fetch_add(1, Ordering::SeqCst);
+        }
+        drop(engine);
+
+        let (received, max_in_flight) = consumer.join().unwrap();
+        assert_eq!(received, 30);
+        assert!(max_in_flight <= capacity);
+    }
+
+    /// Tests cluster counting during a run via `SimulationParams::cluster_cutoff`.
+    ///
+    /// Validates that:
+    /// - Every snapshot records the number of clusters of its birds
+    /// - Counts match a post-hoc `find_clusters` with the same cutoff
+    /// - Without a cutoff no counts are recorded
+    #[test]
+    fn recorded_cluster_counts_match_post_hoc() {
+        // Two well-separated groups on opposite sides of the sphere
+        let birds: Vec<Bird> = (0..6)
+            .map(|i| {
+                let theta = if i < 3 { 0.5 } else { 2.6 };
+                Bird::from_spherical(1.0, theta + 0.03 * i as f64, 0.1 * i as f64, 1.0, i as f64)
+            })
+            .collect();
+        let mut params = test_params(6);
+        params.total_iterations = 40;
+        params.frame_interval = 5;
+        params.cluster_cutoff = Some(0.4);
+
+        let result = run_in_memory(test_request(birds.clone(), params));
+        assert_eq!(result.snapshots.len(), 8);
+        for snapshot in &result.snapshots {
+            let expected = crate::analysis::find_clusters(&snapshot.birds, 1.0, 0.4).len();
+            assert_eq!(snapshot.cluster_count, Some(expected));
+        }
+
+        params.cluster_cutoff = None;
+        let result = run_in_memory(test_request(birds, params));
+        assert!(result.snapshots.iter().all(|s| s.cluster_count.is_none()));
+    }
+
+    /// Tests that the saving entry points reject a zero `frame_interval`.
+    ///
+    /// Validates that:
+    /// - `run`, `run_bounded`, and `run_streaming` return a descriptive error
+    /// - Nothing is written
+    /// - The default parameters pass validation
+    #[test]
+    fn run_rejects_zero_frame_interval() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let root = DataRoot::new(temp_dir.path());
+        let mut params = test_params(3);
+        params.frame_interval = 0;
+        let request = test_request(ring_birds(3, 1.0, 1.0), params);
+
+        let error = simulation::run(request.clone(), &root).unwrap_err();
+        assert!(error.contains("frame_interval"));
+        assert!(simulation::run_bounded(request.clone(), &root, 4).is_err());
+        assert!(run_streaming(request, &root).is_err());
+
+        assert!(!SimulationResult::binary_path_for(&root, 7, 3).exists());
+        assert!(test_params(3).validate().is_ok());
+        assert!(SimulationParams::default().validate().is_ok());
+    }
+
+    /// Tests an engine with `frame_interval = 0`.
+    ///
+    /// Validates that:
+    /// - Running does not panic and emits no frames
+    /// - The simulation still advances
+    /// - `run_until` measures every step instead of looping forever
+    #[test]
+    fn zero_frame_interval_emits_no_frames() {
+        let mut params = test_params(4);
+        params.frame_interval = 0;
+        params.total_iterations = 25;
+
+        let result = run_in_memory(test_request(ring_birds(4, 1.0, 1.0), params));
+        assert!(result.snapshots.is_empty());
+
+        let (tx, rx) = mpsc::channel();
+        let mut engine = Engine::new(test_request(ring_birds(4, 1.0, 1.0), params), tx);
+        engine.run();
+        assert_eq!(engine.step_count(), 25);
+
+        assert_eq!(engine.run_until(-1.0, 3, 10), Termination::MaxIterations);
+        assert_eq!(engine.step_count(), 35);
+        drop(engine);
+        assert_eq!(rx.iter().count(), 0);
+    }
+
+    /// Tests neighbor inclusion at exactly `interaction_radius`.
+    ///
+    /// Validates that:
+    /// - A neighbor exactly at the radius is excluded without tolerance
+    /// - A positive `neighbor_tolerance` includes it
+    #[test]
+    fn neighbor_at_interaction_radius_boundary() {
+        // Noise-free pair with perpendicular headings; interaction radius equal to
+        // their separation by construction
+        let a = Bird::from_spherical(1.0, PI / 2.0, 0.0, 1.0, 0.0);
+        let b = Bird::from_spherical(1.0, PI / 2.0, 0.3, 1.0, PI / 2.0);
+        let mut params = test_params(2);
+        params.eta = 1e-12;
+        params.interaction_radius = a.distance_from(&b, 1.0);
+        params.total_iterations = 1;
+        params.frame_interval = 1;
+
+        // Excluded: a moves on unchanged as an isolated bird
+        let isolated = a.move_on_sphere(params.dt, params.radius, params.speed);
+        let result = run_in_memory(test_request(vec![a, b], params));
+        assert!(result.snapshots[0].birds[0].approx_eq(&isolated, 1e-9));
+
+        // Included: a turns towards b's heading
+        params.neighbor_tolerance = 1e-9;
+        let result = run_in_memory(test_request(vec![a, b], params));
+        assert!(!result.snapshots[0].birds[0].approx_eq(&isolated, 1e-3));
+    }
+
+    /// Tests `Engine::neighbors()` against known pairwise distances.
+    ///
+    /// Validates that:
+    /// - Each bird lists exactly the other birds within the interaction radius
+    /// - A bird exactly at the interaction radius is excluded, as in the step
+    #[test]
+    fn neighbors_query_matches_pairwise_distances() {
+        // Three birds on the equator at longitudes 0, 0.2 and 0.5: pairwise geodesic
+        // distances 0.2, 0.3 and 0.5 on the unit sphere
+        let birds: Vec<Bird> = [0.0, 0.2, 0.5]
+            .iter()
+            .map(|&phi| Bird::from_spherical(1.0, PI / 2.0, phi, 1.0, 0.0))
+            .collect();
+        let mut params = test_params(3);
+        params.interaction_radius = 0.35;
+
+        let (tx, _rx) = mpsc::channel();
+        let mut engine = Engine::new(test_request(birds.clone(), params), tx);
+        assert_eq!(engine.neighbors(0), vec![1]);
+        assert_eq!(engine.neighbors(1), vec![0, 2]);
+        assert_eq!(engine.neighbors(2), vec![1]);
+
+        // Exactly at the interaction radius is excluded, as in the step
+        let mut params = test_params(3);
+        params.interaction_radius = birds[0].distance_from(&birds[2], 1.0);
+        engine = Engine::new(test_request(birds, params), mpsc::channel().0);
+        assert_eq!(engine.neighbors(0), vec![1]);
+        assert_eq!(engine.neighbors(2), vec![1]);
+    }
+
+    /// Tests `SimulationParams::alignment_fallback` on a cancelling alignment target.
+    ///
+    /// Validates that:
+    /// - `KeepVelocity` moves the bird on as if it were isolated
+    /// - `RandomDirection` reorients it at full speed, tangent to the sphere
+    #[test]
+    fn alignment_fallback_policies() {
+        // Head-on pair along the equator: with half-strength alignment the blended target
+        // of bird a cancels exactly
+        let a = Bird::from_spherical(1.0, PI / 2.0, 0.0, 1.0, 0.0);
+        let b = Bird::from_spherical(1.0, PI / 2.0, 0.1, 1.0, PI);
+        let mut params = test_params(2);
+        params.eta = 1e-12;
+        params.alignment_strength = 0.5;
+        params.interaction_radius = 0.2;
+        params.total_iterations = 1;
+        params.frame_interval = 1;
+
+        // Keep: a moves on as if isolated
+        let isolated = a.move_on_sphere(params.dt, params.radius, params.speed);
+        let result = run_in_memory(test_request(vec![a, b], params));
+        assert!(result.snapshots[0].birds[0].approx_eq(&isolated, 1e-9));
+
+        // Random: a is reoriented at full speed, almost surely away from its heading
+        params.alignment_fallback = AlignmentFallback::RandomDirection;
+        let headings: Vec<f64> = (0..5)
+            .map(|_| {
+                let moved = run_in_memory(test_request(vec![a, b], params)).snapshots[0].birds[0];
+                assert!((moved.velocity.norm() - 1.0).abs() < 1e-9);
+                assert!(moved.position.normalize().dot(&moved.velocity).abs() < 1e-9);
+                moved.heading()
+            })
+            .collect();
+        assert!(
+            headings
+                .iter()
+                .any(|h| (h - isolated.heading()).abs() > 1e-3)
+        );
+    }
+
+    /// Tests bird repulsion with alignment disabled.
+    ///
+    /// Validates that:
+    /// - Without repulsion, birds on converging meridians approach each other
+    /// - With repulsion they separate, keeping speed and tangency
+    #[test]
+    fn repulsion_separates_birds_without_alignment() {
+        // Two close birds on the equator heading north along converging meridians
+        let a = Bird::from_spherical(1.0, PI / 2.0, 0.0, 1.0, -PI / 2.0);
+        let b = Bird::from_spherical(1.0, PI / 2.0, 0.05, 1.0, -PI / 2.0);
+        let initial = a.distance_from(&b, 1.0);
+        let mut params = test_params(2);
+        params.eta = 1e-12;
+        params.enable_alignment = false;
+        params.total_iterations = 20;
+        params.frame_interval = 20;
+
+        // Without repulsion nothing pushes them apart
+        let result = run_in_memory(test_request(vec![a, b], params));
+        let [a_end, b_end] = result.snapshots[0].birds[..] else {
+            panic!("expected two birds");
+        };
+        assert!(a_end.distance_from(&b_end, 1.0) < initial);
+
+        params.repulsion_radius = 0.2;
+        params.repulsion_strength = 1.0;
+        let result = run_in_memory(test_request(vec![a, b], params));
+        let [a_end, b_end] = result.snapshots[0].birds[..] else {
+            panic!("expected two birds");
+        };
+        assert!(a_end.distance_from(&b_end, 1.0) > initial);
+        assert!((a_end.velocity.norm() - 1.0).abs() < 1e-9);
+        assert!(a_end.position.dot(&a_end.velocity).abs() < 1e-9);
+    }
+
+    /// Tests `SimulationRequest::forbidden_bands` on a bird heading into a band.
+    ///
+    /// Validates that:
+    /// - The bird never enters the band
+    /// - It stays on the sphere with a tangent velocity of the configured speed
+    /// - It ends up back north of its starting latitude
+    #[test]
+    fn forbidden_band_reflects_incoming_bird() {
+        // Bird just north of the band [1.2, 1.4], heading due south into it
+        let bird = Bird::from_spherical(1.0, 1.15, 0.3, 1.0, PI / 2.0);
+        let mut params = test_params(1);
+        params.eta = 1e-12;
+        params.total_iterations = 30;
+        params.frame_interval = 1;
+        let mut request = test_request(vec![bird], params);
+        request.forbidden_bands = vec![(1.2, 1.4)];
+
+        let result = run_in_memory(request);
+        for snapshot in &result.snapshots {
+            let moved = snapshot.birds[0];
+            let theta = moved.position.angle_between(&Vec3::z_hat());
+            assert!(theta <= 1.2, "entered band at theta {theta}");
+            assert!((moved.position.norm() - 1.0).abs() < 1e-9);
+            assert!(moved.position.dot(&moved.velocity).abs() < 1e-9);
+            assert!((moved.velocity.norm() - 1.0).abs() < 1e-9);
+        }
+        // Bounced back north
+        let last = result.snapshots.last().unwrap().birds[0];
+        assert!(last.position.angle_between(&Vec3::z_hat()) < 1.15);
+    }
+
+    /// Tests `SpeedControl::Fixed` and `SpeedControl::Capped`.
+    ///
+    /// Validates that:
+    /// - `Fixed` rescales every bird to exactly `speed`
+    /// - `Capped` keeps a slower blended velocity
+    /// - `Capped` shortens velocities above the cap to the cap
+    #[test]
+    fn speed_control_fixed_and_capped() {
+        // Perpendicular pair: half-strength alignment proposes a velocity of about 0.7
+        let a = Bird::from_spherical(1.0, PI / 2.0, 0.0, 1.0, 0.0);
+        let b = Bird::from_spherical(1.0, PI / 2.0, 0.1, 1.0, PI / 2.0);
+        let mut params = test_params(2);
+        params.eta = 1e-12;
+        params.alignment_strength = 0.5;
+        params.interaction_radius = 0.2;
+        params.total_iterations = 1;
+        params.frame_interval = 1;
+
+        let speeds = |params| {
+            let result = run_in_memory(test_request(vec![a, b], params));
+            result.snapshots[0]
+                .birds
+                .iter()
+                .map(|bird| bird.velocity.norm())
+                .collect::<Vec<_>>()
+        };
+
+        // Fixed renormalizes every bird to exactly `speed`
+        for speed in speeds(params) {
+            assert!((speed - 1.0).abs() < 1e-9);
+        }
+
+        // Capped keeps the slower blended velocity
+        params.speed_control = SpeedControl::Capped { max: 1.0 };
+        for speed in speeds(params) {
+            assert!((speed - 0.5_f64.sqrt()).abs() < 1e-2, "{speed}");
+        }
+
+        // Strong repulsion would exceed the cap and is shortened to it
+        params.alignment_strength = 1.0;
+        params.repulsion_radius = 0.2;
+        params.repulsion_strength = 5.0;
+        params.total_iterations = 10;
+        params.frame_interval = 10;
+        for speed in speeds(params) {
+            assert!((speed - 1.0).abs() < 1e-9, "{speed}");
+        }
+
+        params.speed_control = SpeedControl::Capped { max: 0.5 };
+        assert!(params.validate().is_err());
+    }
+
+    /// Tests `Engine::snapshot()` between and at frame boundaries.
+    ///
+    /// Validates that:
+    /// - The snapshot mirrors the current step, time, birds and cluster count
+    /// - Taking a snapshot emits no frame
+    /// - At an interval boundary it equals the emitted frame
+    #[test]
+    fn snapshot_matches_current_particles() {
+        let mut params = test_params(5);
+        params.frame_interval = 4;
+        params.cluster_cutoff = Some(0.5);
+        let (tx, rx) = mpsc::channel();
+        let mut engine = Engine::new(test_request(ring_birds(5, 1.0, 1.0), params), tx);
+        engine.step_many(3);
+
+        let snapshot = engine.snapshot();
+        assert_eq!(snapshot.step, 3);
+        assert_eq!(snapshot.timestamp, engine.current_time());
+        assert_eq!(snapshot.birds, engine.current_particles());
+        assert!(snapshot.cluster_count.is_some());
+        // Taking a snapshot sends nothing
+        assert!(rx.try_recv().is_err());
+
+        // It equals the frame emitted at an interval boundary
+        engine.step_many(1);
+        let snapshot = engine.snapshot();
+        let frame = rx.try_recv().unwrap();
+        assert_eq!(frame.step, snapshot.step);
+        assert_eq!(frame.birds, snapshot.birds);
+        assert_eq!(frame.cluster_count, snapshot.cluster_count);
+    }
+
+    /// Tests `Engine::current_order_parameter()` against the snapshot-based value.
+    ///
+    /// Validates that:
+    /// - It equals `SimulationSnapshot::order_parameter()` of a snapshot at every step
+    #[test]
+    fn current_order_parameter_matches_snapshot() {
+        let (tx, _rx) = mpsc::channel();
+        let mut engine = Engine::new(test_request(ring_birds(8, 1.0, 1.0), test_params(8)), tx);
+
+        for _ in 0..5 {
+            assert_eq!(
+                engine.current_order_parameter(),
+                engine.snapshot().order_parameter()
+            );
+            engine.step_many(3);
+        }
+    }
+
+    /// Tests `SimulationParams::species_alignment` with two non-interacting species.
+    ///
+    /// Validates that:
+    /// - Intermixed species heading in opposite directions keep their own headings
+    /// - Each species orders internally from disordered headings
+    /// - Negative couplings are rejected
+    #[test]
+    fn independent_species_order_separately() {
+        use crate::simulation::SpeciesAlignment;
+
+        let patch = |alpha: &dyn Fn(usize) -> f64| -> Vec<Bird> {
+            (0..40)
+                .map(|i| {
+                    let theta = 1.4 + 0.3 * (i / 8) as f64 / 5.0;
+                    let phi = 0.3 * (i % 8) as f64 / 8.0;
+                    Bird::from_spherical(1.0, theta, phi, 0.05, alpha(i))
+                        .with_species((i % 2) as u8)
+                })
+                .collect()
+        };
+        let species_order = |birds: &[Bird], species: u8| {
+            let members: Vec<Bird> = birds
+                .iter()
+                .filter(|bird| bird.species == species)
+                .copied()
+                .collect();
+            (
+                analysis::polarization(&members),
+                members.iter().map(|b| b.velocity).sum::<Vec3>(),
+            )
+        };
+
+        let mut params = test_params(40);
+        params.interaction_radius = 1.0;
+        params.speed = 0.05;
+        params.eta = 1e-12;
+        params.total_iterations = 100;
+        params.frame_interval = 100;
+        params.species_alignment = SpeciesAlignment::independent();
+
+        // Opposite headings: north for species 0, south for species 1
+        let opposite = patch(&|i| if i % 2 == 0 { -PI / 2.0 } else { PI / 2.0 });
+        let result = run_in_memory(test_request(opposite, params));
+        let last = &result.snapshots.last().unwrap().birds;
+        let (order_0, mean_0) = species_order(last, 0);
+        let (order_1, mean_1) = species_order(last, 1);
+        assert!(order_0 > 0.99 && order_1 > 0.99, "{order_0} {order_1}");
+        assert!(mean_0.normalize().dot(&mean_1.normalize()) < -0.99);
+        assert!(analysis::polarization(last) < 0.05);
+
+        // Scattered headings order within each species
+        let scattered = patch(&|i| 2.399 * i as f64);
+        assert!(species_order(&scattered, 0).0 < 0.5);
+        let result = run_in_memory(test_request(scattered, params));
+        let last = &result.snapshots.last().unwrap().birds;
+        assert!(species_order(last, 0).0 > 0.95);
+        assert!(species_order(last, 1).0 > 0.95);
+
+        params.species_alignment.0[1][0] = -1.0;
+        assert!(params.validate().is_err());
+    }
+
+    /// Tests `Engine::new()` with a species outside the alignment matrix.
+    ///
+    /// Validates that:
+    /// - A bird of species `MAX_SPECIES` panics with a message naming the limit
+    #[test]
+    #[should_panic(expected = "at most 4 species")]
+    fn engine_rejects_unknown_species() {
+        let alien = vec![Bird::from_spherical(1.0, 1.0, 0.0, 1.0, 0.0).with_species(4)];
+        let (tx, _rx) = mpsc::channel();
+        Engine::new(test_request(alien, test_params(1)), tx);
+    }
+
+    /// Tests `Engine::new()` with fewer initial values than `params.num_birds`.
+    ///
+    /// Validates that:
+    /// - It panics with a message stating both counts
+    #[test]
+    #[should_panic(expected = "expects 3 birds (params.num_birds) but got 2")]
+    fn engine_rejects_mismatched_bird_count() {
+        let (tx, _rx) = mpsc::channel();
+        Engine::new(test_request(ring_birds(2, 1.0, 1.0), test_params(3)), tx);
+    }
+
+    /// Tests that the dynamics do not depend on the order of the birds.
+    ///
+    /// Validates that:
+    /// - `shuffle_birds` is a deterministic permutation
+    /// - A run from shuffled initial values ends in the permuted final state of the
+    ///   unshuffled run, up to rounding of the neighbor sums
+    #[test]
+    fn dynamics_are_invariant_under_bird_order() {
+        use crate::ensemble::shuffle_birds;
+
+        let birds = random_birds(60, 17);
+
+        let mut shuffled = birds.clone();
+        shuffle_birds(&mut shuffled, 4);
+        let mut again = birds.clone();
+        shuffle_birds(&mut again, 4);
+        assert_eq!(shuffled, again);
+        assert_ne!(shuffled, birds);
+
+        // Initial positions are distinct, so they identify each bird after shuffling
+        let permutation: Vec<usize> = shuffled
+            .iter()
+            .map(|bird| {
+                birds
+                    .iter()
+                    .position(|b| b.position == bird.position)
+                    .unwrap()
+            })
+            .collect();
+
+        let mut params = test_params(60);
+        params.eta = 1e-12;
+        params.interaction_radius = 0.4;
+        params.total_iterations = 50;
+        params.frame_interval = 50;
+        let plain = run_in_memory(test_request(birds, params));
+        let permuted = run_in_memory(test_request(shuffled, params));
+
+        let plain_final = &plain.snapshots.last().unwrap().birds;
+        let permuted_final = &permuted.snapshots.last().unwrap().birds;
+        for (bird, &original) in permuted_final.iter().zip(&permutation) {
+            assert!(bird.approx_eq(&plain_final[original], 1e-8));
+        }
+    }
+
+    /// Tests the pure-noise random walk and `analysis::estimate_rotational_diffusion()`.
+    ///
+    /// Validates that:
+    /// - With `alignment_strength = 0`, isolated birds diffuse too
+    /// - The estimate matches `η² / (2 dt)` and grows with `eta`
+    #[test]
+    fn pure_noise_rotational_diffusion() {
+        let num_birds = 400;
+        let birds = random_birds(num_birds, 8);
+
+        let estimate = |eta: f64| {
+            let mut params = test_params(num_birds);
+            params.alignment_strength = 0.0;
+            params.eta = eta;
+            params.total_iterations = 200;
+            params.frame_interval = 1;
+            let result = run_in_memory(test_request(birds.clone(), params));
+            analysis::estimate_rotational_diffusion(&result)
+        };
+
+        // The noise is not seeded, so the bound comes from the estimator's spread. The fit
+        // is dominated by lags near half the run, where the time-averaged squared heading
+        // change of one random walk has a relative variance of about 4τ/(3T) = 2/3. The
+        // birds are independent, so the relative standard error is sqrt(2 / (3N)), about
+        // 0.04 here (0.04-0.045 over repeated runs). Six standard errors make a spurious
+        // failure negligible while still catching a wrong prefactor.
+        let tolerance = 6.0 * (2.0 / (3.0 * num_birds as f64)).sqrt();
+        let (slow, fast) = (estimate(0.05), estimate(0.2));
+        let expected = |eta: f64| eta * eta / (2.0 * 0.01);
+        assert!((slow / expected(0.05) - 1.0).abs() < tolerance, "{slow}");
+        assert!((fast / expected(0.2) - 1.0).abs() < tolerance, "{fast}");
+        assert!(fast > slow);
+    }
+
+    /// Tests `Engine::with_rule()` with the provided and a custom `InteractionRule`.
+    ///
+    /// Validates that:
+    /// - `VicsekAlignment` reproduces the built-in uniform alignment step
+    /// - A custom rule ignoring all neighbors matches a run with alignment disabled
+    #[test]
+    fn interaction_rules_replace_alignment() {
+        use crate::simulation::{InteractionRule, VicsekAlignment};
+
+        /// Ignores every neighbor, so birds keep their headings.
+        struct Ignore;
+
+        impl InteractionRule for Ignore {
+            fn contribution(&self, _: &Bird, _: &Bird, _: f64) -> Vec3 {
+                Vec3::zero()
+            }
+        }
+
+        let birds = random_birds(40, 23);
+
+        let mut params = test_params(40);
+        params.eta = 1e-12;
+        params.interaction_radius = 0.6;
+        let run = |params: SimulationParams, rule: Option<Box<dyn InteractionRule>>| {
+            let (tx, _rx) = mpsc::channel();
+            let mut engine = Engine::new(test_request(birds.clone(), params), tx);
+            if let Some(rule) = rule {
+                engine = engine.with_rule(rule);
+            }
+            engine.step_many(30);
+            engine.current_particles().to_vec()
+        };
+
+        let built_in = run(params, None);
+        let vicsek = run(params, Some(Box::new(VicsekAlignment)));
+        for (a, b) in built_in.iter().zip(&vicsek) {
+            assert!(a.approx_eq(b, 1e-8));
+        }
+
+        let mut free = params;
+        free.enable_alignment = false;
+        let free_run = run(free, None);
+        let ignoring = run(params, Some(Box::new(Ignore)));
+        for (a, b) in free_run.iter().zip(&ignoring) {
+            assert!(a.approx_eq(b, 1e-8));
+        }
+        assert!(
+            built_in
+                .iter()
+                .zip(&ignoring)
+                .any(|(a, b)| !a.approx_eq(b, 1e-3))
+        );
+    }
+
+    /// Tests `SoftRepulsion::contribution()`.
+    ///
+    /// Validates that:
+    /// - A close neighbor pushes the bird away along the tangent plane
+    /// - The push scales with strength and the bird's speed
+    /// - Neighbors beyond the radius contribute nothing
+    #[test]
+    fn soft_repulsion_pushes_away() {
+        use crate::simulation::{InteractionRule, SoftRepulsion};
+
+        let bird = Bird::from_spherical(1.0, PI / 2.0, 0.0, 2.0, 0.0);
+        let neighbor = Bird::from_spherical(1.0, PI / 2.0, 0.1, 2.0, 0.0);
+        let distance = bird.distance_from(&neighbor, 1.0);
+        let rule = SoftRepulsion {
+            radius: 0.2,
+            strength: 0.5,
+        };
+
+        let push = rule.contribution(&bird, &neighbor, distance);
+        assert!(push.dot(&bird.position).abs() < 1e-12);
+        assert!((push.norm() - 1.0).abs() < 1e-12);
+        assert!(push.dot(&(neighbor.position - bird.position)) < 0.0);
+
+        let far = SoftRepulsion {
+            radius: 0.05,
+            strength: 0.5,
+        };
+        assert_eq!(far.contribution(&bird, &neighbor, distance), Vec3::zero());
+    }
+
+    /// Tests `SimulationParams::warmup_steps`.
+    ///
+    /// Validates that:
+    /// - No snapshot is recorded before the warm-up ends
+    /// - Step counter and timestamps keep counting through the warm-up
+    /// - The recorded frames equal the tail of an unwarmed run
+    /// - A warm-up longer than the run is rejected
+    #[test]
+    fn warmup_steps_skip_transient_frames() {
+        let mut params = test_params(6);
+        params.interaction_radius = 1e-3;
+        params.total_iterations = 40;
+        params.frame_interval = 4;
+        let cold = run_in_memory(test_request(ring_birds(6, 1.0, 1.0), params));
+
+        params.warmup_steps = 15;
+        let warm = run_in_memory(test_request(ring_birds(6, 1.0, 1.0), params));
+        let steps: Vec<usize> = warm.snapshots.iter().map(|s| s.step).collect();
+        assert_eq!(steps, vec![16, 20, 24, 28, 32, 36, 40]);
+        assert!(steps[0] >= params.warmup_steps);
+        assert!((warm.snapshots[0].timestamp - 16.0 * params.dt).abs() < 1e-12);
+        // Isolated birds evolve deterministically up to noise
+        for (frame, reference) in warm.snapshots.iter().zip(&cold.snapshots[3..]) {
+            assert_eq!(frame.step, reference.step);
+            for (a, b) in frame.birds.iter().zip(&reference.birds) {
+                assert!(a.approx_eq(b, 1e-6));
+            }
+        }
+
+        params.warmup_steps = 16;
+        let at_frame = run_in_memory(test_request(ring_birds(6, 1.0, 1.0), params));
+        assert_eq!(at_frame.snapshots[0].step, 16);
+
+        params.warmup_steps = 41;
+        assert!(params.validate().is_err());
+    }
+
+    /// Tests `Engine::step_many()` against individual steps.
+    ///
+    /// Validates that:
+    /// - Batched and single steps reach the same final state
+    /// - Step counter and simulation time advance identically
+    /// - Frames are emitted only at interval boundaries
+    #[test]
+    fn step_many_matches_individual_steps() {
+        // Isolated birds (tiny interaction radius) evolve deterministically without noise
+        let mut params = test_params(6);
+        params.interaction_radius = 1e-3;
+        params.frame_interval = 4;
+        let birds = ring_birds(6, 1.0, 1.0);
+
+        let (batch_tx, batch_rx) = mpsc::channel();
+        let mut batched = Engine::new(test_request(birds.clone(), params), batch_tx);
+        batched.step_many(10);
+
+        let (single_tx, single_rx) = mpsc::channel();
+        let mut single = Engine::new(test_request(birds, params), single_tx);
+        for _ in 0..10 {
+            single.step();
+        }
+
+        assert_eq!(batched.step_count(), 10);
+        assert_eq!(batched.step_count(), single.step_count());
+        assert_eq!(batched.current_time(), single.current_time());
+        for (a, b) in batched
+            .current_particles()
+            .iter()
+            .zip(single.current_particles())
+        {
+            assert_eq!(a, b);
+        }
+
+        drop(batched);
+        drop(single);
+        let frames: Vec<usize> = batch_rx.iter().map(|frame| frame.step).collect();
+        assert_eq!(frames, vec![4, 8]);
+        assert_eq!(single_rx.iter().count(), 0);
+    }
+
+    /// Tests `SimulationResult::steady_state_order()`.
+    ///
+    /// Validates that:
+    /// - A constant order parameter yields zero error
+    /// - Snapshots before the burn-in are excluded
+    /// - A burn-in past the last snapshot yields NaN
+    #[test]
+    fn steady_state_order() {
+        let constant = result_with_orders(&[0.6; 8], 5);
+        let (mean, error) = constant.steady_state_order(0);
+        assert!((mean - 0.6).abs() < 1e-12);
+        assert!(error.abs() < 1e-12);
+
+        // Transient of low order in steps 10 and 20, steady 0.9 afterwards
+        let relaxing = result_with_orders(&[0.1, 0.2, 0.9, 0.9, 0.9], 10);
+        assert!((relaxing.snapshots[0].order_parameter() - 0.1).abs() < 1e-12);
+        let (mean, error) = relaxing.steady_state_order(30);
+        assert!((mean - 0.9).abs() < 1e-12);
+        assert!(error.abs() < 1e-12);
+
+        let (mean, _) = relaxing.steady_state_order(0);
+        assert!((mean - 0.6).abs() < 1e-12);
+
+        let (mean, error) = relaxing.steady_state_order(1000);
+        assert!(mean.is_nan() && error.is_nan());
+    }
+
+    /// Tests `SimulationResult::time_to_order()`.
+    ///
+    /// Validates that:
+    /// - An ordering run reports the first snapshot at or above the threshold
+    /// - A disordering run reports the first snapshot at or below it
+    /// - Unreached thresholds and empty results give `None`
+    #[test]
+    fn time_to_order_rising_and_falling() {
+        let ordering = result_with_orders(&[0.1, 0.3, 0.55, 0.8, 0.95], 10);
+        // Snapshots sit at steps 10, 20, ... with dt = 0.01
+        assert_eq!(ordering.time_to_order(0.5), Some(0.3));
+        assert_eq!(ordering.time_to_order(0.9), Some(0.5));
+        // Starting above the threshold means waiting for the order to fall below it
+        assert_eq!(ordering.time_to_order(0.05), None);
+        assert_eq!(ordering.time_to_order(0.99), None);
+
+        let disordering = result_with_orders(&[0.95, 0.7, 0.4, 0.2], 10);
+        assert_eq!(disordering.time_to_order(0.5), Some(0.3));
+        assert_eq!(disordering.time_to_order(0.1), None);
+
+        assert_eq!(result_with_orders(&[], 10).time_to_order(0.5), None);
+    }
+
+    /// Tests `SimulationResult::susceptibility()`.
+    ///
+    /// Validates that:
+    /// - A fluctuating series gives `N` times its order-parameter variance
+    /// - Snapshots before the burn-in are excluded
+    /// - Fewer than two remaining snapshots yield NaN
+    #[test]
+    fn susceptibility_of_fluctuating_series() {
+        // Transient at 0.1, then alternating 0.5 ± 0.2: variance 0.04, N = 2
+        let result = result_with_orders(&[0.1, 0.1, 0.3, 0.7, 0.3, 0.7, 0.3, 0.7], 10);
+        assert!((result.susceptibility(30) - 2.0 * 0.04).abs() < 1e-9);
+        assert!(result.susceptibility(0) > result.susceptibility(30));
+
+        let constant = result_with_orders(&[0.6; 5], 10);
+        assert!(constant.susceptibility(0).abs() < 1e-12);
+
+        assert!(result.susceptibility(80).is_nan());
+        assert!(result.susceptibility(1000).is_nan());
+    }
+
+    /// Tests `SimulationResult::downsample()`.
+    ///
+    /// Validates that:
+    /// - Every stride-th snapshot is kept and the final one is always retained
+    /// - Metadata is preserved and the frame interval is scaled
+    /// - A stride of 1 is the identity
+    #[test]
+    fn downsample_keeps_stride_and_last() {
+        let orders: Vec<f64> = (0..10).map(|i| i as f64 / 10.0).collect();
+        let result = result_with_orders(&orders, 5);
+
+        let thinned = result.downsample(4);
+        let steps: Vec<usize> = thinned.snapshots.iter().map(|s| s.step).collect();
+        assert_eq!(steps, vec![5, 25, 45, 50]);
+        assert_eq!(thinned.params.frame_interval, 20);
+        assert_eq!(
+            thinned.params.total_iterations,
+            result.params.total_iterations
+        );
+        assert_eq!(
+            (thinned.id, thinned.tag, thinned.ensemble_entry_id),
+            (result.id, result.tag, result.ensemble_entry_id)
+        );
+        let last = thinned.snapshots.last().unwrap();
+        assert_eq!(
+            last.birds[0].velocity,
+            result.snapshots.last().unwrap().birds[0].velocity
+        );
+
+        // Last snapshot already on the stride grid is not duplicated
+        assert_eq!(result.downsample(3).snapshots.len(), 4);
+        assert_eq!(result.downsample(1).snapshots.len(), 10);
+        assert_eq!(result.downsample(100).snapshots.len(), 2);
+        assert!(
+            result_with_orders(&[], 5)
+                .downsample(2)
+                .snapshots
+                .is_empty()
+        );
+    }
+
+    /// Tests `SimulationResult::path_lengths()`.
+    ///
+    /// Validates that:
+    /// - An isolated bird on a great circle accumulates `speed * elapsed time`
+    /// - A result without snapshots yields no lengths
+    #[test]
+    fn path_lengths_constant_speed() {
+        // Isolated birds (tiny interaction radius) move on their great circles without noise
+        let mut params = test_params(2);
+        params.radius = 2.0;
+        params.speed = 1.5;
+        params.interaction_radius = 1e-3;
+        params.total_iterations = 200;
+        params.frame_interval = 10;
+        let birds = vec![
+            Bird::from_spherical(2.0, PI / 3.0, 0.0, 1.5, 0.4),
+            Bird::from_spherical(2.0, 2.0, 3.0, 1.5, 1.2),
+        ];
+        let result = run_in_memory(test_request(birds, params));
+
+        let first = result.snapshots.first().unwrap();
+        let last = result.snapshots.last().unwrap();
+        let expected = params.speed * (last.timestamp - first.timestamp);
+
+        let lengths = result.path_lengths();
+        assert_eq!(lengths.len(), 2);
+        for length in lengths {
+            assert!((length - expected).abs() < 1e-9);
+        }
+
+        assert!(result_with_orders(&[], 5).path_lengths().is_empty());
+    }
+
+    /// Tests predator repulsion in `update_particle_state()`.
+    ///
+    /// Validates that:
+    /// - A bird near a predator turns away from it while keeping its speed
+    /// - A predator outside the interaction radius has no effect
+    #[test]
+    fn predator_repels_nearby_bird() {
+        // Single isolated bird on the equator heading north; no alignment, no noise
+        let bird = Bird {
+            position: Vec3::x_hat(),
+            velocity: Vec3::z_hat(),
+            species: 0,
+        };
+        let mut params = test_params(1);
+        params.total_iterations = 1;
+        params.frame_interval = 1;
+
+        // Predator slightly east of the bird: "away" is the -y direction
+        let predator = Vec3::new(1.0, 0.2, 0.0);
+        let mut request = test_request(vec![bird], params);
+        request.predators = vec![(predator, 1.0)];
+        let result = run_in_memory(request);
+        let moved = result.snapshots[0].birds[0];
+        assert!(moved.velocity.y < -0.1);
+        assert!((moved.velocity.norm() - params.speed).abs() < 1e-10);
+        assert!(moved.position.dot(&moved.velocity).abs() < 1e-10);
+
+        // Same predator on the far side of the sphere is ignored
+        let mut request = test_request(vec![bird], params);
+        request.predators = vec![(-predator, 1.0)];
+        let unaffected = run_in_memory(request).snapshots[0].birds[0];
+        let baseline = run_in_memory(test_request(vec![bird], params)).snapshots[0].birds[0];
+        assert!(unaffected.velocity.approx_eq(&baseline.velocity, 1e-15));
+        assert!(baseline.velocity.y.abs() < 1e-12);
+    }
+
+    /// Tests pinned leaders via `SimulationRequest::leaders`.
+    ///
+    /// Validates that:
+    /// - A leader ignores its neighbors and follows its own great circle exactly
+    /// - Followers within range converge to the leader's heading
+    #[test]
+    fn pinned_leader_keeps_direction_and_is_followed() {
+        let birds: Vec<Bird> = (0..5)
+            .map(|i| {
+                Bird::from_spherical(
+                    1.0,
+                    1.0 + 0.02 * i as f64,
+                    0.02 * i as f64,
+                    1.0,
+                    0.4 * i as f64,
+                )
+            })
+            .collect();
+        let mut params = test_params(5);
+        params.eta = 1e-12;
+        params.total_iterations = 400;
+        params.frame_interval = 400;
+
+        let mut request = test_request(birds.clone(), params);
+        request.leaders = vec![0];
+        let final_birds = run_in_memory(request).snapshots[0].birds.clone();
+
+        // Leader moved exactly as an isolated bird on its great circle
+        let mut expected = birds[0];
+        for _ in 0..params.total_iterations {
+            expected = expected.move_on_sphere(params.dt, params.radius, params.speed);
+        }
+        assert!(final_birds[0].approx_eq(&expected, 1e-12));
+
+        // Followers head the same way as the leader (transported to their position)
+        for follower in &final_birds[1..] {
+            let leader_heading = final_birds[0].parallel_transport_velocity(follower);
+            assert!(follower.velocity.dot(&leader_heading) > 0.999);
+        }
+    }
+
+    /// Tests `Engine::run_until()`.
+    ///
+    /// Validates that:
+    /// - A low-noise, fully connected flock converges long before the budget
+    /// - An unreachable tolerance exhausts exactly `max_iterations` steps
+    #[test]
+    fn run_until_converges_early() {
+        let birds: Vec<Bird> = (0..12)
+            .map(|i| {
+                Bird::from_spherical(1.0, 0.3 + 0.02 * i as f64, 0.1 * i as f64, 1.0, i as f64)
+            })
+            .collect();
+        let mut params = test_params(12);
+        params.interaction_radius = 2.0;
+        params.eta = 0.01;
+        params.frame_interval = 5;
+
+        let (tx, _rx) = mpsc::channel();
+        let mut engine = Engine::new(test_request(birds.clone(), params), tx);
+        assert_eq!(engine.run_until(0.01, 5, 10_000), Termination::Converged);
+        assert!(engine.step_count() < 1_000);
+        assert!(engine.step_count().is_multiple_of(params.frame_interval));
+
+        let (tx, _rx) = mpsc::channel();
+        let mut engine = Engine::new(test_request(birds, params), tx);
+        assert_eq!(engine.run_until(-1.0, 3, 23), Termination::MaxIterations);
+        assert_eq!(engine.step_count(), 23);
+    }
+
+    /// Tests `sweep::sweep()` over `eta`.
+    ///
+    /// Validates that:
+    /// - One result per value is returned, in input order
+    /// - Only the swept parameter differs, and ids are offset by position
+    #[test]
+    fn sweep_eta_three_values() {
+        use crate::simulation::{SweepParameter, sweep};
+
+        let params = test_params(5);
+        let base = test_request(ring_birds(5, params.radius, params.speed), params);
+        let etas = [0.05, 0.5, 2.0];
+
+        let results = sweep(&base, SweepParameter::Eta, &etas);
+        assert_eq!(results.len(), 3);
+        for (k, ((value, result), eta)) in results.iter().zip(etas).enumerate() {
+            assert_eq!(*value, eta);
+            assert_eq!(result.params.eta, eta);
+            assert_eq!(result.params.interaction_radius, params.interaction_radius);
+            assert_eq!(result.id, base.id + k);
+            assert_eq!(result.tag, base.tag);
+            assert_eq!(result.snapshots.len(), 5);
+        }
+
+        let radii = SweepParameter::InteractionRadius.apply(params, 0.9);
+        assert_eq!(radii.interaction_radius, 0.9);
+        assert_eq!(radii.eta, params.eta);
+    }
+
+    /// Tests `ensemble_average()` on the order parameter.
+    ///
+    /// Two runs start from differently seeded ensembles with isolated birds, which
+    /// makes them deterministic so the average can be checked against separate runs.
+    ///
+    /// Validates that:
+    /// - One average is returned per frame, at the frame's step
+    /// - The mean and standard error match those of the separate runs
+    #[test]
+    fn ensemble_average_order_parameter() {
+        use crate::ensemble::{
+            EntryGenerationParams, InitialDistribution, VelocityInit, generate_parallel,
+        };
+        use crate::simulation::ensemble_average;
+
+        let mut params = test_params(6);
+        params.interaction_radius = 1e-3;
+        let entries = generate_parallel(
+            0,
+            2,
+            EntryGenerationParams {
+                num_birds: 6,
+                radius: params.radius,
+                speed: params.speed,
+                min_distance: 0.1,
+                distribution: InitialDistribution::Uniform,
+                velocity_init: VelocityInit::Random,
+            },
+            Some(17),
+            None,
+        )
+        .unwrap();
+        let requests: Vec<SimulationRequest> = entries
+            .into_iter()
+            .map(|entry| test_request(entry.birds, params))
+            .collect();
+
+        let separate: Vec<SimulationResult> = requests.iter().cloned().map(run_in_memory).collect();
+        let averaged = ensemble_average(requests, SimulationSnapshot::order_parameter);
+
+        assert_eq!(averaged.len(), 5);
+        for (k, &(step, mean, error)) in averaged.iter().enumerate() {
+            assert_eq!(step, (k + 1) * params.frame_interval);
+            let a = separate[0].snapshots[k].order_parameter();
+            let b = separate[1].snapshots[k].order_parameter();
+            assert!((mean - (a + b) / 2.0).abs() < 1e-12);
+            // Standard error of two samples is |a - b| / 2
+            assert!((error - (a - b).abs() / 2.0).abs() < 1e-12);
+        }
+    }
+
+    /// Tests `SimulationParams::alignment_strength`.
+    ///
+    /// Validates that:
+    /// - `s = 0` keeps each bird's own direction (up to negligible noise), i.e. it
+    ///   moves exactly like an isolated bird
+    /// - `s = 1` lets interacting birds turn towards each other
+    #[test]
+    fn alignment_strength_zero_keeps_direction() {
+        let birds = vec![
+            Bird::from_spherical(1.0, 1.0, 0.0, 1.0, 0.0),
+            Bird::from_spherical(1.0, 1.1, 0.0, 1.0, 1.5),
+        ];
+        let mut params = test_params(2);
+        params.eta = 1e-12;
+        params.total_iterations = 1;
+        params.frame_interval = 1;
+
+        let mut isolated = params;
+        isolated.interaction_radius = 1e-3;
+        let expected = run_in_memory(test_request(birds.clone(), isolated)).snapshots[0].clone();
+
+        let mut inertial = params;
+        inertial.alignment_strength = 0.0;
+        let frozen = run_in_memory(test_request(birds.clone(), inertial)).snapshots[0].clone();
+        for (a, b) in frozen.birds.iter().zip(&expected.birds) {
+            assert!(a.approx_eq(b, 1e-9));
+        }
+
+        let aligned = run_in_memory(test_request(birds, params)).snapshots[0].clone();
+        assert!(
+            !aligned.birds[0]
+                .velocity
+                .approx_eq(&expected.birds[0].velocity, 1e-3)
+        );
+    }
+
+    /// Tests `AlignmentKernel` weighting.
+    ///
+    /// Validates that:
+    /// - Kernel weights decrease with distance as documented
+    /// - With a Gaussian kernel a bird follows its close neighbor more than under
+    ///   the uniform kernel, where the distant neighbor counts equally
+    #[test]
+    fn gaussian_kernel_downweights_distant_neighbor() {
+        let gaussian = AlignmentKernel::Gaussian { sigma: 0.05 };
+        assert_eq!(AlignmentKernel::Uniform.weight(0.4, 0.5), 1.0);
+        assert!((AlignmentKernel::Linear.weight(0.4, 0.5) - 0.2).abs() < 1e-12);
+        assert!(gaussian.weight(0.05, 0.5) > 100.0 * gaussian.weight(0.4, 0.5));
+
+        // Bird 0 heads east; close bird 1 and distant bird 2 tilt in opposite directions
+        let birds = vec![
+            Bird::from_spherical(1.0, PI / 2.0, 0.0, 1.0, 0.0),
+            Bird::from_spherical(1.0, PI / 2.0 - 0.05, 0.0, 1.0, -1.2),
+            Bird::from_spherical(1.0, PI / 2.0 + 0.4, 0.0, 1.0, 1.2),
+        ];
+        let mut params = test_params(3);
+        params.eta = 1e-12;
+        params.total_iterations = 1;
+        params.frame_interval = 1;
+
+        let heading = |kernel| {
+            let mut p = params;
+            p.kernel = kernel;
+            run_in_memory(test_request(birds.clone(), p)).snapshots[0].birds[0].velocity
+        };
+        let uniform = heading(AlignmentKernel::Uniform);
+        let weighted = heading(gaussian);
+
+        // Bird 1 tilts north (+z, i.e. along -θ̂ for alpha < 0), bird 2 south
+        assert!(weighted.z > uniform.z + 0.1);
+        assert!((weighted.norm() - params.speed).abs() < 1e-10);
+    }
+
+    /// Tests `Engine::reset()`.
+    ///
+    /// Validates that:
+    /// - A reset engine reproduces a freshly constructed one (isolated birds make the
+    ///   dynamics deterministic)
+    /// - Counters restart and buffers keep their allocation
+    #[test]
+    fn reset_matches_fresh_engine() {
+        let mut params = test_params(6);
+        params.interaction_radius = 1e-3;
+        let first = ring_birds(6, params.radius, params.speed);
+        let second: Vec<Bird> = (0..6)
+            .map(|i| Bird::from_spherical(1.0, 0.4 + 0.3 * i as f64, 1.0, 1.0, 0.7 * i as f64))
+            .collect();
+
+        let (tx, _rx) = mpsc::channel();
+        let mut reused = Engine::new(test_request(first, params), tx);
+        reused.step_many(7);
+        let front_buffer = reused.current_particles().as_ptr();
+        reused.reset(second.clone());
+        assert_eq!(reused.step_count(), 0);
+        assert_eq!(reused.current_time(), 0.0);
+        reused.step_many(5);
+
+        let (tx, _rx) = mpsc::channel();
+        let mut fresh = Engine::new(test_request(second, params), tx);
+        fresh.step_many(5);
+
+        assert_eq!(reused.step_count(), fresh.step_count());
+        assert_eq!(reused.current_time(), fresh.current_time());
+        for (a, b) in reused
+            .current_particles()
+            .iter()
+            .zip(fresh.current_particles())
+        {
+            assert_eq!(a, b);
+        }
+        // After an even number of swaps the same allocation is in front again
+        reused.step_many(1);
+        assert_eq!(reused.current_particles().as_ptr(), front_buffer);
+    }
+
+    /// Tests `SimulationRequest::from_ensemble()`.
+    ///
+    /// Validates that:
+    /// - A generated entry is wired into a runnable request
+    /// - Bird count and radius mismatches are rejected
+    /// - Radius and speed are matched relative to their magnitude
+    #[test]
+    fn request_from_ensemble_round_trip() {
+        use crate::ensemble::{
+            EntryGenerationParams, InitialDistribution, VelocityInit, generate_parallel,
+        };
+
+        let entry = generate_parallel(
+            2,
+            4,
+            EntryGenerationParams {
+                num_birds: 8,
+                radius: 1.0,
+                speed: 1.0,
+                min_distance: 0.1,
+                distribution: InitialDistribution::Uniform,
+                velocity_init: VelocityInit::Random,
+            },
+            Some(3),
+            None,
+        )
+        .unwrap()
+        .remove(3);
+
+        let params = test_params(8);
+        let request = SimulationRequest::from_ensemble(entry.clone(), params, 40, 41).unwrap();
+        assert_eq!((request.id, request.tag), (40, 41));
+        assert_eq!(request.ensemble_entry_id, 3);
+        assert_eq!(request.initial_values[5].position, entry.birds[5].position);
+        assert!(request.predators.is_empty());
+        let result = run_in_memory(request);
+        assert_eq!(result.ensemble_entry_id, 3);
+        assert_eq!(result.snapshots[0].birds.len(), 8);
+
+        let err = SimulationRequest::from_ensemble(entry.clone(), test_params(7), 0, 0);
+        assert!(err.unwrap_err().contains("holds 8 birds"));
+
+        let mut wrong_radius = test_params(8);
+        wrong_radius.radius = 2.0;
+        assert!(SimulationRequest::from_ensemble(entry.clone(), wrong_radius, 0, 0).is_err());
+
+        // A rounding-level mismatch on a large sphere exceeds 1e-12 in absolute terms
+        let mut large = entry;
+        large.params.radius = 1e4;
+        large.params.speed = 1e4;
+        let mut rounded = test_params(8);
+        rounded.radius = 1e4 * (1.0 + 1e-13);
+        rounded.speed = 1e4 * (1.0 - 1e-13);
+        assert!(SimulationRequest::from_ensemble(large, rounded, 0, 0).is_ok());
+    }
 }
@@ -3,6 +3,7 @@
 //! and rotation.
 
 use super::Vec3;
+use crate::constants::UNIT_AXIS_TOLERANCE;
 
 impl Vec3 {
     /// Calculates the squared magnitude (length) of the vector.
@@ -50,10 +51,25 @@ impl Vec3 {
         self.norm_squared().sqrt()
     }
 
+    /// Returns `true` if no component is NaN or infinite.
+    ///
+    /// # Examples
+    /// ```
+    /// # use flocking_lib::vector::Vec3;
+    /// assert!(Vec3::new(1.0, 2.0, 3.0).is_finite());
+    /// assert!(!Vec3::new(f64::NAN, 0.0, 0.0).is_finite());
+    /// assert!(!Vec3::new(0.0, f64::INFINITY, 0.0).is_finite());
+    /// ```
+    pub fn is_finite(&self) -> bool {
+        self.x.is_finite() && self.y.is_finite() && self.z.is_finite()
+    }
+
     /// Returns a unit vector in the same direction as this vector.
     ///
     /// If the vector has zero or near-zero magnitude (within floating-point epsilon),
     /// returns the zero vector to avoid division by zero and numerical instability.
+    /// The zero vector is also returned when the vector or its magnitude is not finite,
+    /// so NaN or infinite input does not propagate; check [`Vec3::is_finite`] to detect it.
     ///
     /// The normalization process preserves direction while setting magnitude to 1.
     /// This is essential for direction vectors in physics calculations.
@@ -69,13 +85,34 @@ impl Vec3 {
     ///
     /// // Zero vector normalization
     /// assert_eq!(Vec3::zero().normalize(), Vec3::zero());
+    ///
+    /// // Non-finite input
+    /// assert_eq!(Vec3::new(f64::INFINITY, 1.0, 0.0).normalize(), Vec3::zero());
     /// ```
     pub fn normalize(&self) -> Self {
+        self.try_normalize().unwrap_or_else(Vec3::zero)
+    }
+
+    /// Returns a unit vector in the same direction, or `None` if there is no direction.
+    ///
+    /// `None` is returned for zero or near-zero magnitude (within floating-point
+    /// epsilon) and for non-finite input, i.e. exactly where [`Vec3::normalize`] falls
+    /// back to the zero vector. Use this when a direction is required and the
+    /// degenerate case needs explicit handling.
+    ///
+    /// # Examples
+    /// ```
+    /// # use flocking_lib::vector::Vec3;
+    /// assert_eq!(Vec3::new(0.0, 3.0, 0.0).try_normalize(), Some(Vec3::y_hat()));
+    /// assert_eq!(Vec3::zero().try_normalize(), None);
+    /// assert_eq!(Vec3::new(f64::NAN, 1.0, 0.0).try_normalize(), None);
+    /// ```
+    pub fn try_normalize(&self) -> Option<Self> {
         let norm = self.norm();
-        if norm > f64::EPSILON {
-            *self / norm
+        if norm > f64::EPSILON && norm.is_finite() {
+            Some(*self / norm)
         } else {
-            Vec3::zero()
+            None
         }
     }
 
@@ -224,8 +261,7 @@ impl Vec3 {
         }
 
         // Check if axis is normalized (within tolerance)
-        let tolerance = f64::EPSILON * 10.0; // Allow small numerical errors
-        if (axis_norm_sq - 1.0).abs() > tolerance {
+        if (axis_norm_sq - 1.0).abs() > UNIT_AXIS_TOLERANCE {
             return None; // Axis must be normalized
         }
 
@@ -245,4 +281,130 @@ impl Vec3 {
 
         Some(rotated)
     }
+
+    /// Returns the minimal rotation mapping the direction of this vector onto `target`.
+    ///
+    /// The rotation is returned as an `(axis, angle)` pair directly usable with
+    /// [`Vec3::rotate_around`]: the axis is normalized and the angle lies in [0, π].
+    /// The angle is computed as `atan2(|a × b|, a · b)`, which stays accurate near
+    /// 0 and π where `acos` loses precision. Only directions matter, so the inputs
+    /// need not be unit vectors.
+    ///
+    /// # Degenerate Cases
+    /// - **Parallel** inputs: angle 0 with an arbitrary (but deterministic) axis
+    ///   orthogonal to `self`
+    /// - **Antiparallel** inputs: angle π about a deterministic axis orthogonal to `self`
+    /// - **Zero** input: angle 0 about the X-axis
+    ///
+    /// # Examples
+    /// ```
+    /// # use flocking_lib::vector::Vec3;
+    /// use std::f64::consts::PI;
+    ///
+    /// let (axis, angle) = Vec3::x_hat().rotation_to(&Vec3::y_hat());
+    /// assert!(axis.approx_eq(&Vec3::z_hat(), 1e-12));
+    /// assert!((angle - PI / 2.0).abs() < 1e-12);
+    ///
+    /// let rotated = Vec3::x_hat().rotate_around(&axis, angle).unwrap();
+    /// assert!(rotated.approx_eq(&Vec3::y_hat(), 1e-12));
+    /// ```
+    pub fn rotation_to(&self, target: &Self) -> (Self, f64) {
+        let from = self.normalize();
+        let to = target.normalize();
+        let cross = from.cross(&to);
+        let angle = cross.norm().atan2(from.dot(&to));
+
+        if from == Vec3::zero() || to == Vec3::zero() {
+            return (Vec3::x_hat(), 0.0);
+        }
+
+        let axis = cross.normalize();
+        if axis == Vec3::zero() {
+            // Parallel or antiparallel: any axis orthogonal to `self` works
+            return (from.orthogonal_unit(), angle);
+        }
+        (axis, angle)
+    }
+
+    /// Calculates the squared Euclidean distance to `other`.
+    ///
+    /// Computed directly from the component differences without building an
+    /// intermediate vector; prefer it over [`Vec3::distance_to`] for comparisons.
+    ///
+    /// # Examples
+    /// ```
+    /// # use flocking_lib::vector::Vec3;
+    /// let d2 = Vec3::new(1.0, 2.0, 3.0).distance_squared_to(&Vec3::new(4.0, 6.0, 3.0));
+    /// assert_eq!(d2, 25.0);
+    /// ```
+    pub fn distance_squared_to(&self, other: &Self) -> f64 {
+        let dx = self.x - other.x;
+        let dy = self.y - other.y;
+        let dz = self.z - other.z;
+        dx * dx + dy * dy + dz * dz
+    }
+
+    /// Calculates the Euclidean (chord) distance to `other`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use flocking_lib::vector::Vec3;
+    /// let d = Vec3::new(1.0, 2.0, 3.0).distance_to(&Vec3::new(4.0, 6.0, 3.0));
+    /// assert_eq!(d, 5.0);
+    /// ```
+    pub fn distance_to(&self, other: &Self) -> f64 {
+        self.distance_squared_to(other).sqrt()
+    }
+
+    /// Linearly interpolates between this vector and `other`.
+    ///
+    /// Computes `self * (1 - t) + other * t`, so `t = 0` yields `self` and `t = 1`
+    /// yields `other`. Values of `t` outside [0, 1] extrapolate along the same line.
+    ///
+    /// # Examples
+    /// ```
+    /// # use flocking_lib::vector::Vec3;
+    /// let mid = Vec3::zero().lerp(&Vec3::new(2.0, 4.0, 6.0), 0.5);
+    /// assert_eq!(mid, Vec3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn lerp(&self, other: &Self, t: f64) -> Self {
+        *self * (1.0 - t) + *other * t
+    }
+
+    /// Returns this vector rescaled to length `max` if it is longer, otherwise unchanged.
+    ///
+    /// The direction is always preserved, and the zero vector is returned as is.
+    ///
+    /// # Examples
+    /// ```
+    /// # use flocking_lib::vector::Vec3;
+    /// let capped = Vec3::new(3.0, 4.0, 0.0).clamp_magnitude(1.0);
+    /// assert!((capped.norm() - 1.0).abs() < 1e-12);
+    /// assert_eq!(Vec3::new(0.1, 0.0, 0.0).clamp_magnitude(1.0), Vec3::new(0.1, 0.0, 0.0));
+    /// ```
+    pub fn clamp_magnitude(&self, max: f64) -> Self {
+        let norm = self.norm();
+        if norm > max && norm > f64::EPSILON {
+            *self * (max / norm)
+        } else {
+            *self
+        }
+    }
+
+    /// Returns a unit vector orthogonal to this vector.
+    ///
+    /// The result is deterministic: it is the normalized cross product with the
+    /// coordinate axis least aligned with `self`, which keeps it numerically well
+    /// conditioned. Returns the zero vector for (near-)zero input.
+    pub(crate) fn orthogonal_unit(&self) -> Self {
+        let (ax, ay, az) = (self.x.abs(), self.y.abs(), self.z.abs());
+        let reference = if ax <= ay && ax <= az {
+            Vec3::x_hat()
+        } else if ay <= az {
+            Vec3::y_hat()
+        } else {
+            Vec3::z_hat()
+        };
+        self.cross(&reference).normalize()
+    }
 }
@@ -25,9 +25,13 @@
 pub mod ops;
 // Helper Functions for Vec3 struct
 pub mod math;
+// Quaternions for rotation composition
+pub mod quat;
 // Unit tests
 pub mod tests;
 
+pub use quat::Quat;
+
 /// A 3D vector in Cartesian coordinates optimized for flocking simulations.
 ///
 /// `Vec3` represents a point or direction in 3D space using double-precision
@@ -164,4 +168,27 @@ impl Vec3 {
     pub fn z_hat() -> Self {
         Vec3::new(0.0, 0.0, 1.0)
     }
+
+    /// Returns the components as an `[x, y, z]` array.
+    ///
+    /// # Examples
+    /// ```
+    /// # use flocking_lib::vector::Vec3;
+    /// assert_eq!(Vec3::new(1.0, 2.0, 3.0).as_array(), [1.0, 2.0, 3.0]);
+    /// ```
+    pub fn as_array(&self) -> [f64; 3] {
+        [self.x, self.y, self.z]
+    }
+
+    /// Creates a vector from an `[x, y, z]` array.
+    ///
+    /// # Examples
+    /// ```
+    /// # use flocking_lib::vector::Vec3;
+    /// assert_eq!(Vec3::from_array([1.0, 2.0, 3.0]), Vec3::new(1.0, 2.0, 3.0));
+    /// ```
+    pub fn from_array(components: [f64; 3]) -> Self {
+        let [x, y, z] = components;
+        Vec3::new(x, y, z)
+    }
 }
@@ -2,13 +2,21 @@
 //! This module provides implementations of various mathematical operations
 //! for the `Vec3` struct, including addition, subtraction, scalar multiplication,
 //! scalar division, and negation. These operations are implemented using Rust's
-//! operator overloading traits (`Add`, `Sub`, `Mul`, `Div`, `Neg`).
+//! operator overloading traits (`Add`, `Sub`, `Mul`, `Div`, `Neg`). `Sum` lets
+//! iterators of vectors be accumulated with `.sum::<Vec3>()`.
 //!
 //! Since Vec3 implements Copy, only value-based operations are provided for
 //! simplicity and clarity. The Copy trait ensures these operations are efficient.
+//!
+//! Batch operations over slices (`add_slices`, `scale_slice`, `sum_slice`) are provided
+//! as free functions. They are written as tight loops over contiguous buffers so the
+//! compiler can auto-vectorize them.
 
 use super::Vec3;
-use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::iter::Sum;
+use std::ops::{
+    Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign,
+};
 
 impl Vec3 {
     /// Checks if this vector is approximately equal to another within epsilon tolerance.
@@ -151,7 +159,11 @@ impl Mul<Vec3> for f64 {
 impl Div<f64> for Vec3 {
     type Output = Self;
     fn div(self, rhs: f64) -> Self::Output {
-        self * rhs.recip()
+        Vec3 {
+            x: self.x / rhs,
+            y: self.y / rhs,
+            z: self.z / rhs,
+        }
     }
 }
 
@@ -177,3 +189,199 @@ impl Neg for Vec3 {
         }
     }
 }
+
+/// In-place vector addition (`a += b`).
+///
+/// # Examples
+/// ```
+/// # use flocking_lib::vector::Vec3;
+/// let mut sum = Vec3::zero();
+/// sum += Vec3::new(1.0, 2.0, 3.0);
+/// sum += Vec3::x_hat();
+/// assert_eq!(sum, Vec3::new(2.0, 2.0, 3.0));
+/// ```
+impl AddAssign for Vec3 {
+    fn add_assign(&mut self, rhs: Self) {
+        self.x += rhs.x;
+        self.y += rhs.y;
+        self.z += rhs.z;
+    }
+}
+
+/// In-place vector subtraction (`a -= b`).
+///
+/// # Examples
+/// ```
+/// # use flocking_lib::vector::Vec3;
+/// let mut v = Vec3::new(1.0, 2.0, 3.0);
+/// v -= Vec3::new(1.0, 1.0, 1.0);
+/// assert_eq!(v, Vec3::new(0.0, 1.0, 2.0));
+/// ```
+impl SubAssign for Vec3 {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.x -= rhs.x;
+        self.y -= rhs.y;
+        self.z -= rhs.z;
+    }
+}
+
+/// In-place scalar multiplication (`v *= s`).
+///
+/// # Examples
+/// ```
+/// # use flocking_lib::vector::Vec3;
+/// let mut v = Vec3::new(1.0, -2.0, 3.0);
+/// v *= 2.0;
+/// assert_eq!(v, Vec3::new(2.0, -4.0, 6.0));
+/// ```
+impl MulAssign<f64> for Vec3 {
+    fn mul_assign(&mut self, rhs: f64) {
+        self.x *= rhs;
+        self.y *= rhs;
+        self.z *= rhs;
+    }
+}
+
+/// In-place scalar division (`v /= s`).
+///
+/// # Examples
+/// ```
+/// # use flocking_lib::vector::Vec3;
+/// let mut v = Vec3::new(2.0, 4.0, 6.0);
+/// v /= 2.0;
+/// assert_eq!(v, Vec3::new(1.0, 2.0, 3.0));
+/// ```
+impl DivAssign<f64> for Vec3 {
+    fn div_assign(&mut self, rhs: f64) {
+        self.x /= rhs;
+        self.y /= rhs;
+        self.z /= rhs;
+    }
+}
+
+/// Component access by index (`v[0]` = x, `v[1]` = y, `v[2]` = z).
+///
+/// # Panics
+/// Panics if the index is greater than 2.
+///
+/// # Examples
+/// ```
+/// # use flocking_lib::vector::Vec3;
+/// let v = Vec3::new(1.0, 2.0, 3.0);
+/// let sum: f64 = (0..3).map(|i| v[i]).sum();
+/// assert_eq!(sum, 6.0);
+/// ```
+impl Index<usize> for Vec3 {
+    type Output = f64;
+    fn index(&self, index: usize) -> &Self::Output {
+        match index {
+            0 => &self.x,
+            1 => &self.y,
+            2 => &self.z,
+            _ => panic!("Vec3 index out of bounds: {} (expected 0..=2)", index),
+        }
+    }
+}
+
+/// Mutable component access by index (`v[0]` = x, `v[1]` = y, `v[2]` = z).
+///
+/// # Panics
+/// Panics if the index is greater than 2.
+///
+/// # Examples
+/// ```
+/// # use flocking_lib::vector::Vec3;
+/// let mut v = Vec3::zero();
+/// v[1] = 5.0;
+/// assert_eq!(v, Vec3::new(0.0, 5.0, 0.0));
+/// ```
+impl IndexMut<usize> for Vec3 {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        match index {
+            0 => &mut self.x,
+            1 => &mut self.y,
+            2 => &mut self.z,
+            _ => panic!("Vec3 index out of bounds: {} (expected 0..=2)", index),
+        }
+    }
+}
+
+/// Sum of an iterator of vectors; the empty sum is the zero vector.
+///
+/// # Examples
+/// ```
+/// # use flocking_lib::vector::Vec3;
+/// let vectors = [Vec3::x_hat(), Vec3::y_hat(), Vec3::new(1.0, 1.0, 1.0)];
+/// let total: Vec3 = vectors.iter().copied().sum();
+/// assert_eq!(total, Vec3::new(2.0, 2.0, 1.0));
+/// ```
+impl Sum for Vec3 {
+    fn sum<I: Iterator<Item = Vec3>>(iter: I) -> Self {
+        iter.fold(Vec3::zero(), |acc, v| acc + v)
+    }
+}
+
+/// Sum of an iterator of vector references, e.g. `slice.iter().sum::<Vec3>()`.
+impl<'a> Sum<&'a Vec3> for Vec3 {
+    fn sum<I: Iterator<Item = &'a Vec3>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
+/// Element-wise addition of two slices into an output slice (`out[i] = a[i] + b[i]`).
+///
+/// # Panics
+/// Panics if the three slices do not have the same length.
+///
+/// # Examples
+/// ```
+/// # use flocking_lib::vector::{Vec3, ops::add_slices};
+/// let a = [Vec3::new(1.0, 2.0, 3.0), Vec3::x_hat()];
+/// let b = [Vec3::new(1.0, 1.0, 1.0), Vec3::y_hat()];
+/// let mut out = [Vec3::zero(); 2];
+/// add_slices(&a, &b, &mut out);
+/// assert_eq!(out, [Vec3::new(2.0, 3.0, 4.0), Vec3::new(1.0, 1.0, 0.0)]);
+/// ```
+pub fn add_slices(a: &[Vec3], b: &[Vec3], out: &mut [Vec3]) {
+    assert_eq!(a.len(), b.len(), "add_slices: input lengths differ");
+    assert_eq!(a.len(), out.len(), "add_slices: output length differs");
+    for ((o, x), y) in out.iter_mut().zip(a).zip(b) {
+        o.x = x.x + y.x;
+        o.y = x.y + y.y;
+        o.z = x.z + y.z;
+    }
+}
+
+/// Scales every vector of a slice in place by the same factor.
+///
+/// # Examples
+/// ```
+/// # use flocking_lib::vector::{Vec3, ops::scale_slice};
+/// let mut v = [Vec3::new(1.0, 2.0, 3.0), Vec3::new(-1.0, 0.0, 0.5)];
+/// scale_slice(&mut v, 2.0);
+/// assert_eq!(v, [Vec3::new(2.0, 4.0, 6.0), Vec3::new(-2.0, 0.0, 1.0)]);
+/// ```
+pub fn scale_slice(v: &mut [Vec3], s: f64) {
+    for item in v.iter_mut() {
+        *item *= s;
+    }
+}
+
+/// Sums all vectors of a slice using independent per-component accumulators.
+///
+/// # Examples
+/// ```
+/// # use flocking_lib::vector::{Vec3, ops::sum_slice};
+/// let v = [Vec3::x_hat(), Vec3::y_hat(), Vec3::z_hat()];
+/// assert_eq!(sum_slice(&v), Vec3::new(1.0, 1.0, 1.0));
+/// assert_eq!(sum_slice(&[]), Vec3::zero());
+/// ```
+pub fn sum_slice(v: &[Vec3]) -> Vec3 {
+    let (mut x, mut y, mut z) = (0.0, 0.0, 0.0);
+    for item in v {
+        x += item.x;
+        y += item.y;
+        z += item.z;
+    }
+    Vec3 { x, y, z }
+}
@@ -0,0 +1,140 @@
+//! # Unit quaternions for rotation composition
+//! Provides the `Quat` type used to compose many rotations cheaply. Composing
+//! quaternions costs one Hamilton product per rotation and a renormalization is a
+//! single scaling, whereas chaining `rotate_around` re-evaluates trigonometric
+//! functions on every call and lets direction errors accumulate.
+
+use super::Vec3;
+use std::ops::Mul;
+
+/// A quaternion `w + xi + yj + zk` representing a 3D rotation when normalized.
+///
+/// # Fields
+/// - `w`: The scalar (real) part
+/// - `x`, `y`, `z`: The vector (imaginary) part
+///
+/// # Examples
+/// ```
+/// # use flocking_lib::vector::{Quat, Vec3};
+/// use std::f64::consts::PI;
+///
+/// let quarter_turn = Quat::from_axis_angle(&Vec3::z_hat(), PI / 2.0);
+/// let half_turn = quarter_turn * quarter_turn;
+/// let rotated = half_turn.rotate(&Vec3::x_hat());
+/// assert!(rotated.approx_eq(&Vec3::new(-1.0, 0.0, 0.0), 1e-12));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct Quat {
+    /// Scalar part
+    pub w: f64,
+    /// First imaginary component
+    pub x: f64,
+    /// Second imaginary component
+    pub y: f64,
+    /// Third imaginary component
+    pub z: f64,
+}
+
+impl Quat {
+    /// Returns the identity rotation (1, 0, 0, 0).
+    pub fn identity() -> Self {
+        Quat {
+            w: 1.0,
+            x: 0.0,
+            y: 0.0,
+            z: 0.0,
+        }
+    }
+
+    /// Creates the rotation by `angle` radians around `axis` (right-hand rule).
+    ///
+    /// The axis is normalized internally; a (near-)zero axis yields the identity.
+    /// For a unit axis the result rotates vectors exactly like
+    /// [`Vec3::rotate_around`] with the same arguments.
+    pub fn from_axis_angle(axis: &Vec3, angle: f64) -> Self {
+        let axis = axis.normalize();
+        if axis == Vec3::zero() {
+            return Quat::identity();
+        }
+        let (sin_half, cos_half) = (0.5 * angle).sin_cos();
+        Quat {
+            w: cos_half,
+            x: axis.x * sin_half,
+            y: axis.y * sin_half,
+            z: axis.z * sin_half,
+        }
+    }
+
+    /// Returns the Euclidean norm √(w² + x² + y² + z²).
+    pub fn norm(&self) -> f64 {
+        (self.w * self.w + self.x * self.x + self.y * self.y + self.z * self.z).sqrt()
+    }
+
+    /// Returns the quaternion scaled to unit norm, or the identity for a zero quaternion.
+    ///
+    /// Call this periodically when composing long chains of rotations to remove
+    /// the slow drift away from unit norm caused by rounding.
+    pub fn normalize(&self) -> Self {
+        let norm = self.norm();
+        if norm > f64::EPSILON {
+            Quat {
+                w: self.w / norm,
+                x: self.x / norm,
+                y: self.y / norm,
+                z: self.z / norm,
+            }
+        } else {
+            Quat::identity()
+        }
+    }
+
+    /// Returns the conjugate (w, -x, -y, -z), the inverse rotation of a unit quaternion.
+    pub fn conjugate(&self) -> Self {
+        Quat {
+            w: self.w,
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+
+    /// Rotates a vector by this (unit) quaternion.
+    ///
+    /// Uses the expanded form `v' = v + 2w(q × v) + 2 q × (q × v)` with `q` the
+    /// vector part, which avoids building the full sandwich product `q v q*`.
+    pub fn rotate(&self, v: &Vec3) -> Vec3 {
+        let q = Vec3::new(self.x, self.y, self.z);
+        let t = 2.0 * q.cross(v);
+        *v + self.w * t + q.cross(&t)
+    }
+}
+
+impl Default for Quat {
+    fn default() -> Self {
+        Quat::identity()
+    }
+}
+
+/// Quaternion composition (Hamilton product).
+///
+/// `a * b` is the rotation that first applies `b`, then `a`.
+///
+/// # Examples
+/// ```
+/// # use flocking_lib::vector::{Quat, Vec3};
+/// let a = Quat::from_axis_angle(&Vec3::x_hat(), 0.3);
+/// let b = Quat::from_axis_angle(&Vec3::y_hat(), 0.7);
+/// let v = Vec3::new(1.0, 2.0, 3.0);
+/// assert!((a * b).rotate(&v).approx_eq(&a.rotate(&b.rotate(&v)), 1e-12));
+/// ```
+impl Mul for Quat {
+    type Output = Self;
+    fn mul(self, rhs: Self) -> Self::Output {
+        Quat {
+            w: self.w * rhs.w - self.x * rhs.x - self.y * rhs.y - self.z * rhs.z,
+            x: self.w * rhs.x + self.x * rhs.w + self.y * rhs.z - self.z * rhs.y,
+            y: self.w * rhs.y - self.x * rhs.z + self.y * rhs.w + self.z * rhs.x,
+            z: self.w * rhs.z + self.x * rhs.y - self.y * rhs.x + self.z * rhs.w,
+        }
+    }
+}
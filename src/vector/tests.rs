@@ -12,6 +12,35 @@ mod units {
         assert_eq!(normalized, Vec3::zero());
     }
 
+    #[test]
+    fn non_finite_components() {
+        let nan = Vec3::new(f64::NAN, 1.0, 0.0);
+        let inf = Vec3::new(0.0, f64::INFINITY, 0.0);
+        let neg_inf = Vec3::new(1.0, 2.0, f64::NEG_INFINITY);
+
+        assert!(Vec3::new(1e300, -1e300, 0.0).is_finite());
+        for v in [nan, inf, neg_inf] {
+            assert!(!v.is_finite());
+            assert_eq!(v.normalize(), Vec3::zero());
+        }
+
+        // Finite components whose magnitude overflows
+        assert_eq!(Vec3::new(1e300, 1e300, 0.0).normalize(), Vec3::zero());
+    }
+
+    #[test]
+    fn try_normalize_some_and_none() {
+        let v = Vec3::new(3.0, -4.0, 12.0);
+        let unit = v.try_normalize().unwrap();
+        assert!((unit.norm() - 1.0).abs() < 1e-15);
+        assert_eq!(unit, v.normalize());
+
+        assert_eq!(Vec3::zero().try_normalize(), None);
+        assert_eq!(Vec3::new(1e-20, 1e-20, 1e-20).try_normalize(), None);
+        assert_eq!(Vec3::new(0.0, f64::INFINITY, 0.0).try_normalize(), None);
+        assert_eq!(Vec3::new(f64::NAN, 0.0, 0.0).try_normalize(), None);
+    }
+
     #[test]
     fn cross_product() {
         // Standard basis vectors
@@ -156,6 +185,7 @@ mod units {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn debug_and_clone() {
         let v = Vec3::new(1.0, 2.0, 3.0);
 
@@ -378,4 +408,329 @@ mod units {
         let rotated = zero.rotate_around(&axis, PI).unwrap();
         assert_eq!(rotated, Vec3::zero());
     }
+
+    #[test]
+    fn add_slices_matches_operator() {
+        use super::super::ops::add_slices;
+
+        let a: Vec<Vec3> = (0..17)
+            .map(|i| Vec3::new(i as f64, -0.5 * i as f64, 1.0 / (i as f64 + 1.0)))
+            .collect();
+        let b: Vec<Vec3> = (0..17)
+            .map(|i| Vec3::new((i as f64).sin(), (i as f64).cos(), 3.0))
+            .collect();
+        let mut out = vec![Vec3::zero(); a.len()];
+
+        add_slices(&a, &b, &mut out);
+
+        for i in 0..a.len() {
+            assert_eq!(out[i], a[i] + b[i]);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "add_slices")]
+    fn add_slices_length_mismatch_panics() {
+        use super::super::ops::add_slices;
+
+        let a = [Vec3::zero(); 3];
+        let b = [Vec3::zero(); 2];
+        let mut out = [Vec3::zero(); 3];
+        add_slices(&a, &b, &mut out);
+    }
+
+    #[test]
+    fn scale_slice_matches_operator() {
+        use super::super::ops::scale_slice;
+
+        let original: Vec<Vec3> = (0..9)
+            .map(|i| Vec3::new(i as f64, 2.0 * i as f64, -(i as f64)))
+            .collect();
+        let mut scaled = original.clone();
+
+        scale_slice(&mut scaled, -1.5);
+
+        for (s, o) in scaled.iter().zip(&original) {
+            assert_eq!(*s, *o * -1.5);
+        }
+    }
+
+    #[test]
+    fn iterator_sum_of_known_vectors() {
+        let v = [
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(-4.0, 0.5, 0.0),
+            Vec3::new(0.0, -2.5, 7.0),
+        ];
+
+        assert_eq!(v.iter().sum::<Vec3>(), Vec3::new(-3.0, 0.0, 10.0));
+        assert_eq!(v.iter().copied().sum::<Vec3>(), Vec3::new(-3.0, 0.0, 10.0));
+        assert_eq!(std::iter::empty::<Vec3>().sum::<Vec3>(), Vec3::zero());
+    }
+
+    #[test]
+    fn sum_slice_matches_fold() {
+        use super::super::ops::sum_slice;
+
+        let v: Vec<Vec3> = (0..11)
+            .map(|i| Vec3::new(i as f64, 1.0, -2.0 * i as f64))
+            .collect();
+        let folded = v.iter().fold(Vec3::zero(), |acc, x| acc + *x);
+
+        assert_eq!(sum_slice(&v), folded);
+        assert_eq!(sum_slice(&[]), Vec3::zero());
+    }
+
+    #[test]
+    fn orthogonal_unit() {
+        let vectors = [
+            Vec3::x_hat(),
+            Vec3::new(0.0, -3.0, 0.0),
+            Vec3::new(1.0, 2.0, 3.0),
+            Vec3::new(-0.2, 0.1, 5.0),
+        ];
+        for v in vectors {
+            let o = v.orthogonal_unit();
+            assert!((o.norm() - 1.0).abs() < 1e-12);
+            assert!(o.dot(&v).abs() < 1e-12);
+        }
+        assert_eq!(Vec3::zero().orthogonal_unit(), Vec3::zero());
+    }
+
+    #[test]
+    fn rotation_to_perpendicular() {
+        let (axis, angle) = Vec3::x_hat().rotation_to(&Vec3::y_hat());
+        assert!(axis.approx_eq(&Vec3::z_hat(), 1e-12));
+        assert!((angle - PI / 2.0).abs() < 1e-12);
+
+        // Non-unit inputs give the same rotation
+        let (axis, angle) = Vec3::new(3.0, 0.0, 0.0).rotation_to(&Vec3::new(0.0, 0.5, 0.0));
+        assert!(axis.approx_eq(&Vec3::z_hat(), 1e-12));
+        assert!((angle - PI / 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn rotation_to_parallel() {
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        let (axis, angle) = v.rotation_to(&(v * 2.0));
+        assert_eq!(angle, 0.0);
+        assert!((axis.norm() - 1.0).abs() < 1e-12);
+
+        let (axis, angle) = Vec3::zero().rotation_to(&v);
+        assert_eq!(angle, 0.0);
+        assert_eq!(axis, Vec3::x_hat());
+    }
+
+    #[test]
+    fn rotation_to_antiparallel() {
+        let v = Vec3::new(1.0, -2.0, 0.5).normalize();
+        let (axis, angle) = v.rotation_to(&-v);
+        assert!((angle - PI).abs() < 1e-12);
+        assert!((axis.norm() - 1.0).abs() < 1e-12);
+        assert!(axis.dot(&v).abs() < 1e-12);
+
+        let rotated = v.rotate_around(&axis, angle).unwrap();
+        assert!(rotated.approx_eq(&-v, 1e-12));
+    }
+
+    #[test]
+    fn rotation_to_composes_with_rotate_around() {
+        let pairs = [
+            (Vec3::new(1.0, 1.0, 0.0), Vec3::new(0.0, 1.0, 1.0)),
+            (Vec3::new(-1.0, 0.3, 2.0), Vec3::new(0.5, -0.5, 0.1)),
+            (Vec3::z_hat(), Vec3::new(0.0, 0.1, -1.0)),
+        ];
+        for (from, to) in pairs {
+            let (axis, angle) = from.rotation_to(&to);
+            assert!((0.0..=PI).contains(&angle));
+            let rotated = from.normalize().rotate_around(&axis, angle).unwrap();
+            assert!(rotated.approx_eq(&to.normalize(), 1e-12));
+        }
+    }
+
+    #[test]
+    fn quat_matches_rotate_around() {
+        use super::super::Quat;
+
+        let cases = [
+            (Vec3::z_hat(), PI / 2.0),
+            (Vec3::new(1.0, 1.0, 0.0).normalize(), 0.7),
+            (Vec3::new(-0.3, 0.4, 2.0).normalize(), -2.5),
+            (Vec3::x_hat(), PI),
+        ];
+        let v = Vec3::new(0.4, -1.2, 2.5);
+
+        for (axis, angle) in cases {
+            let expected = v.rotate_around(&axis, angle).unwrap();
+            let q = Quat::from_axis_angle(&axis, angle);
+            assert!((q.norm() - 1.0).abs() < 1e-12);
+            assert!(q.rotate(&v).approx_eq(&expected, 1e-12));
+        }
+    }
+
+    #[test]
+    fn quat_identity_and_zero_axis() {
+        use super::super::Quat;
+
+        let v = Vec3::new(1.0, 2.0, 3.0);
+        assert_eq!(Quat::identity().rotate(&v), v);
+        assert_eq!(Quat::default(), Quat::identity());
+        assert_eq!(Quat::from_axis_angle(&Vec3::zero(), 1.0), Quat::identity());
+    }
+
+    #[test]
+    fn quat_composition() {
+        use super::super::Quat;
+
+        let a = Quat::from_axis_angle(&Vec3::x_hat(), 0.4);
+        let b = Quat::from_axis_angle(&Vec3::new(0.0, 1.0, 1.0), -1.1);
+        let v = Vec3::new(-0.5, 0.25, 2.0);
+
+        let sequential = a.rotate(&b.rotate(&v));
+        assert!((a * b).rotate(&v).approx_eq(&sequential, 1e-12));
+
+        // Conjugate undoes the rotation
+        let round_trip = (a.conjugate() * a).rotate(&v);
+        assert!(round_trip.approx_eq(&v, 1e-12));
+    }
+
+    #[test]
+    fn quat_long_composition_with_normalize() {
+        use super::super::Quat;
+
+        // 10_000 steps of 2π/10_000 about z is one full turn
+        let n = 10_000;
+        let step = Quat::from_axis_angle(&Vec3::z_hat(), 2.0 * PI / n as f64);
+        let mut total = Quat::identity();
+        for i in 0..n {
+            total = step * total;
+            if i % 100 == 0 {
+                total = total.normalize();
+            }
+        }
+        let total = total.normalize();
+
+        assert!((total.norm() - 1.0).abs() < 1e-12);
+        let v = Vec3::new(1.0, 0.5, -0.3);
+        assert!(total.rotate(&v).approx_eq(&v, 1e-9));
+        assert_eq!(
+            Quat {
+                w: 0.0,
+                x: 0.0,
+                y: 0.0,
+                z: 0.0
+            }
+            .normalize(),
+            Quat::identity()
+        );
+    }
+
+    #[test]
+    fn lerp_endpoints_and_midpoint() {
+        let a = Vec3::new(1.0, -2.0, 3.0);
+        let b = Vec3::new(-3.0, 4.0, 5.0);
+
+        assert_eq!(a.lerp(&b, 0.0), a);
+        assert_eq!(a.lerp(&b, 1.0), b);
+        assert!(a.lerp(&b, 0.5).approx_eq(&Vec3::new(-1.0, 1.0, 4.0), 1e-12));
+        assert!(a.lerp(&b, 2.0).approx_eq(&(b * 2.0 - a), 1e-12));
+    }
+
+    #[test]
+    fn clamp_magnitude_caps_only_long_vectors() {
+        let long = Vec3::new(0.0, 6.0, 8.0);
+        let capped = long.clamp_magnitude(2.0);
+        assert!((capped.norm() - 2.0).abs() < 1e-12);
+        assert!(capped.normalize().approx_eq(&long.normalize(), 1e-12));
+
+        let short = Vec3::new(0.3, 0.0, -0.4);
+        assert_eq!(short.clamp_magnitude(1.0), short);
+        assert_eq!(Vec3::zero().clamp_magnitude(0.0), Vec3::zero());
+        assert_eq!(long.clamp_magnitude(0.0), Vec3::zero());
+    }
+
+    #[test]
+    fn distance_to_matches_difference_norm() {
+        let pairs = [
+            (Vec3::new(1.0, 2.0, 3.0), Vec3::new(-4.0, 0.5, 9.0)),
+            (Vec3::new(1e-8, -1e-8, 0.0), Vec3::zero()),
+            (Vec3::x_hat(), Vec3::x_hat()),
+        ];
+        for (a, b) in pairs {
+            assert!((a.distance_squared_to(&b) - (a - b).norm_squared()).abs() < 1e-12);
+            assert!((a.distance_to(&b) - (a - b).norm()).abs() < 1e-12);
+            assert_eq!(a.distance_to(&b), b.distance_to(&a));
+        }
+        assert_eq!(Vec3::x_hat().distance_to(&Vec3::x_hat()), 0.0);
+    }
+
+    #[test]
+    fn index_and_index_mut_components() {
+        let mut v = Vec3::new(1.5, -2.5, 3.5);
+        assert_eq!(v[0], v.x);
+        assert_eq!(v[1], v.y);
+        assert_eq!(v[2], v.z);
+
+        v[0] = 10.0;
+        v[1] *= 2.0;
+        v[2] += 0.5;
+        assert_eq!(v, Vec3::new(10.0, -5.0, 4.0));
+
+        // Loop-based dot product agrees with Vec3::dot
+        let w = Vec3::new(0.5, 1.0, -1.0);
+        let dot: f64 = (0..3).map(|i| v[i] * w[i]).sum();
+        assert_eq!(dot, v.dot(&w));
+    }
+
+    #[test]
+    #[should_panic(expected = "Vec3 index out of bounds")]
+    fn index_out_of_bounds_panics() {
+        let v = Vec3::zero();
+        let _ = v[3];
+    }
+
+    #[test]
+    #[should_panic(expected = "Vec3 index out of bounds")]
+    fn index_mut_out_of_bounds_panics() {
+        let mut v = Vec3::zero();
+        v[3] = 1.0;
+    }
+
+    #[test]
+    fn array_conversions_round_trip() {
+        let v = Vec3::new(-1.0, 0.25, 7.0);
+        assert_eq!(v.as_array(), [-1.0, 0.25, 7.0]);
+        assert_eq!(Vec3::from_array(v.as_array()), v);
+        assert_eq!(Vec3::from_array([0.0, 0.0, 1.0]), Vec3::z_hat());
+    }
+
+    #[test]
+    fn compound_assignment_operators() {
+        let a = Vec3::new(1.0, 2.0, 3.0);
+        let b = Vec3::new(-0.5, 4.0, 0.25);
+
+        let mut v = a;
+        v += b;
+        assert_eq!(v, a + b);
+
+        let mut v = a;
+        v -= b;
+        assert_eq!(v, a - b);
+
+        let mut v = a;
+        v *= -3.0;
+        assert_eq!(v, a * -3.0);
+
+        let mut v = a;
+        v /= 4.0;
+        assert_eq!(v, a / 4.0);
+
+        // Accumulation matches the fold-based sum
+        let values = [a, b, -a, b * 2.0];
+        let mut total = Vec3::zero();
+        for value in values {
+            total += value;
+        }
+        assert_eq!(total, values.iter().fold(Vec3::zero(), |acc, v| acc + *v));
+    }
 }
@@ -0,0 +1,156 @@
+use flocking_lib::bird::Bird;
+use flocking_lib::ensemble::{self, EntryGenerationParams, InitialDistribution, VelocityInit};
+use flocking_lib::io::{DataPersistence, DataRoot, DataType, bin};
+use flocking_lib::simulation::{self, SimulationParams, SimulationRequest, SimulationResult};
+use std::process::Command;
+
+fn binary() -> Command {
+    Command::new(env!("CARGO_BIN_EXE_vicseksim-rs"))
+}
+
+#[test]
+fn simulate_subcommand_runs_from_ensemble() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = DataRoot::new(dir.path());
+    let params = EntryGenerationParams {
+        num_birds: 8,
+        radius: 1.0,
+        speed: 1.0,
+        min_distance: 0.1,
+        distribution: InitialDistribution::Uniform,
+        velocity_init: VelocityInit::Random,
+    };
+    ensemble::generate(5, 1, params, &root, None).unwrap();
+
+    let output = binary()
+        .args(["--data-dir", dir.path().to_str().unwrap()])
+        .args(["simulate", "--ensemble-tag", "5", "--ensemble-id", "0"])
+        .args(["--iterations", "20", "--frame-interval", "5", "--tag", "6"])
+        .output()
+        .unwrap();
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(stdout.contains("Final order parameter"));
+
+    let result: SimulationResult =
+        bin::load_file(&SimulationResult::binary_path_for(&root, 6, 0)).unwrap();
+    assert_eq!(result.ensemble_entry_id, 0);
+    assert_eq!(result.params.num_birds, 8);
+    assert_eq!(result.snapshots.len(), 4);
+}
+
+#[test]
+fn simulate_subcommand_missing_ensemble_fails() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = binary()
+        .args(["--data-dir", dir.path().to_str().unwrap()])
+        .args(["simulate", "--ensemble-tag", "1", "--ensemble-id", "0"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Error"));
+}
+
+#[test]
+fn ensemble_subcommand_generates_entries() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = binary()
+        .args(["--data-dir", dir.path().to_str().unwrap()])
+        .args(["ensemble", "--tag", "4", "--count", "2", "--threads", "2"])
+        .args(["--num-birds", "10", "--min-distance", "0.1", "--seed", "3"])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+
+    let root = DataRoot::new(dir.path());
+    for id in 0..2 {
+        let entry = ensemble::io::load_ensemble(&root, 4, id).unwrap();
+        assert_eq!(entry.birds.len(), 10);
+        assert_eq!(entry.params.min_distance, 0.1);
+    }
+    assert!(!root.folder(DataType::Ensemble).join("t4-i2.bin").exists());
+}
+
+#[test]
+fn ensemble_subcommand_rejects_infeasible_min_distance() {
+    let dir = tempfile::tempdir().unwrap();
+    let output = binary()
+        .args(["--data-dir", dir.path().to_str().unwrap()])
+        .args(["ensemble", "--tag", "4", "--count", "2"])
+        .args(["--num-birds", "1000", "--min-distance", "0.5"])
+        .output()
+        .unwrap();
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("infeasible"));
+    assert!(
+        !DataRoot::new(dir.path())
+            .folder(DataType::Ensemble)
+            .exists()
+    );
+}
+
+#[test]
+fn analyze_subcommand_writes_time_series() {
+    let dir = tempfile::tempdir().unwrap();
+    let root = DataRoot::new(dir.path());
+    let birds = (0..6)
+        .map(|i| Bird::from_spherical(1.0, 1.0 + 0.05 * i as f64, 0.0, 1.0, 0.3))
+        .collect();
+    let params = SimulationParams {
+        num_birds: 6,
+        radius: 1.0,
+        speed: 1.0,
+        dt: 0.01,
+        interaction_radius: 0.2,
+        eta: 0.1,
+        total_iterations: 30,
+        frame_interval: 10,
+        ..Default::default()
+    };
+    let request = SimulationRequest {
+        id: 1,
+        tag: 8,
+        ensemble_entry_id: 0,
+        initial_values: birds,
+        params,
+        predators: Vec::new(),
+        leaders: Vec::new(),
+        forbidden_bands: Vec::new(),
+    };
+    simulation::run(request, &root).unwrap();
+
+    let csv = dir.path().join("analysis").join("series.csv");
+    let output = binary()
+        .args(["--data-dir", dir.path().to_str().unwrap()])
+        .args(["analyze", "--tag", "8", "--id", "1", "--burn-in", "20"])
+        .args(["--csv", csv.to_str().unwrap()])
+        .output()
+        .unwrap();
+    assert!(
+        output.status.success(),
+        "stderr: {}",
+        String::from_utf8_lossy(&output.stderr)
+    );
+    assert!(String::from_utf8_lossy(&output.stdout).contains("Steady-state order"));
+
+    let contents = std::fs::read_to_string(&csv).unwrap();
+    let lines: Vec<&str> = contents.lines().collect();
+    assert_eq!(lines[0], "step,timestamp,order_parameter,clusters");
+    assert_eq!(lines.len(), 4);
+    for (line, step) in lines[1..].iter().zip([10, 20, 30]) {
+        let fields: Vec<&str> = line.split(',').collect();
+        assert_eq!(fields[0], step.to_string());
+        let order: f64 = fields[2].parse().unwrap();
+        assert!((0.0..=1.0 + 1e-12).contains(&order));
+        let clusters: usize = fields[3].parse().unwrap();
+        assert!((1..=6).contains(&clusters));
+    }
+}